@@ -1,3 +1,4 @@
+import os
 import typing
 
 class StopTranslation(Exception):
@@ -50,10 +51,30 @@ class DNABase:
         """Transcription of the DNA base to a RNA base."""
         ...
 
+    @property
+    def bases(self) -> typing.FrozenSet[DNABase]:
+        """The unambiguous bases represented by this IUPAC code. For example:
+
+        >>> DNABase.ADENINE_GUANINE.bases  # {DNABase.ADENINE, DNABase.GUANINE}"""
+        ...
+
+    def matches(self, other: typing.Union[DNABase, str]) -> bool:
+        """Return true if this and the other IUPAC code share any bases, so
+        that ambiguity codes match the bases they stand for."""
+        ...
+
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __reduce__(self) -> typing.Tuple[typing.Any, ...]:
+        """Pickle the member as its code, rebuilt by calling `DNABase` with it.
+        Members are frozen, so there is no `__setstate__` to restore state into
+        an existing member and `__reduce__` takes the place of `__getstate__`
+        and `__setstate__`."""
+        ...
+
     def __bool__(self) -> bool:
         """Casting to bool is False for DNABase.GAP and True otherwise."""
         ...
@@ -135,10 +156,30 @@ class RNABase:
         """Reverse transcription of the RNA base to a DNA base."""
         ...
 
+    @property
+    def bases(self) -> typing.FrozenSet[RNABase]:
+        """The unambiguous bases represented by this IUPAC code. For example:
+
+        >>> RNABase.ADENINE_GUANINE.bases  # {RNABase.ADENINE, RNABase.GUANINE}"""
+        ...
+
+    def matches(self, other: typing.Union[RNABase, str]) -> bool:
+        """Return true if this and the other IUPAC code share any bases, so
+        that ambiguity codes match the bases they stand for."""
+        ...
+
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __reduce__(self) -> typing.Tuple[typing.Any, ...]:
+        """Pickle the member as its code, rebuilt by calling `RNABase` with it.
+        Members are frozen, so there is no `__setstate__` to restore state into
+        an existing member and `__reduce__` takes the place of `__getstate__`
+        and `__setstate__`."""
+        ...
+
     def __bool__(self) -> bool:
         """Casting to bool is False for RNABase.GAP and True otherwise."""
         ...
@@ -191,6 +232,12 @@ class AminoAcid:
     appropriate. For example:
 
     >>> AminoAcid('AGY')  # Serine
+    >>> AminoAcid('MUU')  # Leucine/isoleucine
+
+    Codons are translated using the standard genetic code unless another NCBI
+    translation table is given. For example:
+
+    >>> AminoAcid('UGA', table=2)  # Tryptophan
 
     Invalid inputs or codons that result in ambiguous amino acids will raise a
     ValueError.
@@ -220,6 +267,11 @@ class AminoAcid:
     ANY: AminoAcid
     TYROSINE: AminoAcid
     GLUTAMINE_GLUTAMIC_ACID: AminoAcid
+    STOP: AminoAcid
+    SELENOCYSTEINE: AminoAcid
+    PYRROLYSINE: AminoAcid
+    LEUCINE_ISOLEUCINE: AminoAcid
+    GAP: AminoAcid
 
     @classmethod
     def __new__(
@@ -227,6 +279,7 @@ class AminoAcid:
         code_or_codon: typing.Union[
             str, typing.Tuple[RNABase, RNABase, RNABase], typing.Tuple[str, str, str]
         ],
+        table: typing.Union[GeneticCode, int, str] = 1,
     ) -> AminoAcid: ...
     @property
     def code(self) -> str:
@@ -238,12 +291,32 @@ class AminoAcid:
         """Three-letter IUPAC code of the amino acid."""
         ...
 
+    @property
+    def amino_acids(self) -> typing.FrozenSet[AminoAcid]:
+        """The unambiguous amino acids represented by this IUPAC code. For example:
+
+        >>> AminoAcid("B").amino_acids  # {ASPARTIC_ACID, ASPARAGINE}"""
+        ...
+
+    def matches(self, other: typing.Union[AminoAcid, str]) -> bool:
+        """Return true if this and the other IUPAC code share any amino acids, so
+        that ambiguity codes match the amino acids they stand for."""
+        ...
+
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __reduce__(self) -> typing.Tuple[typing.Any, ...]:
+        """Pickle the member as its code, rebuilt by calling `AminoAcid` with it.
+        Members are frozen, so there is no `__setstate__` to restore state into
+        an existing member and `__reduce__` takes the place of `__getstate__`
+        and `__setstate__`."""
+        ...
+
     def __bool__(self) -> bool:
-        """Always true."""
+        """Casting to bool is False for AminoAcid.GAP and True otherwise."""
         ...
 
     def __add__(
@@ -260,6 +333,91 @@ class AminoAcid:
         followed by this amino acid."""
         ...
 
+class GeneticCode:
+    """A genetic code, as defined by the NCBI translation tables.
+
+    GeneticCodes may be instantiated by their NCBI table id or by their name,
+    or any of the organisms in their name, ignoring case. For example:
+
+    >>> GeneticCode(2)
+    >>> GeneticCode("Vertebrate Mitochondrial")
+    >>> GeneticCode("mycoplasma")  # Table 4
+
+    Codons that NCBI lists as either stop or sense codons (tables 27, 28 and
+    31) are treated as stop codons.
+
+    A ValueError is raised if no table matches."""
+
+    @classmethod
+    def __new__(cls, table: typing.Union[GeneticCode, int, str]) -> GeneticCode: ...
+    @property
+    def id(self) -> int:
+        """NCBI id of the translation table."""
+        ...
+
+    @property
+    def name(self) -> str:
+        """NCBI name of the translation table."""
+        ...
+
+    @property
+    def start_codons(self) -> typing.List[str]:
+        """The codons that may initiate translation."""
+        ...
+
+    @property
+    def stop_codons(self) -> typing.List[str]:
+        """The codons that terminate translation."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+
+class OpenReadingFrame:
+    """An open reading frame found by `DNASequence.find_orfs` or
+    `RNASequence.find_orfs`."""
+
+    @property
+    def start(self) -> int:
+        """Position of the first base of the start codon, relative to the
+        forward strand."""
+        ...
+
+    @property
+    def end(self) -> int:
+        """Position following the last base of the stop codon, relative to the
+        forward strand."""
+        ...
+
+    @property
+    def strand(self) -> int:
+        """1 for the forward strand or -1 for the reverse strand."""
+        ...
+
+    @property
+    def frame(self) -> int:
+        """Offset (0, 1 or 2) of the reading frame along its strand."""
+        ...
+
+    @property
+    def sequence(self) -> AminoAcidSequence:
+        """Translation of the open reading frame, excluding the stop codon."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """Length of the open reading frame in bases, including the stop
+        codon."""
+        ...
+
+class MatchIterator:
+    """An iterator over match positions, returned by `finditer`."""
+
+    def __iter__(self) -> MatchIterator: ...
+    def __next__(self) -> int: ...
+
 class DNASequence:
     @classmethod
     def __new__(
@@ -285,6 +443,26 @@ class DNASequence:
 
         ...
 
+    @classmethod
+    def from_bytes(cls, data: bytes) -> DNASequence:
+        """Decode a sequence encoded by `to_bytes`. A ValueError is raised if
+        the bytes are not a valid encoding."""
+        ...
+
+    def to_bytes(self) -> bytes:
+        """A compact binary encoding of the sequence, as used when pickling."""
+        ...
+
+    def view(
+        self,
+        start: typing.Optional[int] = None,
+        stop: typing.Optional[int] = None,
+        step: typing.Optional[int] = None,
+    ) -> DNASequenceView:
+        """A view of `sequence[start:stop:step]` that refers to this sequence
+        rather than copying its members."""
+        ...
+
     @property
     def complement(self) -> DNASequence:
         """The complementary DNA sequence."""
@@ -293,6 +471,65 @@ class DNASequence:
         """Transcription of the DNA sequence to a RNA sequence."""
         ...
 
+    def translate(
+        self,
+        table: typing.Union[GeneticCode, int, str] = 1,
+        *,
+        frame: typing.Optional[int] = None,
+        to_stop: bool = True,
+        cds: bool = False,
+        alternative_starts: bool = False,
+    ) -> AminoAcidSequence:
+        """Translate the DNA sequence to an amino acid sequence, without first
+        transcribing it. Options are as for `RNASequence.translate`."""
+        ...
+
+    def translate_frames(
+        self, table: typing.Union[GeneticCode, int, str] = 1
+    ) -> typing.List[AminoAcidSequence]:
+        """Translate all six reading frames of the DNA sequence: frames 0, 1
+        and 2 of the sequence followed by frames 0, 1 and 2 of its reverse
+        complement. Each frame is translated through stop codons, as with
+        `translate(frame=..., to_stop=False)`, except that ambiguous codons
+        are translated as `AminoAcid.ANY` and codons containing gaps as
+        `AminoAcid.GAP` rather than raising."""
+        ...
+
+    def find_orfs(
+        self,
+        table: typing.Union[GeneticCode, int, str] = 1,
+        *,
+        min_length: int = 0,
+        nested: bool = False,
+        alternative_starts: bool = False,
+    ) -> typing.List[OpenReadingFrame]:
+        """Find open reading frames on both strands of the DNA sequence,
+        ordered by their position on the forward strand.
+
+        An open reading frame runs from a start codon to the next in-frame
+        stop codon. Reading frames without a stop codon are not reported.
+        Ambiguous codons within an open reading frame are translated as
+        `AminoAcid.ANY`.
+
+        `min_length` is the minimum number of amino acids in a reported open
+        reading frame. If `nested` is True, in-frame start codons within an
+        open reading frame begin further open reading frames sharing its stop
+        codon; otherwise only the longest is reported.
+
+        If `alternative_starts` is True, any start codon of the table begins an
+        open reading frame, and is translated as Methionine."""
+        ...
+
+    def reverse_complement(self) -> DNASequence:
+        """The reverse complement of the DNA sequence, as read from the
+        opposite strand. IUPAC ambiguity codes are complemented as in
+        `DNABase.complement`."""
+        ...
+
+    def reverse(self) -> DNASequence:
+        """The DNA sequence in reverse order, without complementing."""
+        ...
+
     def __invert__(self) -> DNASequence:
         """See `DNASequence.complement`."""
         ...
@@ -301,6 +538,21 @@ class DNASequence:
     def __str__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __reduce__(self) -> typing.Tuple[typing.Any, ...]:
+        """Pickle the sequence as its `to_bytes` encoding, rebuilt with
+        `from_bytes`. Sequences are frozen, so there is no `__setstate__` to
+        restore state into an existing sequence and `__reduce__` takes the
+        place of `__getstate__` and `__setstate__`."""
+        ...
+
+    def __lt__(self, other: DNASequence) -> bool:
+        """Sequences are ordered by their codes, as strings would be."""
+        ...
+
+    def __le__(self, other: DNASequence) -> bool: ...
+    def __gt__(self, other: DNASequence) -> bool: ...
+    def __ge__(self, other: DNASequence) -> bool: ...
     def __bool__(self) -> bool:
         """Casting to bool is False for empty sequences and True otherwise."""
         ...
@@ -324,174 +576,2001 @@ class DNASequence:
         self, key: typing.Union[int, slice]
     ) -> typing.Union[DNABase, DNASequence]: ...
     def __iter__(self) -> typing.Iterator[DNABase]: ...
+    def __reversed__(self) -> typing.Iterator[DNABase]: ...
     def count(
-        self, item: typing.Union[DNABase, DNASequence, str], overlap: bool = True
+        self,
+        item: typing.Union[DNABase, DNASequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
     ) -> int:
-        """Count the occurances of a DNABase in the sequence."""
+        """Count the occurances of a DNABase in the sequence.
+
+        If `ambiguous` is True, IUPAC codes match any base they share, as in
+        `DNABase.matches`. For example, R matches A or G, and N matches any base."""
         ...
 
     def find(
-        self, target: typing.Union[DNASequence, DNABase, str]
+        self,
+        target: typing.Union[DNASequence, DNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
     ) -> typing.Optional[int]:
         """Find the index of the first occurance of the given DNABase or
-        DNASequence."""
-        ...
-
-class RNASequence:
-    @classmethod
-    def __new__(
-        cls,
-        bases: typing.Union[
-            str,
-            typing.Iterable[typing.Union[str, RNABase]],
-            typing.Sequence[typing.Union[str, RNABase]],
-        ] = "",
-    ) -> RNASequence:
-        """A sequence of `RNABase`s.
-
-        `RNASequence` may be instantiated by a string of IUPAC RNA codes, or a
-        sequence or iterable of `RNABase`s or IUPAC RNA codes. For example:
-
-        >>> RNASequence("ACGU")
-        >>> RNASequence(["A", "C", "G", "U"])
-        >>> RNASequence(iter(["A", "C", "G", "U"]))
-        >>> RNASequence([RNABase.ADENINE, RNABase.CYTOSINE])
-        >>> RNASequence(iter([RNABase.ADENINE, RNABase.CYTOSINE]))
-
-        A ValueError is raised if any RNA code is not valid."""
+        DNASequence. IUPAC codes are matched as in `count`.
 
+        As with `str.find`, the search may be limited to `sequence[start:end]`,
+        though the index returned is relative to the whole sequence."""
         ...
 
-    @property
-    def complement(self) -> RNASequence:
-        """The complementary RNA sequence."""
-
-    def retro_transcribe(self) -> DNASequence:
-        """Reverse transcription of the RNA sequence to a DNA sequence."""
+    def rfind(
+        self,
+        target: typing.Union[DNASequence, DNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """Find the index of the last occurance of the given DNABase or
+        DNASequence, as for `find`."""
         ...
 
-    def translate(self) -> AminoAcidSequence:
-        """Translate the RNA sequence to an amino acid sequence.
+    def index(
+        self,
+        target: typing.Union[DNASequence, DNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Like `find`, but raise ValueError if the target is not found."""
+        ...
 
-        Translation searches for the first Methionine (AUG) codon and
-        translates until it finds a stop codon.
+    def rindex(
+        self,
+        target: typing.Union[DNASequence, DNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Like `rfind`, but raise ValueError if the target is not found."""
+        ...
 
-        A ValueError is raised if no start codon is found, or not stop codon is
-        found following the start codon."""
+    def find_all(
+        self,
+        target: typing.Union[DNASequence, DNABase, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.List[int]:
+        """Find the index of every occurance of the given DNABase or
+        DNASequence, as counted by `count`."""
         ...
 
-    def __invert__(self) -> RNASequence:
-        """See `RNASequence.complement`."""
+    def finditer(
+        self,
+        target: typing.Union[DNASequence, DNABase, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> MatchIterator:
+        """Lazily find the index of every occurance, as for `find_all`."""
         ...
 
-    def __repr__(self) -> str: ...
-    def __str__(self) -> str: ...
-    def __eq__(self, other: object) -> bool: ...
-    def __ne__(self, other: object) -> bool: ...
-    def __bool__(self) -> bool:
-        """Casting to bool is False for empty sequences and True otherwise."""
+    def contains(
+        self,
+        item: typing.Union[DNASequence, DNABase, str],
+        *,
+        ambiguous: bool = False,
+    ) -> bool:
+        """Return true if the given DNABase or DNASequence is contained within
+        this sequence. IUPAC codes are matched as in `count`."""
         ...
 
-    def __add__(self, other: typing.Union[RNABase, RNASequence, str]) -> RNASequence:
-        """Create a new sequence consisting of this sequence followed by the
-        given sequence member(s)."""
+class DNASequenceView:
+    """A range of members created by `DNASequence.view`. Members are read
+    from the viewed sequence rather than copied. Views compare equal when they
+    view the same members. Views cannot be pickled; pickle `copy()` instead."""
 
-    def __radd__(self, other: typing.Union[RNABase, RNASequence, str]) -> RNASequence:
-        """Create a new sequence consisting of the given sequence member(s)
-        followed by this sequence."""
+    def copy(self) -> DNASequence:
+        """The viewed members as a new `DNASequence`."""
         ...
 
-    def __contains__(self, item: typing.Union[RNABase, RNASequence]) -> bool:
-        """Return true if the given RNABase or RNASequence is contained within
-        this sequence."""
+    @property
+    def complement(self) -> DNASequence:
+        """The complementary sequence of the viewed members."""
+        ...
+
+    def translate(
+        self,
+        table: typing.Union[GeneticCode, int, str] = 1,
+        *,
+        frame: typing.Optional[int] = None,
+        to_stop: bool = True,
+        cds: bool = False,
+        alternative_starts: bool = False,
+    ) -> AminoAcidSequence:
+        """See `DNASequence.translate`."""
         ...
 
-    def __len__(self) -> int: ...
-    def __getitem__(
-        self, key: typing.Union[int, slice]
-    ) -> typing.Union[RNABase, RNASequence]: ...
-    def __iter__(self) -> typing.Iterator[RNABase]: ...
     def count(
-        self, item: typing.Union[RNABase, RNASequence, str], overlap: bool = True
+        self,
+        item: typing.Union[DNABase, DNASequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
     ) -> int:
-        """Count the occurances of a RNABase in the sequence."""
+        """See `DNASequence.count`."""
         ...
 
     def find(
-        self, target: typing.Union[RNASequence, RNABase, str]
+        self,
+        target: typing.Union[DNASequence, DNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
     ) -> typing.Optional[int]:
-        """Find the index of the first occurance of the given RNABase or
-        RNASequence."""
+        """See `DNASequence.find`. The index returned is relative to the view."""
         ...
 
-class AminoAcidSequence:
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __len__(self) -> int: ...
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[DNABase, DNASequenceView]: ...
+    def __iter__(self) -> typing.Iterator[DNABase]: ...
+
+class PackedDNASequence:
     @classmethod
     def __new__(
         cls,
         bases: typing.Union[
             str,
-            typing.Iterable[typing.Union[str, AminoAcid]],
-            typing.Sequence[typing.Union[str, AminoAcid]],
+            PackedDNASequence,
+            DNASequence,
+            typing.Iterable[typing.Union[str, DNABase]],
+            typing.Sequence[typing.Union[str, DNABase]],
         ] = "",
-    ) -> AminoAcidSequence:
-        """A sequence of `AminoAcid`s.
+    ) -> PackedDNASequence:
+        """A `DNASequence` packed into 2 bits per base where it contains only
+        A, C, G and T, and into 4 bits per base otherwise.
 
-        `AminoAcidSequence` may be instantiated by a string of IUPAC amino acid
-        codes, or a sequence or iterable of `AminoAcid`s or IUPAC amino acid
-        codes. For example:
+        `PackedDNASequence` may be instantiated from anything a `DNASequence`
+        may be, or from another `PackedDNASequence`."""
+        ...
 
-        >>> AminoAcidSequence("MVVR")
-        >>> AminoAcidSequence(["M", "V", "V", "R"])
-        >>> AminoAcidSequence(iter(["M", "V", "V", "R"]))
-        >>> AminoAcidSequence([AminoAcid.METHIONINE, AminoAcid.VALINE])
-        >>> AminoAcidSequence(iter([AminoAcid.METHIONINE, AminoAcid.VALINE]))
+    def unpack(self) -> DNASequence:
+        """The sequence as a `DNASequence`."""
+        ...
 
-        A ValueError is raised if any amino acid code is not valid."""
+    @property
+    def bits_per_base(self) -> int:
+        """The number of bits each base is stored in, either 2 or 4."""
+        ...
+
+    @property
+    def nbytes(self) -> int:
+        """The number of bytes used to store the bases."""
+        ...
+
+    def count(
+        self,
+        item: typing.Union[PackedDNASequence, DNABase, DNASequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """See `DNASequence.count`."""
+        ...
+
+    def find(
+        self,
+        target: typing.Union[PackedDNASequence, DNABase, DNASequence, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """See `DNASequence.find`."""
+        ...
 
+    def contains(
+        self,
+        item: typing.Union[PackedDNASequence, DNABase, DNASequence, str],
+        *,
+        ambiguous: bool = False,
+    ) -> bool:
+        """See `DNASequence.contains`."""
         ...
 
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
     def __eq__(self, other: object) -> bool: ...
     def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
     def __bool__(self) -> bool:
         """Casting to bool is False for empty sequences and True otherwise."""
         ...
 
     def __add__(
-        self, other: typing.Union[AminoAcid, AminoAcidSequence, str]
-    ) -> AminoAcidSequence:
+        self, other: typing.Union[PackedDNASequence, DNABase, DNASequence, str]
+    ) -> PackedDNASequence:
         """Create a new sequence consisting of this sequence followed by the
         given sequence member(s)."""
+        ...
 
     def __radd__(
-        self, other: typing.Union[AminoAcidSequence, AminoAcid, str]
-    ) -> AminoAcidSequence:
+        self, other: typing.Union[DNABase, DNASequence, str]
+    ) -> PackedDNASequence:
         """Create a new sequence consisting of the given sequence member(s)
         followed by this sequence."""
         ...
 
-    def __contains__(self, item: typing.Union[AminoAcid, AminoAcidSequence]) -> bool:
-        """Return true if the given AminoAcid or AminoAcidSequence is contained
-        within this sequence."""
+    def __contains__(
+        self, item: typing.Union[PackedDNASequence, DNABase, DNASequence]
+    ) -> bool:
+        """Return true if the given sequence is contained within this
+        sequence."""
         ...
 
     def __len__(self) -> int: ...
     def __getitem__(
         self, key: typing.Union[int, slice]
-    ) -> typing.Union[AminoAcid, AminoAcidSequence]: ...
-    def __iter__(self) -> typing.Iterator[AminoAcid]: ...
+    ) -> typing.Union[DNABase, PackedDNASequence]: ...
+    def __iter__(self) -> typing.Iterator[DNABase]: ...
+
+class MutableDNASequence:
+    @classmethod
+    def __new__(
+        cls,
+        sequence: typing.Union[
+            str,
+            DNASequence,
+            MutableDNASequence,
+            typing.Iterable[typing.Union[str, DNABase]],
+            typing.Sequence[typing.Union[str, DNABase]],
+        ] = "",
+    ) -> MutableDNASequence:
+        """A `DNASequence` that may be edited in place, for building sequences
+        a member at a time.
+
+        `MutableDNASequence` may be instantiated from anything a `DNASequence` may
+        be, and converted back to one with `freeze`."""
+        ...
+
+    def freeze(self) -> DNASequence:
+        """A `DNASequence` of the current members."""
+        ...
+
+    def append(self, member: typing.Union[DNABase, str]) -> None:
+        """Add a member to the end of the sequence."""
+        ...
+
+    def extend(
+        self, sequence: typing.Union[DNASequence, MutableDNASequence, str]
+    ) -> None:
+        """Add the members of the given sequence to the end of the sequence."""
+        ...
+
+    def insert(self, index: int, member: typing.Union[DNABase, str]) -> None:
+        """Insert a member before the given index, as for `list.insert`."""
+        ...
+
+    def reverse_complement_inplace(self) -> None:
+        """Reverse complement the sequence in place."""
+        ...
+
     def count(
         self,
-        item: typing.Union[AminoAcid, str, AminoAcidSequence],
+        item: typing.Union[DNABase, DNASequence, MutableDNASequence, str],
         overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
     ) -> int:
-        """Count the occurances of an AminoAcid in the sequence."""
+        """See `DNASequence.count`."""
         ...
 
     def find(
-        self, target: typing.Union[AminoAcidSequence, AminoAcid, str]
+        self,
+        target: typing.Union[DNABase, DNASequence, MutableDNASequence, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
     ) -> typing.Optional[int]:
-        """Find the index of the first occurance of the given AminoAcid or
-        AminoAcidSequence."""
+        """See `DNASequence.find`."""
         ...
+
+    def contains(
+        self,
+        item: typing.Union[DNABase, DNASequence, MutableDNASequence, str],
+        *,
+        ambiguous: bool = False,
+    ) -> bool:
+        """See `DNASequence.contains`."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __bool__(self) -> bool:
+        """Casting to bool is False for empty sequences and True otherwise."""
+        ...
+
+    def __contains__(
+        self, item: typing.Union[DNABase, DNASequence, MutableDNASequence, str]
+    ) -> bool:
+        """See `DNASequence.__contains__`."""
+        ...
+
+    def __len__(self) -> int: ...
+    def __iadd__(
+        self, other: typing.Union[DNASequence, MutableDNASequence, str]
+    ) -> MutableDNASequence:
+        """See `extend`."""
+        ...
+
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[DNABase, MutableDNASequence]: ...
+    def __setitem__(
+        self,
+        key: typing.Union[int, slice],
+        value: typing.Union[DNABase, DNASequence, MutableDNASequence, str],
+    ) -> None:
+        """Replace the members at the given index or slice. As for `list`, an
+        extended slice must be assigned as many members as it selects."""
+        ...
+
+    def __delitem__(self, key: typing.Union[int, slice]) -> None: ...
+    def __iter__(self) -> typing.Iterator[DNABase]: ...
+
+class RNASequence:
+    @classmethod
+    def __new__(
+        cls,
+        bases: typing.Union[
+            str,
+            typing.Iterable[typing.Union[str, RNABase]],
+            typing.Sequence[typing.Union[str, RNABase]],
+        ] = "",
+    ) -> RNASequence:
+        """A sequence of `RNABase`s.
+
+        `RNASequence` may be instantiated by a string of IUPAC RNA codes, or a
+        sequence or iterable of `RNABase`s or IUPAC RNA codes. For example:
+
+        >>> RNASequence("ACGU")
+        >>> RNASequence(["A", "C", "G", "U"])
+        >>> RNASequence(iter(["A", "C", "G", "U"]))
+        >>> RNASequence([RNABase.ADENINE, RNABase.CYTOSINE])
+        >>> RNASequence(iter([RNABase.ADENINE, RNABase.CYTOSINE]))
+
+        A ValueError is raised if any RNA code is not valid."""
+
+        ...
+
+    @classmethod
+    def from_bytes(cls, data: bytes) -> RNASequence:
+        """Decode a sequence encoded by `to_bytes`. A ValueError is raised if
+        the bytes are not a valid encoding."""
+        ...
+
+    def to_bytes(self) -> bytes:
+        """A compact binary encoding of the sequence, as used when pickling."""
+        ...
+
+    def view(
+        self,
+        start: typing.Optional[int] = None,
+        stop: typing.Optional[int] = None,
+        step: typing.Optional[int] = None,
+    ) -> RNASequenceView:
+        """A view of `sequence[start:stop:step]` that refers to this sequence
+        rather than copying its members."""
+        ...
+
+    @property
+    def complement(self) -> RNASequence:
+        """The complementary RNA sequence."""
+
+    def retro_transcribe(self) -> DNASequence:
+        """Reverse transcription of the RNA sequence to a DNA sequence."""
+        ...
+
+    def translate(
+        self,
+        table: typing.Union[GeneticCode, int, str] = 1,
+        *,
+        frame: typing.Optional[int] = None,
+        to_stop: bool = True,
+        cds: bool = False,
+        alternative_starts: bool = False,
+    ) -> AminoAcidSequence:
+        """Translate the RNA sequence to an amino acid sequence.
+
+        By default, translation searches for the first Methionine codon and
+        translates until it finds a stop codon, using the standard genetic code
+        unless another NCBI translation table is given. A ValueError is raised
+        if no start codon is found, or no stop codon is found following the
+        start codon.
+
+        If `alternative_starts` is True, the search accepts any start codon of
+        the table, such as CUG or GUG, and translates it as Methionine.
+
+        If `frame` (0, 1 or 2) is given, translation begins at that offset
+        without searching for a start codon, and a missing stop codon is not
+        an error.
+
+        If `to_stop` is False, translation continues through stop codons,
+        emitting `AminoAcid.STOP`, until the end of the sequence.
+
+        If `cds` is True, the sequence is validated as a complete coding
+        sequence: it must begin with a start codon of the table (translated as
+        Methionine), be a multiple of three in length, and end with its only
+        stop codon, which is not included in the result. A ValueError is
+        raised otherwise."""
+        ...
+
+    def translate_frames(
+        self, table: typing.Union[GeneticCode, int, str] = 1
+    ) -> typing.List[AminoAcidSequence]:
+        """Translate all six reading frames of the RNA sequence: frames 0, 1
+        and 2 of the sequence followed by frames 0, 1 and 2 of its reverse
+        complement. Each frame is translated through stop codons, as with
+        `translate(frame=..., to_stop=False)`, except that ambiguous codons
+        are translated as `AminoAcid.ANY` and codons containing gaps as
+        `AminoAcid.GAP` rather than raising."""
+        ...
+
+    def find_orfs(
+        self,
+        table: typing.Union[GeneticCode, int, str] = 1,
+        *,
+        min_length: int = 0,
+        nested: bool = False,
+        alternative_starts: bool = False,
+    ) -> typing.List[OpenReadingFrame]:
+        """Find open reading frames on both strands of the RNA sequence,
+        ordered by their position on the forward strand.
+
+        An open reading frame runs from a start codon to the next in-frame
+        stop codon. Reading frames without a stop codon are not reported.
+        Ambiguous codons within an open reading frame are translated as
+        `AminoAcid.ANY`.
+
+        `min_length` is the minimum number of amino acids in a reported open
+        reading frame. If `nested` is True, in-frame start codons within an
+        open reading frame begin further open reading frames sharing its stop
+        codon; otherwise only the longest is reported.
+
+        If `alternative_starts` is True, any start codon of the table begins an
+        open reading frame, and is translated as Methionine."""
+        ...
+
+    def reverse_complement(self) -> RNASequence:
+        """The reverse complement of the RNA sequence, as read from the
+        opposite strand. IUPAC ambiguity codes are complemented as in
+        `RNABase.complement`."""
+        ...
+
+    def reverse(self) -> RNASequence:
+        """The RNA sequence in reverse order, without complementing."""
+        ...
+
+    def __invert__(self) -> RNASequence:
+        """See `RNASequence.complement`."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __reduce__(self) -> typing.Tuple[typing.Any, ...]:
+        """Pickle the sequence as its `to_bytes` encoding, rebuilt with
+        `from_bytes`. Sequences are frozen, so there is no `__setstate__` to
+        restore state into an existing sequence and `__reduce__` takes the
+        place of `__getstate__` and `__setstate__`."""
+        ...
+
+    def __lt__(self, other: RNASequence) -> bool:
+        """Sequences are ordered by their codes, as strings would be."""
+        ...
+
+    def __le__(self, other: RNASequence) -> bool: ...
+    def __gt__(self, other: RNASequence) -> bool: ...
+    def __ge__(self, other: RNASequence) -> bool: ...
+    def __bool__(self) -> bool:
+        """Casting to bool is False for empty sequences and True otherwise."""
+        ...
+
+    def __add__(self, other: typing.Union[RNABase, RNASequence, str]) -> RNASequence:
+        """Create a new sequence consisting of this sequence followed by the
+        given sequence member(s)."""
+
+    def __radd__(self, other: typing.Union[RNABase, RNASequence, str]) -> RNASequence:
+        """Create a new sequence consisting of the given sequence member(s)
+        followed by this sequence."""
+        ...
+
+    def __contains__(self, item: typing.Union[RNABase, RNASequence]) -> bool:
+        """Return true if the given RNABase or RNASequence is contained within
+        this sequence."""
+        ...
+
+    def __len__(self) -> int: ...
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[RNABase, RNASequence]: ...
+    def __iter__(self) -> typing.Iterator[RNABase]: ...
+    def __reversed__(self) -> typing.Iterator[RNABase]: ...
+    def count(
+        self,
+        item: typing.Union[RNABase, RNASequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Count the occurances of a RNABase in the sequence.
+
+        If `ambiguous` is True, IUPAC codes match any base they share, as in
+        `RNABase.matches`. For example, R matches A or G, and N matches any base."""
+        ...
+
+    def find(
+        self,
+        target: typing.Union[RNASequence, RNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """Find the index of the first occurance of the given RNABase or
+        RNASequence. IUPAC codes are matched as in `count`.
+
+        As with `str.find`, the search may be limited to `sequence[start:end]`,
+        though the index returned is relative to the whole sequence."""
+        ...
+
+    def rfind(
+        self,
+        target: typing.Union[RNASequence, RNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """Find the index of the last occurance of the given RNABase or
+        RNASequence, as for `find`."""
+        ...
+
+    def index(
+        self,
+        target: typing.Union[RNASequence, RNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Like `find`, but raise ValueError if the target is not found."""
+        ...
+
+    def rindex(
+        self,
+        target: typing.Union[RNASequence, RNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Like `rfind`, but raise ValueError if the target is not found."""
+        ...
+
+    def find_all(
+        self,
+        target: typing.Union[RNASequence, RNABase, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.List[int]:
+        """Find the index of every occurance of the given RNABase or
+        RNASequence, as counted by `count`."""
+        ...
+
+    def finditer(
+        self,
+        target: typing.Union[RNASequence, RNABase, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> MatchIterator:
+        """Lazily find the index of every occurance, as for `find_all`."""
+        ...
+
+    def contains(
+        self,
+        item: typing.Union[RNASequence, RNABase, str],
+        *,
+        ambiguous: bool = False,
+    ) -> bool:
+        """Return true if the given RNABase or RNASequence is contained within
+        this sequence. IUPAC codes are matched as in `count`."""
+        ...
+
+class RNASequenceView:
+    """A range of members created by `RNASequence.view`. Members are read
+    from the viewed sequence rather than copied. Views compare equal when they
+    view the same members. Views cannot be pickled; pickle `copy()` instead."""
+
+    def copy(self) -> RNASequence:
+        """The viewed members as a new `RNASequence`."""
+        ...
+
+    @property
+    def complement(self) -> RNASequence:
+        """The complementary sequence of the viewed members."""
+        ...
+
+    def translate(
+        self,
+        table: typing.Union[GeneticCode, int, str] = 1,
+        *,
+        frame: typing.Optional[int] = None,
+        to_stop: bool = True,
+        cds: bool = False,
+        alternative_starts: bool = False,
+    ) -> AminoAcidSequence:
+        """See `RNASequence.translate`."""
+        ...
+
+    def count(
+        self,
+        item: typing.Union[RNABase, RNASequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """See `RNASequence.count`."""
+        ...
+
+    def find(
+        self,
+        target: typing.Union[RNASequence, RNABase, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """See `RNASequence.find`. The index returned is relative to the view."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __len__(self) -> int: ...
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[RNABase, RNASequenceView]: ...
+    def __iter__(self) -> typing.Iterator[RNABase]: ...
+
+class MutableRNASequence:
+    @classmethod
+    def __new__(
+        cls,
+        sequence: typing.Union[
+            str,
+            RNASequence,
+            MutableRNASequence,
+            typing.Iterable[typing.Union[str, RNABase]],
+            typing.Sequence[typing.Union[str, RNABase]],
+        ] = "",
+    ) -> MutableRNASequence:
+        """A `RNASequence` that may be edited in place, for building sequences
+        a member at a time.
+
+        `MutableRNASequence` may be instantiated from anything a `RNASequence` may
+        be, and converted back to one with `freeze`."""
+        ...
+
+    def freeze(self) -> RNASequence:
+        """A `RNASequence` of the current members."""
+        ...
+
+    def append(self, member: typing.Union[RNABase, str]) -> None:
+        """Add a member to the end of the sequence."""
+        ...
+
+    def extend(
+        self, sequence: typing.Union[RNASequence, MutableRNASequence, str]
+    ) -> None:
+        """Add the members of the given sequence to the end of the sequence."""
+        ...
+
+    def insert(self, index: int, member: typing.Union[RNABase, str]) -> None:
+        """Insert a member before the given index, as for `list.insert`."""
+        ...
+
+    def reverse_complement_inplace(self) -> None:
+        """Reverse complement the sequence in place."""
+        ...
+
+    def count(
+        self,
+        item: typing.Union[RNABase, RNASequence, MutableRNASequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """See `RNASequence.count`."""
+        ...
+
+    def find(
+        self,
+        target: typing.Union[RNABase, RNASequence, MutableRNASequence, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """See `RNASequence.find`."""
+        ...
+
+    def contains(
+        self,
+        item: typing.Union[RNABase, RNASequence, MutableRNASequence, str],
+        *,
+        ambiguous: bool = False,
+    ) -> bool:
+        """See `RNASequence.contains`."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __bool__(self) -> bool:
+        """Casting to bool is False for empty sequences and True otherwise."""
+        ...
+
+    def __contains__(
+        self, item: typing.Union[RNABase, RNASequence, MutableRNASequence, str]
+    ) -> bool:
+        """See `RNASequence.__contains__`."""
+        ...
+
+    def __len__(self) -> int: ...
+    def __iadd__(
+        self, other: typing.Union[RNASequence, MutableRNASequence, str]
+    ) -> MutableRNASequence:
+        """See `extend`."""
+        ...
+
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[RNABase, MutableRNASequence]: ...
+    def __setitem__(
+        self,
+        key: typing.Union[int, slice],
+        value: typing.Union[RNABase, RNASequence, MutableRNASequence, str],
+    ) -> None:
+        """Replace the members at the given index or slice. As for `list`, an
+        extended slice must be assigned as many members as it selects."""
+        ...
+
+    def __delitem__(self, key: typing.Union[int, slice]) -> None: ...
+    def __iter__(self) -> typing.Iterator[RNABase]: ...
+
+class AminoAcidSequence:
+    @classmethod
+    def __new__(
+        cls,
+        bases: typing.Union[
+            str,
+            typing.Iterable[typing.Union[str, AminoAcid]],
+            typing.Sequence[typing.Union[str, AminoAcid]],
+        ] = "",
+    ) -> AminoAcidSequence:
+        """A sequence of `AminoAcid`s.
+
+        `AminoAcidSequence` may be instantiated by a string of IUPAC amino acid
+        codes, or a sequence or iterable of `AminoAcid`s or IUPAC amino acid
+        codes. For example:
+
+        >>> AminoAcidSequence("MVVR")
+        >>> AminoAcidSequence(["M", "V", "V", "R"])
+        >>> AminoAcidSequence(iter(["M", "V", "V", "R"]))
+        >>> AminoAcidSequence([AminoAcid.METHIONINE, AminoAcid.VALINE])
+        >>> AminoAcidSequence(iter([AminoAcid.METHIONINE, AminoAcid.VALINE]))
+
+        A ValueError is raised if any amino acid code is not valid."""
+
+        ...
+
+    @classmethod
+    def from_bytes(cls, data: bytes) -> AminoAcidSequence:
+        """Decode a sequence encoded by `to_bytes`. A ValueError is raised if
+        the bytes are not a valid encoding."""
+        ...
+
+    def to_bytes(self) -> bytes:
+        """A compact binary encoding of the sequence, as used when pickling."""
+        ...
+
+    def view(
+        self,
+        start: typing.Optional[int] = None,
+        stop: typing.Optional[int] = None,
+        step: typing.Optional[int] = None,
+    ) -> AminoAcidSequenceView:
+        """A view of `sequence[start:stop:step]` that refers to this sequence
+        rather than copying its members."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __reduce__(self) -> typing.Tuple[typing.Any, ...]:
+        """Pickle the sequence as its `to_bytes` encoding, rebuilt with
+        `from_bytes`. Sequences are frozen, so there is no `__setstate__` to
+        restore state into an existing sequence and `__reduce__` takes the
+        place of `__getstate__` and `__setstate__`."""
+        ...
+
+    def __lt__(self, other: AminoAcidSequence) -> bool:
+        """Sequences are ordered by their codes, as strings would be."""
+        ...
+
+    def __le__(self, other: AminoAcidSequence) -> bool: ...
+    def __gt__(self, other: AminoAcidSequence) -> bool: ...
+    def __ge__(self, other: AminoAcidSequence) -> bool: ...
+    def __bool__(self) -> bool:
+        """Casting to bool is False for empty sequences and True otherwise."""
+        ...
+
+    def __add__(
+        self, other: typing.Union[AminoAcid, AminoAcidSequence, str]
+    ) -> AminoAcidSequence:
+        """Create a new sequence consisting of this sequence followed by the
+        given sequence member(s)."""
+
+    def __radd__(
+        self, other: typing.Union[AminoAcidSequence, AminoAcid, str]
+    ) -> AminoAcidSequence:
+        """Create a new sequence consisting of the given sequence member(s)
+        followed by this sequence."""
+        ...
+
+    def __contains__(self, item: typing.Union[AminoAcid, AminoAcidSequence]) -> bool:
+        """Return true if the given AminoAcid or AminoAcidSequence is contained
+        within this sequence."""
+        ...
+
+    def __len__(self) -> int: ...
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[AminoAcid, AminoAcidSequence]: ...
+    def __iter__(self) -> typing.Iterator[AminoAcid]: ...
+    def count(
+        self,
+        item: typing.Union[AminoAcid, AminoAcidSequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Count the occurances of an AminoAcid in the sequence.
+
+        If `ambiguous` is True, the B, Z, J and X codes match any amino acid
+        they share, as in `AminoAcid.matches`."""
+        ...
+
+    def find(
+        self,
+        target: typing.Union[AminoAcidSequence, AminoAcid, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """Find the index of the first occurance of the given AminoAcid or
+        AminoAcidSequence. Ambiguity codes are matched as in `count`.
+
+        As with `str.find`, the search may be limited to `sequence[start:end]`,
+        though the index returned is relative to the whole sequence."""
+        ...
+
+    def rfind(
+        self,
+        target: typing.Union[AminoAcidSequence, AminoAcid, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """Find the index of the last occurance of the given AminoAcid or
+        AminoAcidSequence, as for `find`."""
+        ...
+
+    def index(
+        self,
+        target: typing.Union[AminoAcidSequence, AminoAcid, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Like `find`, but raise ValueError if the target is not found."""
+        ...
+
+    def rindex(
+        self,
+        target: typing.Union[AminoAcidSequence, AminoAcid, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """Like `rfind`, but raise ValueError if the target is not found."""
+        ...
+
+    def find_all(
+        self,
+        target: typing.Union[AminoAcidSequence, AminoAcid, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.List[int]:
+        """Find the index of every occurance of the given AminoAcid or
+        AminoAcidSequence, as counted by `count`."""
+        ...
+
+    def finditer(
+        self,
+        target: typing.Union[AminoAcidSequence, AminoAcid, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> MatchIterator:
+        """Lazily find the index of every occurance, as for `find_all`."""
+        ...
+
+    def contains(
+        self,
+        item: typing.Union[AminoAcidSequence, AminoAcid, str],
+        *,
+        ambiguous: bool = False,
+    ) -> bool:
+        """Return true if the given AminoAcid or AminoAcidSequence is contained
+        within this sequence. Ambiguity codes are matched as in `count`."""
+        ...
+
+class AminoAcidSequenceView:
+    """A range of members created by `AminoAcidSequence.view`. Members are read
+    from the viewed sequence rather than copied. Views compare equal when they
+    view the same members. Views cannot be pickled; pickle `copy()` instead."""
+
+    def copy(self) -> AminoAcidSequence:
+        """The viewed members as a new `AminoAcidSequence`."""
+        ...
+
+    def count(
+        self,
+        item: typing.Union[AminoAcid, AminoAcidSequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """See `AminoAcidSequence.count`."""
+        ...
+
+    def find(
+        self,
+        target: typing.Union[AminoAcidSequence, AminoAcid, str],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """See `AminoAcidSequence.find`. The index returned is relative to the view."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __len__(self) -> int: ...
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[AminoAcid, AminoAcidSequenceView]: ...
+    def __iter__(self) -> typing.Iterator[AminoAcid]: ...
+
+class MutableAminoAcidSequence:
+    @classmethod
+    def __new__(
+        cls,
+        sequence: typing.Union[
+            str,
+            AminoAcidSequence,
+            MutableAminoAcidSequence,
+            typing.Iterable[typing.Union[str, AminoAcid]],
+            typing.Sequence[typing.Union[str, AminoAcid]],
+        ] = "",
+    ) -> MutableAminoAcidSequence:
+        """An `AminoAcidSequence` that may be edited in place, for building
+        sequences a member at a time.
+
+        `MutableAminoAcidSequence` may be instantiated from anything an
+        `AminoAcidSequence` may be, and converted back to one with `freeze`."""
+        ...
+
+    def freeze(self) -> AminoAcidSequence:
+        """An `AminoAcidSequence` of the current members."""
+        ...
+
+    def append(self, member: typing.Union[AminoAcid, str]) -> None:
+        """Add a member to the end of the sequence."""
+        ...
+
+    def extend(
+        self, sequence: typing.Union[AminoAcidSequence, MutableAminoAcidSequence, str]
+    ) -> None:
+        """Add the members of the given sequence to the end of the sequence."""
+        ...
+
+    def insert(self, index: int, member: typing.Union[AminoAcid, str]) -> None:
+        """Insert a member before the given index, as for `list.insert`."""
+        ...
+
+    def count(
+        self,
+        item: typing.Union[AminoAcid, AminoAcidSequence, MutableAminoAcidSequence, str],
+        overlap: bool = False,
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> int:
+        """See `AminoAcidSequence.count`."""
+        ...
+
+    def find(
+        self,
+        target: typing.Union[
+            AminoAcid, AminoAcidSequence, MutableAminoAcidSequence, str
+        ],
+        start: typing.Optional[int] = None,
+        end: typing.Optional[int] = None,
+        *,
+        ambiguous: bool = False,
+    ) -> typing.Optional[int]:
+        """See `AminoAcidSequence.find`."""
+        ...
+
+    def contains(
+        self,
+        item: typing.Union[AminoAcid, AminoAcidSequence, MutableAminoAcidSequence, str],
+        *,
+        ambiguous: bool = False,
+    ) -> bool:
+        """See `AminoAcidSequence.contains`."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __bool__(self) -> bool:
+        """Casting to bool is False for empty sequences and True otherwise."""
+        ...
+
+    def __contains__(
+        self,
+        item: typing.Union[
+            AminoAcid, AminoAcidSequence, MutableAminoAcidSequence, str
+        ],
+    ) -> bool:
+        """See `AminoAcidSequence.__contains__`."""
+        ...
+
+    def __len__(self) -> int: ...
+    def __iadd__(
+        self, other: typing.Union[AminoAcidSequence, MutableAminoAcidSequence, str]
+    ) -> MutableAminoAcidSequence:
+        """See `extend`."""
+        ...
+
+    def __getitem__(
+        self, key: typing.Union[int, slice]
+    ) -> typing.Union[AminoAcid, MutableAminoAcidSequence]: ...
+    def __setitem__(
+        self,
+        key: typing.Union[int, slice],
+        value: typing.Union[
+            AminoAcid, AminoAcidSequence, MutableAminoAcidSequence, str
+        ],
+    ) -> None:
+        """Replace the members at the given index or slice. As for `list`, an
+        extended slice must be assigned as many members as it selects."""
+        ...
+
+    def __delitem__(self, key: typing.Union[int, slice]) -> None: ...
+    def __iter__(self) -> typing.Iterator[AminoAcid]: ...
+
+class PatternMatcher:
+    @classmethod
+    def __new__(
+        cls,
+        patterns: typing.Sequence[
+            typing.Union[DNASequence, RNASequence, AminoAcidSequence]
+        ],
+        *,
+        both_strands: bool = False,
+        ambiguous: bool = False,
+    ) -> PatternMatcher:
+        """A compiled Aho-Corasick matcher, which finds every occurance of many
+        patterns in a single pass over a sequence. For example:
+
+        >>> matcher = PatternMatcher([DNASequence("GAATTC"), DNASequence("GGATCC")])
+        >>> matcher.find_all(genome)
+
+        Patterns must all be of the same sequence type, and may not be empty.
+
+        If `both_strands` is True, the reverse complement of each nucleotide
+        pattern is also searched for, so that matches on the opposite strand
+        are found.
+
+        If `ambiguous` is True, IUPAC codes in the patterns and the searched
+        sequence match any member they share, as for `DNASequence.find`. A
+        ValueError is raised if a pattern stands for too many sequences."""
+        ...
+
+    @property
+    def both_strands(self) -> bool: ...
+    @property
+    def ambiguous(self) -> bool: ...
+    def find_all(
+        self, sequence: typing.Union[DNASequence, RNASequence, AminoAcidSequence]
+    ) -> typing.List[typing.Tuple[int, int, int]]:
+        """Find every match in the sequence, as a tuple of the index of the
+        pattern, the position of the match and the strand it was found on: 1
+        for the pattern itself or -1 for its reverse complement. Matches are
+        ordered by position.
+
+        A TypeError is raised if the sequence is not of the same type as the
+        patterns."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """The number of patterns."""
+        ...
+
+class FMIndex:
+    @classmethod
+    def __new__(
+        cls, sequences: typing.Union[DNASequence, typing.Sequence[DNASequence]]
+    ) -> FMIndex:
+        """A full-text index of one or more DNA sequences, built once so that
+        exact matches can then be counted and located in time proportional to
+        the length of the query rather than of the indexed sequences. For
+        example:
+
+        >>> index = FMIndex([chromosome_1, chromosome_2])
+        >>> index.locate(DNASequence("GAATTC"))
+
+        Matches never span two of the indexed sequences."""
+        ...
+
+    @classmethod
+    def load(cls, path: typing.Union[str, os.PathLike[str]]) -> FMIndex:
+        """Load an index written by `save`, without rebuilding it. A ValueError
+        is raised if the file is not a valid index."""
+        ...
+
+    def save(self, path: typing.Union[str, os.PathLike[str]]) -> None:
+        """Write the index to disk, including its suffix array and BWT, so that
+        it can be loaded without being built again."""
+        ...
+
+    @property
+    def bwt(self) -> str:
+        """The Burrows-Wheeler transform of the indexed sequences, each of which
+        is terminated by `$`."""
+        ...
+
+    @property
+    def suffix_array(self) -> typing.List[int]:
+        """The suffix array of the indexed sequences, each of which is
+        terminated by `$`."""
+        ...
+
+    def count(self, sequence: typing.Union[DNASequence, str]) -> int:
+        """Count the matches of the sequence, which may overlap. A ValueError
+        is raised if the sequence is empty."""
+        ...
+
+    def locate(
+        self, sequence: typing.Union[DNASequence, str]
+    ) -> typing.List[typing.Tuple[int, int]]:
+        """Find every match of the sequence, as a tuple of the index of the
+        indexed sequence it was found in and the position within it. Matches
+        are ordered by sequence and then by position."""
+        ...
+
+    def contains(self, sequence: typing.Union[DNASequence, str]) -> bool: ...
+    def __contains__(self, sequence: typing.Union[DNASequence, str]) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __len__(self) -> int:
+        """The number of indexed sequences."""
+        ...
+
+class Motif:
+    @classmethod
+    def __new__(cls, pattern: str) -> Motif:
+        """A protein motif, compiled from a regular expression over amino acid
+        codes. For example:
+
+        >>> motif = Motif("N[^P][ST][^P]")
+        >>> motif.find_all(protein)
+
+        The syntax supports:
+
+        - amino acid codes, where ambiguous codes such as `B` match themselves
+          and any of the amino acids they stand for
+        - `.`, matching any amino acid but neither a stop nor a gap
+        - character classes such as `[ST]` and exclusions such as `[^P]`
+        - repeats `?`, `*`, `+`, `{n}`, `{n,}` and `{n,m}`
+        - anchors `^` and `$`, matching the start and end of the sequence
+
+        A ValueError is raised if the pattern is invalid or could match an
+        empty sequence."""
+        ...
+
+    @classmethod
+    def from_prosite(cls, pattern: str) -> Motif:
+        """Compile a motif from a PROSITE pattern, such as the N-glycosylation
+        site `N-{P}-[ST]-{P}`. Elements are separated by `-` and may be:
+
+        - an amino acid code, or `x` for any amino acid
+        - a character class such as `[ST]` or an exclusion such as `{P}`
+        - followed by a repeat such as `x(2)` or `x(2,4)`
+
+        The pattern may start with `<` or end with `>` to anchor it to the
+        start or end of the sequence, and may end with a period."""
+        ...
+
+    @property
+    def pattern(self) -> str: ...
+    def find_all(
+        self,
+        sequence: typing.Union[AminoAcidSequence, str],
+        overlap: bool = False,
+    ) -> typing.List[typing.Tuple[int, int]]:
+        """Find the start and end of each match in the sequence. The longest
+        match at each position is reported, and matches do not overlap unless
+        `overlap` is True."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class SubstitutionMatrix:
+    """Scores for substituting one amino acid or nucleotide for another, for
+    use with `align`.
+
+    Built in matrices may be instantiated by name, ignoring case:
+
+    - "BLOSUM45", "BLOSUM50", "BLOSUM62", "BLOSUM80" and "BLOSUM90"
+    - "PAM30", "PAM70" and "PAM250"
+    - "NUC.4.4", which scores IUPAC ambiguity codes
+
+    For example:
+
+    >>> matrix = SubstitutionMatrix("BLOSUM62")
+    >>> matrix[AminoAcid.ALANINE, AminoAcid.GLYCINE]
+    0
+
+    A ValueError is raised if no matrix matches."""
+
+    @classmethod
+    def __new__(cls, name: str) -> SubstitutionMatrix: ...
+    @classmethod
+    def load(cls, path: typing.Union[str, os.PathLike[str]]) -> SubstitutionMatrix:
+        """Load a matrix in the NCBI format, as distributed with BLAST. The
+        matrix scores nucleotides if all of its codes are nucleotide codes, and
+        amino acids otherwise.
+
+        A ValueError is raised if the file is malformed."""
+        ...
+
+    @classmethod
+    def nucleotide(
+        cls, match_score: int = 1, mismatch_score: int = -1
+    ) -> SubstitutionMatrix:
+        """A nucleotide matrix scoring each IUPAC code. Scores between
+        ambiguous codes are the mean score of the pairs of bases they stand
+        for, rounded to the nearest integer."""
+        ...
+
+    @property
+    def name(self) -> str: ...
+    @property
+    def alphabet(self) -> str:
+        """The codes scored by the matrix, in order."""
+        ...
+
+    def __getitem__(
+        self,
+        key: typing.Tuple[
+            typing.Union[AminoAcid, DNABase, RNABase, str],
+            typing.Union[AminoAcid, DNABase, RNABase, str],
+        ],
+    ) -> int:
+        """The score for substituting one member for another. Nucleotide
+        matrices score uracil as thymine.
+
+        A KeyError is raised if the matrix has no score for either member."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class PairwiseAlignment:
+    """The result of aligning two sequences. See `align`."""
+
+    @property
+    def score(self) -> int: ...
+    @property
+    def first(self) -> typing.Union[DNASequence, RNASequence, AminoAcidSequence]:
+        """The aligned part of the first sequence, with gaps inserted."""
+        ...
+
+    @property
+    def second(self) -> typing.Union[DNASequence, RNASequence, AminoAcidSequence]:
+        """The aligned part of the second sequence, with gaps inserted."""
+        ...
+
+    @property
+    def first_start(self) -> int: ...
+    @property
+    def first_end(self) -> int: ...
+    @property
+    def second_start(self) -> int: ...
+    @property
+    def second_end(self) -> int: ...
+    @property
+    def cigar(self) -> str:
+        """The alignment as a CIGAR string, treating the first sequence as the
+        reference: `M` for aligned members, `D` for gaps in the second sequence
+        and `I` for gaps in the first."""
+        ...
+
+    def __len__(self) -> int:
+        """The number of columns in the alignment."""
+        ...
+
+    def __str__(self) -> str:
+        """The aligned sequences on separate lines, with `|` between identical
+        members and `.` between mismatches."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+def align(
+    first: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+    second: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+    *,
+    mode: str = "global",
+    match_score: int = 1,
+    mismatch_score: int = -1,
+    gap_open: int = -2,
+    gap_extend: int = -1,
+    matrix: typing.Optional[SubstitutionMatrix] = None,
+) -> PairwiseAlignment:
+    """Find the highest scoring alignment of two sequences of the same type.
+    For example:
+
+    >>> alignment = align(DNASequence("GATTACA"), DNASequence("GCATGCA"))
+    >>> print(alignment)
+    GATTACA
+    |..|.||
+    GCATGCA
+
+    The mode may be:
+
+    - "global", aligning the whole of both sequences (Needleman-Wunsch)
+    - "local", aligning the most similar parts of each sequence
+      (Smith-Waterman)
+    - "semi-global", aligning the overlap of the two sequences without
+      penalising gaps at either end
+
+    A gap of length k scores `gap_open + (k - 1) * gap_extend`.
+
+    If a substitution matrix is given, aligned members are scored by the
+    matrix rather than by `match_score` and `mismatch_score`:
+
+    >>> align(first, second, matrix=SubstitutionMatrix("BLOSUM62"))
+
+    A TypeError is raised if the sequences are of different types, and a
+    ValueError if the matrix has no score for one of their members."""
+    ...
+
+class Alignment:
+    @classmethod
+    def __new__(
+        cls,
+        sequences: typing.Union[
+            typing.Iterable[DNASequence],
+            typing.Iterable[RNASequence],
+            typing.Iterable[AminoAcidSequence],
+        ],
+        ids: typing.Optional[typing.List[str]] = None,
+    ) -> Alignment:
+        """A multiple sequence alignment: gapped sequences of the same type and
+        length, one per row. For example:
+
+        >>> alignment = Alignment([DNASequence("AC-GT"), DNASequence("ACTGT")])
+
+        Rows are named "seq1", "seq2" and so on unless `ids` are given.
+
+        A TypeError is raised if the sequences are of different types, and a
+        ValueError if they are of different lengths."""
+        ...
+
+    @property
+    def ids(self) -> typing.List[str]: ...
+    @property
+    def sequences(
+        self,
+    ) -> typing.Union[
+        typing.List[DNASequence],
+        typing.List[RNASequence],
+        typing.List[AminoAcidSequence],
+    ]:
+        """The gapped sequence of each row."""
+        ...
+
+    @property
+    def width(self) -> int:
+        """The number of columns in the alignment."""
+        ...
+
+    def composition(
+        self,
+    ) -> typing.Union[
+        typing.List[typing.Dict[DNABase, int]],
+        typing.List[typing.Dict[RNABase, int]],
+        typing.List[typing.Dict[AminoAcid, int]],
+    ]:
+        """The number of each member, including gaps, in each column."""
+        ...
+
+    def consensus(self) -> typing.Union[DNASequence, RNASequence, AminoAcidSequence]:
+        """The most common member of each column, including gaps. Ties are
+        broken in favour of the member appearing first in the column."""
+        ...
+
+    def gap_fractions(self) -> typing.List[float]:
+        """The fraction of each column that is gaps."""
+        ...
+
+    def __getitem__(
+        self,
+        key: typing.Union[
+            int,
+            slice,
+            typing.Tuple[typing.Union[int, slice], typing.Union[int, slice]],
+        ],
+    ) -> typing.Union[
+        DNABase,
+        RNABase,
+        AminoAcid,
+        DNASequence,
+        RNASequence,
+        AminoAcidSequence,
+        Alignment,
+    ]:
+        """Select rows, or rows and columns. A single row or column is returned
+        as a sequence and a single cell as a member, otherwise the selection is
+        returned as an alignment. For example, the first ten columns:
+
+        >>> alignment[:, :10]"""
+        ...
+
+    def __len__(self) -> int:
+        """The number of rows in the alignment."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+def read_alignment(
+    path: typing.Union[str, os.PathLike[str]],
+    format: str = "fasta",
+    kind: typing.Optional[
+        typing.Union[
+            typing.Type[DNASequence],
+            typing.Type[RNASequence],
+            typing.Type[AminoAcidSequence],
+        ]
+    ] = None,
+) -> Alignment:
+    """Read an alignment from a file.
+
+    The format may be "fasta", "clustal", "stockholm" or "phylip" (relaxed,
+    sequential or interleaved). Sequences are parsed as `kind`, which defaults
+    to `DNASequence`. For example:
+
+    >>> read_alignment("globins.sto", "stockholm", kind=AminoAcidSequence)
+
+    A ValueError is raised if the file is malformed, contains an invalid code
+    or its sequences are of different lengths."""
+    ...
+
+def write_alignment(
+    path: typing.Union[str, os.PathLike[str]],
+    alignment: Alignment,
+    format: str = "fasta",
+) -> None:
+    """Write an alignment to a file in one of the formats read by
+    `read_alignment`."""
+    ...
+
+def align_multiple(
+    sequences: typing.Union[
+        typing.Iterable[DNASequence],
+        typing.Iterable[RNASequence],
+        typing.Iterable[AminoAcidSequence],
+    ],
+    ids: typing.Optional[typing.List[str]] = None,
+    *,
+    match_score: int = 1,
+    mismatch_score: int = -1,
+    gap_open: int = -2,
+    gap_extend: int = -1,
+    matrix: typing.Optional[SubstitutionMatrix] = None,
+) -> Alignment:
+    """Align sequences of the same type by progressive alignment.
+
+    Every pair of sequences is first aligned (in parallel) to estimate their
+    distances, from which a guide tree is built by UPGMA. Profiles of the
+    aligned sequences are then aligned following the tree, most similar
+    first. For example:
+
+    >>> align_multiple(proteins, matrix=SubstitutionMatrix("BLOSUM62"))
+
+    Members are scored as by `align`, and a pair of profile columns scores the
+    mean over every pair of their members. Any gaps in the sequences are
+    removed before aligning them.
+
+    A TypeError is raised if the sequences are of different types, and a
+    ValueError if the matrix has no score for one of their members."""
+    ...
+
+def consensus(
+    sequences: typing.Union[
+        Alignment,
+        typing.Iterable[DNASequence],
+        typing.Iterable[RNASequence],
+        typing.Iterable[AminoAcidSequence],
+    ],
+    *,
+    threshold: float = 0.5,
+    gaps: str = "ignore",
+    min_depth: int = 1,
+) -> typing.Union[DNASequence, RNASequence, AminoAcidSequence]:
+    """The consensus of an alignment or of sequences of the same type and
+    length.
+
+    Each column is summarised by its most common members, taking as many as
+    needed for their combined frequency to reach `threshold` along with any
+    as common as the last of them. Several members are combined into the
+    ambiguous member standing for them all. For example:
+
+    >>> consensus([DNASequence("ACGT"), DNASequence("GCGT")])
+    <DNASequence: RCGT>
+
+    Amino acids are combined into B, Z or J where possible and X otherwise,
+    so the default threshold gives a majority-rule consensus with X, B or Z
+    for ties. Ambiguous members in the sequences count as an equal share of
+    each member they stand for.
+
+    Columns of only gaps are gaps. If `gaps` is "include", gaps count towards
+    the frequencies and the column is a gap if they reach the threshold,
+    otherwise they are ignored. Columns with fewer than `min_depth` members
+    are N for nucleotides and X for amino acids.
+
+    A ValueError is raised if the sequences are of different lengths."""
+    ...
+
+def hamming_distance(
+    first: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+    second: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+    *,
+    ambiguous: bool = False,
+) -> int:
+    """The number of positions at which two sequences of the same type and
+    length differ. For example:
+
+    >>> hamming_distance(DNASequence("ACGT"), DNASequence("ANGA"))
+    2
+
+    If `ambiguous` is True, members are equal if they could stand for the
+    same member, so that N is equal to any base:
+
+    >>> hamming_distance(DNASequence("ACGT"), DNASequence("ANGA"), ambiguous=True)
+    1
+
+    A TypeError is raised if the sequences are of different types, and a
+    ValueError if they are of different lengths."""
+    ...
+
+def levenshtein_distance(
+    first: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+    second: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+    *,
+    max_distance: typing.Optional[int] = None,
+    ambiguous: bool = False,
+) -> typing.Optional[int]:
+    """The least number of substitutions, insertions and deletions needed to
+    turn one sequence into another of the same type. For example:
+
+    >>> levenshtein_distance(DNASequence("GATTACA"), DNASequence("GATACA"))
+    1
+
+    If `max_distance` is given, None is returned for distances greater than
+    it, which is much faster to find for similar sequences such as barcodes.
+    Members are compared as by `hamming_distance`.
+
+    A TypeError is raised if the sequences are of different types."""
+    ...
+
+def distance_matrix(
+    sequences: typing.Union[
+        typing.Iterable[DNASequence],
+        typing.Iterable[RNASequence],
+        typing.Iterable[AminoAcidSequence],
+    ],
+    metric: str = "hamming",
+    *,
+    max_distance: typing.Optional[int] = None,
+    ambiguous: bool = False,
+) -> typing.List[typing.List[typing.Optional[int]]]:
+    """The distance between every pair of sequences of the same type, computed
+    in parallel.
+
+    The metric may be "hamming" or "levenshtein". Distances greater than
+    `max_distance` are None. For example:
+
+    >>> distance_matrix(barcodes, "levenshtein", max_distance=2)
+
+    A TypeError is raised if the sequences are of different types, and a
+    ValueError if the metric is "hamming" and they are of different
+    lengths."""
+    ...
+
+class FastaRecord:
+    @classmethod
+    def __new__(
+        cls,
+        id: str,
+        sequence: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+        description: str = "",
+    ) -> FastaRecord:
+        """A single FASTA record.
+
+        The header line is split on the first whitespace into `id` and
+        `description`. For example:
+
+        >>> FastaRecord("chr1", DNASequence("ACGT"), "chromosome 1")
+
+        A TypeError is raised if the sequence is not a DNASequence,
+        RNASequence or AminoAcidSequence."""
+        ...
+
+    @property
+    def id(self) -> str:
+        """Identifier of the record, the first word of the header."""
+        ...
+
+    @property
+    def description(self) -> str:
+        """The remainder of the header following the identifier."""
+        ...
+
+    @property
+    def sequence(self) -> typing.Union[DNASequence, RNASequence, AminoAcidSequence]:
+        """The sequence of the record."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+class FastaReader:
+    """An iterator over the records of a FASTA file. See `read_fasta`."""
+
+    def __iter__(self) -> FastaReader: ...
+    def __next__(self) -> FastaRecord: ...
+
+def read_fasta(
+    path: typing.Union[str, os.PathLike[str]],
+    kind: typing.Optional[
+        typing.Union[
+            typing.Type[DNASequence],
+            typing.Type[RNASequence],
+            typing.Type[AminoAcidSequence],
+        ]
+    ] = None,
+) -> FastaReader:
+    """Lazily read the records of a FASTA file.
+
+    Sequences are parsed as `kind`, which defaults to `DNASequence`. Records
+    are read one at a time, so large files are never loaded fully into memory.
+    Lower case (soft-masked) codes are accepted. For example:
+
+    >>> for record in read_fasta("proteins.fa", kind=AminoAcidSequence):
+    ...     print(record.id, len(record.sequence))
+
+    A ValueError is raised if the file is malformed or contains an invalid
+    code."""
+    ...
+
+def write_fasta(
+    path: typing.Union[str, os.PathLike[str]],
+    records: typing.Iterable[FastaRecord],
+    line_width: typing.Optional[int] = 60,
+) -> None:
+    """Write records to a FASTA file.
+
+    Sequences are wrapped every `line_width` codes, or written on a single line
+    if `line_width` is None."""
+    ...
+
+class FastqRecord:
+    @classmethod
+    def __new__(
+        cls,
+        id: str,
+        sequence: typing.Union[
+            DNASequence,
+            str,
+            typing.Iterable[typing.Union[str, DNABase]],
+            typing.Sequence[typing.Union[str, DNABase]],
+        ],
+        qualities: typing.Sequence[int],
+        description: str = "",
+    ) -> FastqRecord:
+        """A single FASTQ record, a DNA sequence with a Phred quality score per
+        base. For example:
+
+        >>> FastqRecord("read1", "ACGT", [40, 40, 30, 20])
+
+        A ValueError is raised if the sequence and qualities differ in
+        length."""
+        ...
+
+    @property
+    def id(self) -> str:
+        """Identifier of the record, the first word of the header."""
+        ...
+
+    @property
+    def description(self) -> str:
+        """The remainder of the header following the identifier."""
+        ...
+
+    @property
+    def sequence(self) -> DNASequence:
+        """The bases of the record, without their qualities."""
+        ...
+
+    @property
+    def qualities(self) -> typing.List[int]:
+        """The Phred quality score of each base."""
+        ...
+
+    @property
+    def mean_quality(self) -> typing.Optional[float]:
+        """The mean quality score, or None for empty records."""
+        ...
+
+    @property
+    def min_quality(self) -> typing.Optional[int]:
+        """The lowest quality score, or None for empty records."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __bool__(self) -> bool:
+        """Casting to bool is False for empty records and True otherwise."""
+        ...
+
+    def __len__(self) -> int: ...
+    @typing.overload
+    def __getitem__(self, key: int) -> DNABase: ...
+    @typing.overload
+    def __getitem__(self, key: slice) -> FastqRecord:
+        """Slicing a record slices its bases and qualities together."""
+        ...
+
+class FastqReader:
+    """An iterator over the records of a FASTQ file. See `read_fastq`."""
+
+    def __iter__(self) -> FastqReader: ...
+    def __next__(self) -> FastqRecord: ...
+
+def read_fastq(
+    path: typing.Union[str, os.PathLike[str]], offset: int = 33
+) -> FastqReader:
+    """Lazily read the records of a FASTQ file.
+
+    Quality scores are decoded as Phred+33 or Phred+64 according to `offset`.
+
+    A ValueError is raised if the file is malformed, contains an invalid code
+    or the offset is not 33 or 64."""
+    ...
+
+def write_fastq(
+    path: typing.Union[str, os.PathLike[str]],
+    records: typing.Iterable[FastqRecord],
+    offset: int = 33,
+) -> None:
+    """Write records to a FASTQ file, encoding qualities as Phred+33 or
+    Phred+64 according to `offset`."""
+    ...
+
+class Feature:
+    @classmethod
+    def __new__(
+        cls,
+        kind: str,
+        start: int,
+        end: int,
+        strand: typing.Optional[int] = None,
+        qualifiers: typing.Optional[typing.Dict[str, typing.Any]] = None,
+    ) -> Feature:
+        """A feature annotating the half-open region `start..end` of a
+        `SequenceRecord`. For example:
+
+        >>> Feature("gene", 0, 300, strand=1, qualifiers={"gene": "lacZ"})
+
+        A ValueError is raised if `start` is after `end` or `strand` is not 1,
+        -1 or None."""
+        ...
+
+    @property
+    def kind(self) -> str:
+        """The kind of feature, such as "gene" or "CDS"."""
+        ...
+
+    @property
+    def start(self) -> int: ...
+    @property
+    def end(self) -> int: ...
+    @property
+    def strand(self) -> typing.Optional[int]: ...
+    @property
+    def qualifiers(self) -> typing.Dict[str, typing.Any]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __ne__(self, other: object) -> bool: ...
+    def __len__(self) -> int: ...
+
+class SequenceRecord:
+    @classmethod
+    def __new__(
+        cls,
+        sequence: typing.Union[DNASequence, RNASequence, AminoAcidSequence],
+        id: str = "",
+        description: str = "",
+        annotations: typing.Optional[typing.Dict[str, typing.Any]] = None,
+        features: typing.Optional[typing.Iterable[Feature]] = None,
+    ) -> SequenceRecord:
+        """A sequence annotated with an identifier, description, free-form
+        annotations and features. For example:
+
+        >>> SequenceRecord(DNASequence("ATGGCC"), "orf1", annotations={"note": "x"})
+
+        Records derived from this record through `complement`, `transcribe`,
+        `retro_transcribe`, `translate` or slicing keep its id and description
+        and a shallow copy of its annotations. Features are kept by
+        `complement`, `transcribe` and `retro_transcribe`, dropped by
+        `translate`, and kept by contiguous slices only when they lie entirely
+        within the slice.
+
+        A TypeError is raised if the sequence is not a DNASequence,
+        RNASequence or AminoAcidSequence."""
+        ...
+
+    id: str
+    description: str
+
+    @property
+    def sequence(self) -> typing.Union[DNASequence, RNASequence, AminoAcidSequence]:
+        """The annotated sequence."""
+        ...
+
+    @property
+    def annotations(self) -> typing.Dict[str, typing.Any]: ...
+    @property
+    def features(self) -> typing.List[Feature]: ...
+    @property
+    def complement(self) -> SequenceRecord:
+        """A record of the complementary sequence."""
+        ...
+
+    def transcribe(self) -> SequenceRecord:
+        """A record of the transcribed sequence."""
+        ...
+
+    def retro_transcribe(self) -> SequenceRecord:
+        """A record of the reverse transcribed sequence."""
+        ...
+
+    def translate(self, *args: typing.Any, **kwargs: typing.Any) -> SequenceRecord:
+        """A record of the translated sequence. Arguments are passed to the
+        sequence's `translate`."""
+        ...
+
+    def reverse_complement(self) -> SequenceRecord:
+        """A record of the reverse complement sequence. Features are mirrored
+        onto the opposite strand.
+
+        A ValueError is raised if a feature extends beyond the sequence."""
+        ...
+
+    def __invert__(self) -> SequenceRecord:
+        """See `SequenceRecord.complement`."""
+        ...
+
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __len__(self) -> int: ...
+    @typing.overload
+    def __getitem__(
+        self, key: int
+    ) -> typing.Union[DNABase, RNABase, AminoAcid]: ...
+    @typing.overload
+    def __getitem__(self, key: slice) -> SequenceRecord: ...
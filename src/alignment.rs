@@ -0,0 +1,302 @@
+use crate::aminoacid::AminoAcid;
+use crate::aminoacidsequence::AminoAcidSequence;
+use crate::dnabase::DNABase;
+use crate::dnasequence::DNASequence;
+use crate::member::{Member, MemberOrMembers};
+use crate::mutablesequence;
+use crate::rnabase::RNABase;
+use crate::rnasequence::RNASequence;
+use crate::sequence::{AnySequence, SequenceKind};
+use crate::utils::IntOrSlice;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The members an alignment may be made of, and the sequences its rows are
+// returned as.
+pub trait Row:
+    Member + Copy + Eq + Hash + Send + Sync + TryFrom<char, Error = PyErr> + 'static
+{
+    fn code(&self) -> char;
+    fn sequence(py: Python<'_>, members: Vec<Self>) -> PyResult<Bound<'_, PyAny>>;
+    fn rows(rows: Vec<Vec<Self>>) -> Rows;
+}
+
+macro_rules! impl_row {
+    ($member_type:ty, $sequence_type:ident) => {
+        impl Row for $member_type {
+            fn code(&self) -> char {
+                char::from(self)
+            }
+
+            fn sequence(py: Python<'_>, members: Vec<Self>) -> PyResult<Bound<'_, PyAny>> {
+                Ok(Bound::new(py, $sequence_type::from(members))?.into_any())
+            }
+
+            fn rows(rows: Vec<Vec<Self>>) -> Rows {
+                Rows::$sequence_type(rows)
+            }
+        }
+    };
+}
+
+impl_row!(DNABase, DNASequence);
+impl_row!(RNABase, RNASequence);
+impl_row!(AminoAcid, AminoAcidSequence);
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone)]
+pub enum Rows {
+    DNASequence(Vec<Vec<DNABase>>),
+    RNASequence(Vec<Vec<RNABase>>),
+    AminoAcidSequence(Vec<Vec<AminoAcid>>),
+}
+
+// Evaluates the expression with the rows of any kind of alignment.
+macro_rules! with_rows {
+    ($rows:expr, $name:ident => $body:expr) => {
+        match $rows {
+            Rows::DNASequence($name) => $body,
+            Rows::RNASequence($name) => $body,
+            Rows::AminoAcidSequence($name) => $body,
+        }
+    };
+}
+
+impl Rows {
+    pub fn parse(kind: SequenceKind, rows: Vec<String>) -> PyResult<Self> {
+        fn parse<T: Row>(rows: Vec<String>) -> PyResult<Vec<Vec<T>>> {
+            rows.iter()
+                .map(|codes| codes.chars().map(T::try_from).collect())
+                .collect()
+        }
+
+        Ok(match kind {
+            SequenceKind::DNASequence => Self::DNASequence(parse(rows)?),
+            SequenceKind::RNASequence => Self::RNASequence(parse(rows)?),
+            SequenceKind::AminoAcidSequence => Self::AminoAcidSequence(parse(rows)?),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        with_rows!(self, rows => rows.len())
+    }
+
+    pub fn width(&self) -> usize {
+        with_rows!(self, rows => rows.first().map_or(0, Vec::len))
+    }
+
+    pub fn codes(&self, row: usize) -> String {
+        with_rows!(self, rows => rows[row].iter().map(Row::code).collect())
+    }
+}
+
+impl TryFrom<Vec<AnySequence<'_>>> for Rows {
+    type Error = PyErr;
+
+    fn try_from(sequences: Vec<AnySequence<'_>>) -> PyResult<Self> {
+        let mismatch = || PyTypeError::new_err("sequences must all be of the same type");
+
+        macro_rules! rows {
+            ($variant:ident) => {
+                Rows::$variant(
+                    sequences
+                        .iter()
+                        .map(|sequence| match sequence {
+                            AnySequence::$variant(sequence) => Ok(sequence.sequence.clone()),
+                            _ => Err(mismatch()),
+                        })
+                        .collect::<PyResult<_>>()?,
+                )
+            };
+        }
+
+        Ok(match sequences.first() {
+            None | Some(AnySequence::DNASequence(_)) => rows!(DNASequence),
+            Some(AnySequence::RNASequence(_)) => rows!(RNASequence),
+            Some(AnySequence::AminoAcidSequence(_)) => rows!(AminoAcidSequence),
+        })
+    }
+}
+
+fn column<T: Copy>(rows: &[Vec<T>], index: usize) -> Vec<T> {
+    rows.iter().map(|row| row[index]).collect()
+}
+
+fn select<T: Copy>(rows: &[Vec<T>], columns: &[usize]) -> Vec<Vec<T>> {
+    rows.iter()
+        .map(|row| columns.iter().map(|column| row[*column]).collect())
+        .collect()
+}
+
+#[derive(FromPyObject)]
+pub enum AlignmentIndex<'py> {
+    Cell(IntOrSlice<'py>, IntOrSlice<'py>),
+    Row(IntOrSlice<'py>),
+}
+
+#[pyclass(frozen)]
+pub struct Alignment {
+    #[pyo3(get)]
+    pub ids: Vec<String>,
+    pub rows: Rows,
+}
+
+#[pymethods]
+impl Alignment {
+    #[new]
+    #[pyo3(signature = (sequences, ids = None))]
+    fn __new__(sequences: Vec<AnySequence<'_>>, ids: Option<Vec<String>>) -> PyResult<Self> {
+        Self::new(ids, Rows::try_from(sequences)?)
+    }
+
+    #[getter]
+    fn get_sequences<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyAny>>> {
+        with_rows!(&self.rows, rows => rows
+            .iter()
+            .map(|row| Row::sequence(py, row.clone()))
+            .collect())
+    }
+
+    #[getter]
+    fn get_width(&self) -> usize {
+        self.rows.width()
+    }
+
+    // The number of each member in each column, including gaps.
+    fn composition<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyAny>>> {
+        with_rows!(&self.rows, rows => (0..self.rows.width())
+            .map(|index| {
+                let mut counts = HashMap::new();
+                for member in column(rows, index) {
+                    *counts.entry(member).or_insert(0) += 1;
+                }
+                Ok(counts.into_pyobject(py)?.into_any())
+            })
+            .collect())
+    }
+
+    fn gap_fractions(&self) -> Vec<f64> {
+        fn gap_fractions<T: Row>(rows: &[Vec<T>], width: usize) -> Vec<f64> {
+            (0..width)
+                .map(|index| {
+                    let gaps = rows.iter().filter(|row| row[index] == T::GAP).count();
+                    gaps as f64 / rows.len() as f64
+                })
+                .collect()
+        }
+
+        with_rows!(&self.rows, rows => gap_fractions(rows, self.rows.width()))
+    }
+
+    // Ties are broken in favour of the member found first in the column.
+    fn consensus<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        with_rows!(&self.rows, rows => {
+            let consensus = (0..self.rows.width())
+                .map(|index| {
+                    let column = column(rows, index);
+                    let mut best = (0, column[0]);
+                    for member in &column {
+                        let count = column.iter().filter(|other| *other == member).count();
+                        if count > best.0 {
+                            best = (count, *member);
+                        }
+                    }
+                    best.1
+                })
+                .collect();
+
+            Row::sequence(py, consensus)
+        })
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index: AlignmentIndex<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (rows, columns) = match index {
+            AlignmentIndex::Row(rows) => (rows, None),
+            AlignmentIndex::Cell(rows, columns) => (rows, Some(columns)),
+        };
+
+        // Only slices select more than one position to be listed.
+        let indices = |length: usize, name: &str, index: &IntOrSlice| match index {
+            IntOrSlice::Int(index) => Ok(MemberOrMembers::Member(mutablesequence::index(
+                length, *index, name,
+            )?)),
+            IntOrSlice::Slice(slice) => {
+                let indices = slice.indices(length as isize)?;
+                Ok::<_, PyErr>(MemberOrMembers::Sequence(
+                    (0..indices.slicelength)
+                        .map(|i| (indices.start + i as isize * indices.step) as usize)
+                        .collect(),
+                ))
+            }
+        };
+        let rows = indices(self.rows.len(), "row", &rows)?;
+        let columns = match columns {
+            Some(columns) => indices(self.rows.width(), "column", &columns)?,
+            None => MemberOrMembers::Sequence((0..self.rows.width()).collect()),
+        };
+
+        with_rows!(&self.rows, members => match (rows, columns) {
+            (MemberOrMembers::Member(row), MemberOrMembers::Member(column)) => {
+                Ok(members[row][column].into_pyobject(py)?.into_any())
+            }
+            (MemberOrMembers::Member(row), MemberOrMembers::Sequence(columns)) => {
+                Row::sequence(py, columns.iter().map(|column| members[row][*column]).collect())
+            }
+            (MemberOrMembers::Sequence(rows), MemberOrMembers::Member(column)) => {
+                Row::sequence(py, rows.iter().map(|row| members[*row][column]).collect())
+            }
+            (MemberOrMembers::Sequence(rows), MemberOrMembers::Sequence(columns)) => {
+                let selected = rows.iter().map(|row| members[*row].clone()).collect::<Vec<_>>();
+                Ok(Bound::new(
+                    py,
+                    Self {
+                        ids: rows.iter().map(|row| self.ids[*row].clone()).collect(),
+                        rows: Row::rows(select(&selected, &columns)),
+                    },
+                )?
+                .into_any())
+            }
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Alignment: {} sequences, {} columns>",
+            self.rows.len(),
+            self.rows.width()
+        )
+    }
+}
+
+impl Alignment {
+    pub fn new(ids: Option<Vec<String>>, rows: Rows) -> PyResult<Self> {
+        let ids = match ids {
+            Some(ids) if ids.len() != rows.len() => {
+                return Err(PyValueError::new_err(
+                    "ids must be the same length as sequences",
+                ))
+            }
+            Some(ids) => ids,
+            None => (1..=rows.len()).map(|row| format!("seq{row}")).collect(),
+        };
+
+        let width = rows.width();
+        if with_rows!(&rows, rows => rows.iter().any(|row| row.len() != width)) {
+            return Err(PyValueError::new_err(
+                "sequences must all be the same length",
+            ));
+        }
+
+        Ok(Self { ids, rows })
+    }
+}
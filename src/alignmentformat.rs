@@ -0,0 +1,262 @@
+use crate::alignment::{Alignment, Rows};
+use crate::fasta::write_wrapped;
+use crate::sequence::SequenceKind;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+const BLOCK_WIDTH: usize = 60;
+
+#[derive(Clone, Copy)]
+enum Format {
+    Fasta,
+    Clustal,
+    Stockholm,
+    Phylip,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = PyErr;
+
+    fn try_from(format: &str) -> PyResult<Self> {
+        match format {
+            "fasta" => Ok(Self::Fasta),
+            "clustal" => Ok(Self::Clustal),
+            "stockholm" => Ok(Self::Stockholm),
+            "phylip" => Ok(Self::Phylip),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown alignment format \"{format}\""
+            ))),
+        }
+    }
+}
+
+// Rows of an alignment by id in the order first seen, where the codes of a
+// row may be split across several blocks.
+#[derive(Default)]
+struct Blocks {
+    ids: Vec<String>,
+    rows: Vec<String>,
+}
+
+impl Blocks {
+    fn push(&mut self, id: &str, codes: &str) {
+        let index = match self.ids.iter().position(|other| other == id) {
+            Some(index) => index,
+            None => {
+                self.ids.push(id.to_string());
+                self.rows.push(String::new());
+                self.rows.len() - 1
+            }
+        };
+
+        self.rows[index].extend(codes.chars().filter(|code| !code.is_whitespace()));
+    }
+}
+
+fn parse_fasta(text: &str) -> PyResult<Blocks> {
+    let mut blocks = Blocks::default();
+
+    for line in text.lines() {
+        match line.strip_prefix('>') {
+            Some(header) => {
+                let id = header.split_whitespace().next().unwrap_or_default();
+                blocks.ids.push(id.to_string());
+                blocks.rows.push(String::new());
+            }
+            None if line.trim().is_empty() || line.starts_with(';') => continue,
+            None => match blocks.rows.last_mut() {
+                Some(row) => row.push_str(line.trim()),
+                None => return Err(PyValueError::new_err("invalid FASTA: expected header")),
+            },
+        }
+    }
+
+    Ok(blocks)
+}
+
+// Lines starting with whitespace mark conserved columns and are ignored, as
+// are the cumulative counts some writers append to each line.
+fn parse_clustal(text: &str) -> PyResult<Blocks> {
+    let mut lines = text.lines().skip_while(|line| line.trim().is_empty());
+    if !lines.next().is_some_and(|line| line.starts_with("CLUSTAL")) {
+        return Err(PyValueError::new_err("invalid Clustal: expected header"));
+    }
+
+    let mut blocks = Blocks::default();
+    for line in lines {
+        if line.trim().is_empty() || line.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        if let (Some(id), Some(codes)) = (fields.next(), fields.next()) {
+            blocks.push(id, codes);
+        }
+    }
+
+    Ok(blocks)
+}
+
+fn parse_stockholm(text: &str) -> PyResult<Blocks> {
+    let mut lines = text.lines().skip_while(|line| line.trim().is_empty());
+    if !lines
+        .next()
+        .is_some_and(|line| line.starts_with("# STOCKHOLM"))
+    {
+        return Err(PyValueError::new_err("invalid Stockholm: expected header"));
+    }
+
+    let mut blocks = Blocks::default();
+    for line in lines {
+        match line.trim_end() {
+            "//" => return Ok(blocks),
+            line if line.trim().is_empty() || line.starts_with('#') => continue,
+            line => match line.split_once(char::is_whitespace) {
+                Some((id, codes)) => blocks.push(id, codes),
+                None => blocks.push(line, ""),
+            },
+        }
+    }
+
+    Err(PyValueError::new_err(
+        "invalid Stockholm: expected terminator",
+    ))
+}
+
+// Relaxed PHYLIP, where ids are separated from codes by whitespace rather
+// than padded to ten characters. Rows are written on one line each or
+// interleaved, with ids only on the first block.
+fn parse_phylip(text: &str) -> PyResult<Blocks> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    let header = lines
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(str::parse::<usize>)
+        .collect::<Result<Vec<_>, _>>();
+
+    let (count, width) = match header.as_deref() {
+        Ok([count, width]) => (*count, *width),
+        _ => return Err(PyValueError::new_err("invalid PHYLIP: expected header")),
+    };
+
+    let invalid = || {
+        PyValueError::new_err(format!(
+            "invalid PHYLIP: expected {count} sequences of length {width}"
+        ))
+    };
+
+    let mut blocks = Blocks::default();
+    for (index, line) in lines.enumerate() {
+        match index < count {
+            true => {
+                let line = line.trim_start();
+                match line.split_once(char::is_whitespace) {
+                    Some((id, codes)) => blocks.push(id, codes),
+                    None => blocks.push(line, ""),
+                }
+            }
+            // Lines beyond those declared, or rows lost to repeated ids, leave
+            // no row for an interleaved block to continue.
+            false => {
+                let row = index
+                    .checked_rem(count)
+                    .and_then(|row| blocks.rows.get_mut(row))
+                    .ok_or_else(invalid)?;
+                row.extend(line.chars().filter(|code| !code.is_whitespace()));
+            }
+        }
+    }
+
+    match blocks.rows.len() == count && blocks.rows.iter().all(|row| row.len() == width) {
+        true => Ok(blocks),
+        false => Err(invalid()),
+    }
+}
+
+fn write_blocks(file: &mut impl Write, alignment: &Alignment, block_width: usize) -> PyResult<()> {
+    let padding = alignment.ids.iter().map(String::len).max().unwrap_or(0) + 1;
+    let rows = (0..alignment.ids.len())
+        .map(|row| alignment.rows.codes(row))
+        .collect::<Vec<_>>();
+
+    for start in (0..alignment.rows.width()).step_by(block_width) {
+        if start > 0 {
+            writeln!(file)?;
+        }
+
+        let end = (start + block_width).min(alignment.rows.width());
+        for (id, codes) in alignment.ids.iter().zip(&rows) {
+            writeln!(file, "{id:padding$}{}", &codes[start..end])?;
+        }
+    }
+
+    Ok(())
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, format = "fasta", kind = None))]
+pub fn read_alignment(
+    path: PathBuf,
+    format: &str,
+    kind: Option<Bound<'_, PyType>>,
+) -> PyResult<Alignment> {
+    let format = Format::try_from(format)?;
+    let kind = match kind {
+        Some(kind) => SequenceKind::try_from(&kind)?,
+        None => SequenceKind::DNASequence,
+    };
+
+    let text = fs::read_to_string(path)?;
+    let blocks = match format {
+        Format::Fasta => parse_fasta(&text)?,
+        Format::Clustal => parse_clustal(&text)?,
+        Format::Stockholm => parse_stockholm(&text)?,
+        Format::Phylip => parse_phylip(&text)?,
+    };
+
+    // Clustal and Stockholm files are usually written in upper case, but
+    // lower case is used for unaligned regions by some tools.
+    let rows = blocks
+        .rows
+        .into_iter()
+        .map(|codes| codes.to_ascii_uppercase())
+        .collect();
+
+    Alignment::new(Some(blocks.ids), Rows::parse(kind, rows)?)
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, alignment, format = "fasta"))]
+pub fn write_alignment(path: PathBuf, alignment: &Alignment, format: &str) -> PyResult<()> {
+    let format = Format::try_from(format)?;
+    let mut file = BufWriter::new(File::create(path)?);
+
+    match format {
+        Format::Fasta => {
+            for (row, id) in alignment.ids.iter().enumerate() {
+                writeln!(file, ">{id}")?;
+                write_wrapped(&mut file, &alignment.rows.codes(row), Some(BLOCK_WIDTH))?;
+            }
+        }
+        Format::Clustal => {
+            writeln!(file, "CLUSTAL W multiple sequence alignment\n")?;
+            write_blocks(&mut file, alignment, BLOCK_WIDTH)?;
+        }
+        Format::Stockholm => {
+            writeln!(file, "# STOCKHOLM 1.0\n")?;
+            write_blocks(&mut file, alignment, alignment.rows.width().max(1))?;
+            writeln!(file, "//")?;
+        }
+        Format::Phylip => {
+            writeln!(file, "{} {}", alignment.ids.len(), alignment.rows.width())?;
+            write_blocks(&mut file, alignment, alignment.rows.width().max(1))?;
+        }
+    }
+
+    Ok(file.flush()?)
+}
@@ -1,9 +1,12 @@
 use crate::aminoacidsequence::AminoAcidSequence;
 use crate::aminoacidsequence::AminoAcidSequenceInput;
-use crate::member::Member;
+use crate::dnabase::DNABase;
+use crate::geneticcode::{GeneticCode, GeneticCodeInput};
+use crate::member::{Member, MemberOrCode};
 use crate::rnabase::RNABase;
 use pyo3::create_exception;
 use pyo3::prelude::*;
+use pyo3::types::{PyFrozenSet, PyType};
 use std::fmt;
 
 create_exception!(haem, StopTranslation, pyo3::exceptions::PyException);
@@ -14,30 +17,19 @@ enum Codon {
     Chars(char, char, char),
 }
 
-impl TryFrom<Codon> for AminoAcid {
-    type Error = PyErr;
-
-    fn try_from(codon: Codon) -> PyResult<AminoAcid> {
-        match codon {
-            Codon::Bases(first, second, third) => (&first, &second, &third).try_into(),
-            Codon::Chars(first, second, third) => (first, second, third).try_into(),
+impl Codon {
+    fn translate(self, table: &GeneticCode) -> PyResult<AminoAcid> {
+        match self {
+            Codon::Bases(first, second, third) => table.translate((&first, &second, &third)),
+            Codon::Chars(first, second, third) => table.translate((
+                &RNABase::try_from(first)?,
+                &RNABase::try_from(second)?,
+                &RNABase::try_from(third)?,
+            )),
         }
     }
 }
 
-impl TryFrom<(char, char, char)> for AminoAcid {
-    type Error = PyErr;
-
-    fn try_from(codon: (char, char, char)) -> PyResult<AminoAcid> {
-        (
-            &RNABase::try_from(codon.0)?,
-            &RNABase::try_from(codon.1)?,
-            &RNABase::try_from(codon.2)?,
-        )
-            .try_into()
-    }
-}
-
 #[derive(FromPyObject)]
 enum CodeOrCodon {
     Code(char),
@@ -45,13 +37,11 @@ enum CodeOrCodon {
     CodonStr(String),
 }
 
-impl TryFrom<CodeOrCodon> for AminoAcid {
-    type Error = PyErr;
-
-    fn try_from(code_or_codon: CodeOrCodon) -> PyResult<AminoAcid> {
-        Ok(match code_or_codon {
+impl CodeOrCodon {
+    fn translate(self, table: &GeneticCode) -> PyResult<AminoAcid> {
+        Ok(match self {
             CodeOrCodon::Code(code) => code.try_into()?,
-            CodeOrCodon::Codon(codon) => codon.try_into()?,
+            CodeOrCodon::Codon(codon) => codon.translate(table)?,
             CodeOrCodon::CodonStr(codon) if codon.len() == 3 => {
                 // `codon` is an owned String (from Python). Use chars() as before.
                 let bases = codon
@@ -59,7 +49,7 @@ impl TryFrom<CodeOrCodon> for AminoAcid {
                     .map(RNABase::try_from)
                     .collect::<PyResult<Vec<_>>>()?;
 
-                (&bases[0], &bases[1], &bases[2]).try_into()?
+                table.translate((&bases[0], &bases[1], &bases[2]))?
             }
             _ => {
                 return Err(pyo3::exceptions::PyValueError::new_err(
@@ -74,10 +64,12 @@ impl TryFrom<CodeOrCodon> for AminoAcid {
     eq,
     eq_int,
     frozen,
+    hash,
+    module = "haem",
     rename_all = "SCREAMING_SNAKE_CASE",
     from_py_object
 )]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum AminoAcid {
     Alanine,
     AsparticAcidAsparagine,
@@ -102,15 +94,131 @@ pub enum AminoAcid {
     Any,
     Tyrosine,
     GlutamineGlutamicAcid,
+    Stop,
+    Selenocysteine,
+    Pyrrolysine,
+    LeucineIsoleucine,
+    Gap,
 }
 
-impl Member for AminoAcid {}
+// Every member in declaration order, which is checked when compiling so that
+// the index of a member always finds that member in MEMBERS.
+const MEMBERS: [AminoAcid; 28] = [
+    AminoAcid::Alanine,
+    AminoAcid::AsparticAcidAsparagine,
+    AminoAcid::Cysteine,
+    AminoAcid::AsparticAcid,
+    AminoAcid::GlutamicAcid,
+    AminoAcid::Phenylalanine,
+    AminoAcid::Glycine,
+    AminoAcid::Histidine,
+    AminoAcid::Isoleucine,
+    AminoAcid::Lysine,
+    AminoAcid::Leucine,
+    AminoAcid::Methionine,
+    AminoAcid::Asparagine,
+    AminoAcid::Proline,
+    AminoAcid::Glutamine,
+    AminoAcid::Arginine,
+    AminoAcid::Serine,
+    AminoAcid::Threonine,
+    AminoAcid::Valine,
+    AminoAcid::Tryptophan,
+    AminoAcid::Any,
+    AminoAcid::Tyrosine,
+    AminoAcid::GlutamineGlutamicAcid,
+    AminoAcid::Stop,
+    AminoAcid::Selenocysteine,
+    AminoAcid::Pyrrolysine,
+    AminoAcid::LeucineIsoleucine,
+    AminoAcid::Gap,
+];
+
+const _: () = {
+    let mut index = 0;
+    while index < MEMBERS.len() {
+        assert!(MEMBERS[index] as usize == index);
+        index += 1;
+    }
+};
+
+// Any stands for every amino acid, but not for a stop or a gap.
+const ANY: [AminoAcid; 22] = [
+    AminoAcid::Alanine,
+    AminoAcid::Cysteine,
+    AminoAcid::AsparticAcid,
+    AminoAcid::GlutamicAcid,
+    AminoAcid::Phenylalanine,
+    AminoAcid::Glycine,
+    AminoAcid::Histidine,
+    AminoAcid::Isoleucine,
+    AminoAcid::Lysine,
+    AminoAcid::Leucine,
+    AminoAcid::Methionine,
+    AminoAcid::Asparagine,
+    AminoAcid::Proline,
+    AminoAcid::Glutamine,
+    AminoAcid::Arginine,
+    AminoAcid::Serine,
+    AminoAcid::Threonine,
+    AminoAcid::Valine,
+    AminoAcid::Tryptophan,
+    AminoAcid::Tyrosine,
+    AminoAcid::Selenocysteine,
+    AminoAcid::Pyrrolysine,
+];
+
+impl Member for AminoAcid {
+    const GAP: Self = Self::Gap;
+
+    fn members() -> &'static [Self] {
+        &MEMBERS
+    }
+
+    fn expand(&self) -> &'static [Self] {
+        match self {
+            Self::Alanine => &[Self::Alanine],
+            Self::AsparticAcidAsparagine => &[Self::AsparticAcid, Self::Asparagine],
+            Self::Cysteine => &[Self::Cysteine],
+            Self::AsparticAcid => &[Self::AsparticAcid],
+            Self::GlutamicAcid => &[Self::GlutamicAcid],
+            Self::Phenylalanine => &[Self::Phenylalanine],
+            Self::Glycine => &[Self::Glycine],
+            Self::Histidine => &[Self::Histidine],
+            Self::Isoleucine => &[Self::Isoleucine],
+            Self::Lysine => &[Self::Lysine],
+            Self::Leucine => &[Self::Leucine],
+            Self::Methionine => &[Self::Methionine],
+            Self::Asparagine => &[Self::Asparagine],
+            Self::Proline => &[Self::Proline],
+            Self::Glutamine => &[Self::Glutamine],
+            Self::Arginine => &[Self::Arginine],
+            Self::Serine => &[Self::Serine],
+            Self::Threonine => &[Self::Threonine],
+            Self::Valine => &[Self::Valine],
+            Self::Tryptophan => &[Self::Tryptophan],
+            Self::Any => &ANY,
+            Self::Tyrosine => &[Self::Tyrosine],
+            Self::GlutamineGlutamicAcid => &[Self::GlutamicAcid, Self::Glutamine],
+            Self::Stop => &[Self::Stop],
+            Self::Selenocysteine => &[Self::Selenocysteine],
+            Self::Pyrrolysine => &[Self::Pyrrolysine],
+            Self::LeucineIsoleucine => &[Self::Isoleucine, Self::Leucine],
+            Self::Gap => &[],
+        }
+    }
+
+    fn index(&self) -> u8 {
+        *self as u8
+    }
+}
 
 #[pymethods]
 impl AminoAcid {
     #[new]
-    fn __new__(code_or_codon: CodeOrCodon) -> PyResult<Self> {
-        code_or_codon.try_into()
+    #[pyo3(signature = (code_or_codon, table = GeneticCodeInput::Id(1)))]
+    fn __new__(code_or_codon: CodeOrCodon, table: GeneticCodeInput) -> PyResult<Self> {
+        code_or_codon.translate(&table.try_into()?)
     }
 
     #[getter]
@@ -144,6 +252,11 @@ impl AminoAcid {
             Self::Any => "xaa",
             Self::Tyrosine => "tyr",
             Self::GlutamineGlutamicAcid => "glx",
+            Self::Stop => "ter",
+            Self::Selenocysteine => "sec",
+            Self::Pyrrolysine => "pyl",
+            Self::LeucineIsoleucine => "xle",
+            Self::Gap => "gap",
         }
     }
 
@@ -151,8 +264,30 @@ impl AminoAcid {
         self.to_string()
     }
 
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (char,)) {
+        (slf.py().get_type::<Self>(), (slf.get().get_code(),))
+    }
+
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: Bound<'py, PyAny>) -> Bound<'py, Self> {
+        slf
+    }
+
+    #[getter]
+    fn get_amino_acids<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyFrozenSet>> {
+        PyFrozenSet::new(py, self.expand().iter().copied())
+    }
+
+    #[pyo3(name = "matches")]
+    fn py_matches(&self, other: MemberOrCode<AminoAcid>) -> PyResult<bool> {
+        Ok(self.matches(&other.into_member()?))
+    }
+
     fn __bool__(&self) -> bool {
-        true
+        *self != Self::Gap
     }
 
     fn __add__(&self, other: AminoAcidSequenceInput) -> PyResult<AminoAcidSequence> {
@@ -194,6 +329,11 @@ impl From<&AminoAcid> for char {
             AminoAcid::Any => 'X',
             AminoAcid::Tyrosine => 'Y',
             AminoAcid::GlutamineGlutamicAcid => 'Z',
+            AminoAcid::Stop => '*',
+            AminoAcid::Selenocysteine => 'U',
+            AminoAcid::Pyrrolysine => 'O',
+            AminoAcid::LeucineIsoleucine => 'J',
+            AminoAcid::Gap => '-',
         }
     }
 }
@@ -227,6 +367,11 @@ impl fmt::Display for AminoAcid {
                 Self::Any => "any",
                 Self::Tyrosine => "tyrosine",
                 Self::GlutamineGlutamicAcid => "glutamine/glutamic acid",
+                Self::Stop => "stop",
+                Self::Selenocysteine => "selenocysteine",
+                Self::Pyrrolysine => "pyrrolysine",
+                Self::LeucineIsoleucine => "leucine/isoleucine",
+                Self::Gap => "gap",
             }
         )
     }
@@ -260,6 +405,11 @@ impl TryFrom<char> for AminoAcid {
             'X' => Self::Any,
             'Y' => Self::Tyrosine,
             'Z' => Self::GlutamineGlutamicAcid,
+            '*' => Self::Stop,
+            'U' => Self::Selenocysteine,
+            'O' => Self::Pyrrolysine,
+            'J' => Self::LeucineIsoleucine,
+            '.' | '-' => Self::Gap,
             _ => {
                 return Err(pyo3::exceptions::PyValueError::new_err(format!(
                     "invalid IUPAC amino acid code \"{code}\""
@@ -273,158 +423,14 @@ impl TryFrom<(&RNABase, &RNABase, &RNABase)> for AminoAcid {
     type Error = PyErr;
 
     fn try_from(codon: (&RNABase, &RNABase, &RNABase)) -> PyResult<AminoAcid> {
-        Ok(match (codon.0, codon.1, codon.2) {
-            (RNABase::Gap, _, _) | (_, RNABase::Gap, _) | (_, _, RNABase::Gap) => {
-                return Err(pyo3::exceptions::PyValueError::new_err(
-                    "codon contains gap",
-                ))
-            }
+        GeneticCode::standard().translate(codon)
+    }
+}
 
-            // Alanine
-            (RNABase::Guanine, RNABase::Cytosine, _) => Self::Alanine,
-
-            // Aspartic acid/Asparagine
-            (
-                RNABase::AdenineGuanine,
-                RNABase::Adenine,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::AsparticAcidAsparagine,
-
-            // Cysteine
-            (
-                RNABase::Uracil,
-                RNABase::Guanine,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::Cysteine,
-
-            // Aspartic acid
-            (
-                RNABase::Guanine,
-                RNABase::Adenine,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::AsparticAcid,
-
-            // Glutamic acid
-            (
-                RNABase::Guanine,
-                RNABase::Adenine,
-                RNABase::Adenine | RNABase::Guanine | RNABase::AdenineGuanine,
-            ) => Self::GlutamicAcid,
-
-            // Phenylalanine
-            (
-                RNABase::Uracil,
-                RNABase::Uracil,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::Phenylalanine,
-
-            // Glycine
-            (RNABase::Guanine, RNABase::Guanine, _) => Self::Glycine,
-
-            // Histidine
-            (
-                RNABase::Cytosine,
-                RNABase::Adenine,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::Histidine,
-
-            // Isoleucine
-            (
-                RNABase::Adenine,
-                RNABase::Uracil,
-                RNABase::Adenine
-                | RNABase::Cytosine
-                | RNABase::Uracil
-                | RNABase::AdenineCytosine
-                | RNABase::AdenineUracil
-                | RNABase::CytosineUracil
-                | RNABase::AdenineCytosineUracil,
-            ) => Self::Isoleucine,
-
-            // Lysine
-            (
-                RNABase::Adenine,
-                RNABase::Adenine,
-                RNABase::Adenine | RNABase::Guanine | RNABase::AdenineGuanine,
-            ) => Self::Lysine,
-
-            // Leucine
-            (RNABase::Cytosine, RNABase::Uracil, _)
-            | (
-                RNABase::Uracil,
-                RNABase::Uracil,
-                RNABase::Adenine | RNABase::Guanine | RNABase::AdenineGuanine,
-            ) => Self::Leucine,
-
-            // Methionine
-            (RNABase::Adenine, RNABase::Uracil, RNABase::Guanine) => Self::Methionine,
-
-            // Asparagine
-            (
-                RNABase::Adenine,
-                RNABase::Adenine,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::Asparagine,
-
-            // Proline
-            (RNABase::Cytosine, RNABase::Cytosine, _) => Self::Proline,
-
-            // Glutamine
-            (
-                RNABase::Cytosine,
-                RNABase::Adenine,
-                RNABase::Adenine | RNABase::Guanine | RNABase::AdenineGuanine,
-            ) => Self::Glutamine,
-
-            // Arginine
-            (RNABase::Cytosine, RNABase::Guanine, _)
-            | (
-                RNABase::Adenine | RNABase::AdenineCytosine,
-                RNABase::Guanine,
-                RNABase::Adenine | RNABase::Guanine | RNABase::AdenineGuanine,
-            ) => Self::Arginine,
-
-            // Serine
-            (RNABase::Uracil, RNABase::Cytosine, _)
-            | (
-                RNABase::Adenine,
-                RNABase::Guanine,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::Serine,
-
-            // Threonine
-            (RNABase::Adenine, RNABase::Cytosine, _) => Self::Threonine,
-
-            // Valine
-            (RNABase::Guanine, RNABase::Uracil, _) => Self::Valine,
-
-            // Tryptophan
-            (RNABase::Uracil, RNABase::Guanine, RNABase::Guanine) => Self::Tryptophan,
-
-            // Tyrosine
-            (
-                RNABase::Uracil,
-                RNABase::Adenine,
-                RNABase::Cytosine | RNABase::Uracil | RNABase::CytosineUracil,
-            ) => Self::Tyrosine,
-
-            (
-                RNABase::CytosineGuanine,
-                RNABase::Adenine,
-                RNABase::Adenine | RNABase::Guanine | RNABase::AdenineGuanine,
-            ) => Self::GlutamineGlutamicAcid,
-
-            // Stop
-            (
-                RNABase::Uracil,
-                RNABase::Adenine,
-                RNABase::Adenine | RNABase::Guanine | RNABase::AdenineGuanine,
-            )
-            | (RNABase::Uracil, RNABase::Guanine | RNABase::AdenineGuanine, RNABase::Adenine) => {
-                return Err(StopTranslation::new_err("stop translation"))
-            }
+impl TryFrom<(&DNABase, &DNABase, &DNABase)> for AminoAcid {
+    type Error = PyErr;
 
-            _ => return Err(pyo3::exceptions::PyValueError::new_err("ambiguous codon")),
-        })
+    fn try_from(codon: (&DNABase, &DNABase, &DNABase)) -> PyResult<AminoAcid> {
+        GeneticCode::standard().translate(codon)
     }
 }
@@ -1,11 +1,16 @@
 use crate::aminoacid::AminoAcid;
+use crate::aminoacidsequenceview::AminoAcidSequenceView;
 use crate::impl_sequence;
+use crate::matchiterator::{MatchIterator, MatchTarget};
 use crate::member::MemberOrMembers;
-use crate::sequence::{Sequence, SequenceInput};
+use crate::sequence::{decode, Sequence, SequenceInput, Span};
 use crate::utils::IntOrSlice;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyBytes, PyType};
 
-#[pyclass]
+#[pyclass(frozen, module = "haem")]
 #[derive(FromPyObject)]
 pub struct AminoAcidSequence {
     pub sequence: Vec<AminoAcid>,
@@ -19,14 +24,144 @@ impl AminoAcidSequence {
         sequence.try_into()
     }
 
-    #[pyo3(name = "count", signature = (sequence, overlap = false))]
-    fn py_count(&self, sequence: AminoAcidSequenceInput, overlap: bool) -> PyResult<usize> {
-        self.count(&AminoAcidSequence::try_from(sequence)?, overlap)
+    #[classmethod]
+    fn from_bytes(_cls: &Bound<'_, PyType>, data: &[u8]) -> PyResult<Self> {
+        Ok(decode(data, "AminoAcidSequence")?.into())
     }
 
-    #[pyo3(name = "find")]
-    fn py_find(&self, sequence: AminoAcidSequenceInput) -> PyResult<Option<usize>> {
-        self.find(&AminoAcidSequence::try_from(sequence)?)
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.encode())
+    }
+
+    #[pyo3(signature = (start = None, stop = None, step = None))]
+    fn view(
+        slf: Bound<'_, Self>,
+        start: Option<isize>,
+        stop: Option<isize>,
+        step: Option<isize>,
+    ) -> PyResult<AminoAcidSequenceView> {
+        let span = Span::new(slf.py(), slf.get().len(), start, stop, step)?;
+
+        Ok(AminoAcidSequenceView::new(slf.unbind(), span))
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.count(
+            &AminoAcidSequence::try_from(sequence)?,
+            overlap,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        self.find(
+            &AminoAcidSequence::try_from(sequence)?,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "rfind", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_rfind(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        self.rfind(
+            &AminoAcidSequence::try_from(sequence)?,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn index(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.py_find(sequence, start, end, ambiguous)?
+            .ok_or_else(|| PyValueError::new_err("subsequence not found"))
+    }
+
+    #[pyo3(signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn rindex(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.py_rfind(sequence, start, end, ambiguous)?
+            .ok_or_else(|| PyValueError::new_err("subsequence not found"))
+    }
+
+    #[pyo3(
+        name = "find_all",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_find_all(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Vec<usize>> {
+        self.find_all(
+            &AminoAcidSequence::try_from(sequence)?,
+            overlap,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false))]
+    fn finditer(
+        slf: PyRef<'_, Self>,
+        sequence: AminoAcidSequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<MatchIterator> {
+        let pattern = AminoAcidSequence::try_from(sequence)?.sequence;
+        let range = slf.bounds(start, end);
+
+        Ok(MatchIterator::new(
+            MatchTarget::AminoAcidSequence(slf.into(), pattern),
+            range,
+            overlap,
+            ambiguous,
+        ))
+    }
+
+    #[pyo3(name = "contains", signature = (sequence, *, ambiguous = false))]
+    fn py_contains(&self, sequence: AminoAcidSequenceInput, ambiguous: bool) -> PyResult<bool> {
+        self.contains(&AminoAcidSequence::try_from(sequence)?, ambiguous)
     }
 
     fn __repr__(&self) -> String {
@@ -37,8 +172,33 @@ impl AminoAcidSequence {
         self.str()
     }
 
-    fn __eq__(&self, other: &Self) -> bool {
-        self.eq(other)
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        match op {
+            CompareOp::Eq => self.eq(other),
+            CompareOp::Ne => !self.eq(other),
+            _ => op.matches(self.cmp(other)),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.hash()
+    }
+
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyBytes>,))> {
+        Ok((
+            slf.py().get_type::<Self>().getattr("from_bytes")?,
+            (slf.get().to_bytes(slf.py()),),
+        ))
+    }
+
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: Bound<'py, PyAny>) -> Bound<'py, Self> {
+        slf
     }
 
     fn __bool__(&self) -> bool {
@@ -54,7 +214,7 @@ impl AminoAcidSequence {
     }
 
     fn __contains__(&self, sequence: AminoAcidSequenceInput) -> PyResult<bool> {
-        self.contains(&AminoAcidSequence::try_from(sequence)?)
+        self.py_contains(sequence, false)
     }
 
     fn __len__(&self) -> usize {
@@ -0,0 +1,111 @@
+use crate::aminoacid::AminoAcid;
+use crate::aminoacidsequence::{AminoAcidSequence, AminoAcidSequenceInput};
+use crate::sequence::{bounds, Indexed, Span};
+use crate::utils::IntOrSlice;
+use pyo3::prelude::*;
+
+#[pyclass(frozen, module = "haem")]
+pub struct AminoAcidSequenceView {
+    sequence: Py<AminoAcidSequence>,
+    span: Span,
+}
+
+impl AminoAcidSequenceView {
+    pub fn new(sequence: Py<AminoAcidSequence>, span: Span) -> Self {
+        Self { sequence, span }
+    }
+
+    fn members(&self) -> &[AminoAcid] {
+        &self.sequence.get().sequence
+    }
+}
+
+impl Indexed<AminoAcid> for AminoAcidSequenceView {
+    fn length(&self) -> usize {
+        self.span.length
+    }
+
+    fn get(&self, index: usize) -> AminoAcid {
+        self.members()[self.span.index(index)]
+    }
+}
+
+#[pymethods]
+impl AminoAcidSequenceView {
+    fn copy(&self) -> AminoAcidSequence {
+        self.span.members(self.members()).into_owned().into()
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        Ok(self.count(
+            &AminoAcidSequence::try_from(sequence)?,
+            overlap,
+            bounds(self.span.length, start, end),
+            ambiguous,
+        ))
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        Ok(self.find(
+            &AminoAcidSequence::try_from(sequence)?,
+            bounds(self.span.length, start, end),
+            ambiguous,
+        ))
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr("AminoAcidSequenceView")
+    }
+
+    fn __str__(&self) -> String {
+        self.str()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.hash()
+    }
+
+    fn __len__(&self) -> usize {
+        self.span.length
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match index_or_slice {
+            IntOrSlice::Int(index) => self
+                .member(index, "AminoAcidSequenceView")?
+                .into_pyobject(py)?
+                .into_any(),
+            IntOrSlice::Slice(slice) => {
+                Self::new(self.sequence.clone_ref(py), self.span.slice(&slice)?)
+                    .into_pyobject(py)?
+                    .into_any()
+            }
+        })
+    }
+}
@@ -0,0 +1,180 @@
+use crate::alignment::{Alignment, Row, Rows};
+use crate::aminoacid::AminoAcid;
+use crate::dnabase::DNABase;
+use crate::rnabase::RNABase;
+use crate::sequence::AnySequence;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+// Members that stand for a set of other members, which a column of mixed
+// members is summarised by, and the member standing for anything.
+trait Ambiguous: Row {
+    const AMBIGUOUS: &'static str;
+    const ANY: Self;
+
+    fn ambiguous(members: &[Self]) -> Self {
+        match members {
+            [member] => *member,
+            _ => Self::AMBIGUOUS
+                .chars()
+                .filter_map(|code| Self::try_from(code).ok())
+                .find(|ambiguous| {
+                    let expanded = ambiguous.expand();
+                    expanded.len() == members.len()
+                        && members.iter().all(|member| expanded.contains(member))
+                })
+                .unwrap_or(Self::ANY),
+        }
+    }
+}
+
+impl Ambiguous for DNABase {
+    const AMBIGUOUS: &'static str = "MRWSYKVHDBN";
+    const ANY: Self = Self::Any;
+}
+
+impl Ambiguous for RNABase {
+    const AMBIGUOUS: &'static str = "MRWSYKVHDBN";
+    const ANY: Self = Self::Any;
+}
+
+impl Ambiguous for AminoAcid {
+    const AMBIGUOUS: &'static str = "BZJ";
+    const ANY: Self = Self::Any;
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Gaps {
+    Ignore,
+    Include,
+}
+
+impl TryFrom<&str> for Gaps {
+    type Error = PyErr;
+
+    fn try_from(gaps: &str) -> PyResult<Self> {
+        match gaps {
+            "ignore" => Ok(Self::Ignore),
+            "include" => Ok(Self::Include),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown gap handling \"{gaps}\""
+            ))),
+        }
+    }
+}
+
+struct Options {
+    threshold: f64,
+    gaps: Gaps,
+    min_depth: usize,
+}
+
+// Ambiguous members count as an equal share of each member they stand for.
+fn column_consensus<T: Ambiguous>(column: impl Iterator<Item = T>, options: &Options) -> T {
+    let mut weights: Vec<(T, f64)> = vec![];
+    let (mut members, mut gaps) = (0, 0);
+
+    for member in column {
+        let expanded = member.expand();
+        match expanded.len() {
+            0 => gaps += 1,
+            length => {
+                members += 1;
+                for member in expanded {
+                    match weights.iter_mut().find(|(other, _)| other == member) {
+                        Some((_, weight)) => *weight += 1.0 / length as f64,
+                        None => weights.push((*member, 1.0 / length as f64)),
+                    }
+                }
+            }
+        }
+    }
+
+    let depth = match options.gaps {
+        Gaps::Ignore => members,
+        Gaps::Include => members + gaps,
+    };
+    let reaches = |weight: f64| weight + 1e-9 >= options.threshold * depth as f64;
+
+    if members == 0 || (options.gaps == Gaps::Include && reaches(gaps as f64)) {
+        return T::GAP;
+    }
+    if depth < options.min_depth {
+        return T::ANY;
+    }
+
+    // The most common members up to the threshold, along with any as common
+    // as the last of them.
+    weights.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let mut total = 0.0;
+    let mut selected = vec![];
+    for (member, weight) in weights {
+        if reaches(total)
+            && selected
+                .last()
+                .is_some_and(|(_, last)| weight + 1e-9 < *last)
+        {
+            break;
+        }
+        total += weight;
+        selected.push((member, weight));
+    }
+
+    let selected = selected
+        .into_iter()
+        .map(|(member, _)| member)
+        .collect::<Vec<_>>();
+    T::ambiguous(&selected)
+}
+
+fn build<'py, T: Ambiguous>(
+    py: Python<'py>,
+    rows: &[Vec<T>],
+    options: &Options,
+) -> PyResult<Bound<'py, PyAny>> {
+    let width = rows.first().map_or(0, Vec::len);
+    let consensus = (0..width)
+        .map(|index| column_consensus(rows.iter().map(|row| row[index]), options))
+        .collect();
+
+    T::sequence(py, consensus)
+}
+
+#[derive(FromPyObject)]
+pub enum ConsensusInput<'py> {
+    Alignment(PyRef<'py, Alignment>),
+    Sequences(Vec<AnySequence<'py>>),
+}
+
+#[pyfunction]
+#[pyo3(signature = (sequences, *, threshold = 0.5, gaps = "ignore", min_depth = 1))]
+pub fn consensus<'py>(
+    py: Python<'py>,
+    sequences: ConsensusInput<'py>,
+    threshold: f64,
+    gaps: &str,
+    min_depth: usize,
+) -> PyResult<Bound<'py, PyAny>> {
+    if !(threshold > 0.0 && threshold <= 1.0) {
+        return Err(PyValueError::new_err("threshold must be between 0 and 1"));
+    }
+
+    let options = Options {
+        threshold,
+        gaps: Gaps::try_from(gaps)?,
+        min_depth,
+    };
+
+    let rows = match sequences {
+        ConsensusInput::Alignment(alignment) => alignment.rows.clone(),
+        ConsensusInput::Sequences(sequences) => {
+            Alignment::new(None, Rows::try_from(sequences)?)?.rows
+        }
+    };
+
+    match &rows {
+        Rows::DNASequence(rows) => build(py, rows, &options),
+        Rows::RNASequence(rows) => build(py, rows, &options),
+        Rows::AminoAcidSequence(rows) => build(py, rows, &options),
+    }
+}
@@ -0,0 +1,196 @@
+use crate::alignment::Rows;
+use crate::member::Member;
+use crate::sequence::AnySequence;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+const INFINITE: usize = usize::MAX / 2;
+
+#[derive(Clone, Copy)]
+enum Metric {
+    Hamming,
+    Levenshtein,
+}
+
+impl TryFrom<&str> for Metric {
+    type Error = PyErr;
+
+    fn try_from(metric: &str) -> PyResult<Self> {
+        match metric {
+            "hamming" => Ok(Self::Hamming),
+            "levenshtein" => Ok(Self::Levenshtein),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown distance metric \"{metric}\""
+            ))),
+        }
+    }
+}
+
+// Ambiguous members are equal to any member they share a possibility with,
+// so that N is equal to anything but a gap.
+fn equal<T: Member + PartialEq + 'static>(first: &T, second: &T, ambiguous: bool) -> bool {
+    match ambiguous {
+        true => first.matches(second),
+        false => first == second,
+    }
+}
+
+fn hamming<T>(first: &[T], second: &[T], ambiguous: bool) -> PyResult<usize>
+where
+    T: Member + PartialEq + Sync + 'static,
+{
+    if first.len() != second.len() {
+        return Err(PyValueError::new_err("sequences must be the same length"));
+    }
+
+    Ok(first
+        .par_iter()
+        .zip(second.par_iter())
+        .filter(|(a, b)| !equal(*a, *b, ambiguous))
+        .count())
+}
+
+// Only cells within `max_distance` of the diagonal can lead to a distance
+// within the cut-off, so the rest are never computed and the search stops
+// as soon as every cell in a row exceeds it.
+fn levenshtein<T>(
+    first: &[T],
+    second: &[T],
+    max_distance: Option<usize>,
+    ambiguous: bool,
+) -> Option<usize>
+where
+    T: Member + PartialEq + 'static,
+{
+    let max_distance = max_distance.unwrap_or(INFINITE);
+    let band = max_distance.min(first.len().max(second.len()));
+    if first.len().abs_diff(second.len()) > band {
+        return None;
+    }
+
+    let width = second.len() + 1;
+    let mut previous = (0..width)
+        .map(|j| match j <= band {
+            true => j,
+            false => INFINITE,
+        })
+        .collect::<Vec<_>>();
+    let mut current = vec![INFINITE; width];
+
+    for i in 1..=first.len() {
+        let start = i.saturating_sub(band).max(1);
+        let end = (i + band).min(second.len());
+
+        current[start - 1] = match start {
+            1 => i,
+            _ => INFINITE,
+        };
+
+        for j in start..=end {
+            let substitution =
+                previous[j - 1] + !equal(&first[i - 1], &second[j - 1], ambiguous) as usize;
+            current[j] = substitution.min(previous[j] + 1).min(current[j - 1] + 1);
+        }
+        if end < second.len() {
+            current[end + 1] = INFINITE;
+        }
+
+        if current[start - 1..=end]
+            .iter()
+            .all(|distance| *distance > max_distance)
+        {
+            return None;
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[second.len()]).filter(|distance| *distance <= max_distance)
+}
+
+fn matrix<T>(
+    rows: &[Vec<T>],
+    metric: Metric,
+    max_distance: Option<usize>,
+    ambiguous: bool,
+) -> PyResult<Vec<Vec<Option<usize>>>>
+where
+    T: Member + PartialEq + Sync + 'static,
+{
+    if let Metric::Hamming = metric {
+        if rows.windows(2).any(|pair| pair[0].len() != pair[1].len()) {
+            return Err(PyValueError::new_err("sequences must be the same length"));
+        }
+    }
+
+    let pairs = (0..rows.len())
+        .flat_map(|i| (i + 1..rows.len()).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+    let distances = pairs
+        .par_iter()
+        .map(|(i, j)| match metric {
+            Metric::Hamming => hamming(&rows[*i], &rows[*j], ambiguous)
+                .ok()
+                .filter(|distance| max_distance.is_none_or(|max| *distance <= max)),
+            Metric::Levenshtein => levenshtein(&rows[*i], &rows[*j], max_distance, ambiguous),
+        })
+        .collect::<Vec<_>>();
+
+    let mut matrix = vec![vec![Some(0); rows.len()]; rows.len()];
+    for ((i, j), distance) in pairs.into_iter().zip(distances) {
+        matrix[i][j] = distance;
+        matrix[j][i] = distance;
+    }
+
+    Ok(matrix)
+}
+
+macro_rules! with_pair {
+    ($first:expr, $second:expr, |$a:ident, $b:ident| $body:expr) => {
+        match (&$first, &$second) {
+            (AnySequence::DNASequence($a), AnySequence::DNASequence($b)) => $body,
+            (AnySequence::RNASequence($a), AnySequence::RNASequence($b)) => $body,
+            (AnySequence::AminoAcidSequence($a), AnySequence::AminoAcidSequence($b)) => $body,
+            _ => return Err(PyTypeError::new_err("sequences must be of the same type")),
+        }
+    };
+}
+
+#[pyfunction]
+#[pyo3(signature = (first, second, *, ambiguous = false))]
+pub fn hamming_distance(
+    first: AnySequence<'_>,
+    second: AnySequence<'_>,
+    ambiguous: bool,
+) -> PyResult<usize> {
+    with_pair! { first, second, |a, b| hamming(a, b, ambiguous) }
+}
+
+#[pyfunction]
+#[pyo3(signature = (first, second, *, max_distance = None, ambiguous = false))]
+pub fn levenshtein_distance(
+    first: AnySequence<'_>,
+    second: AnySequence<'_>,
+    max_distance: Option<usize>,
+    ambiguous: bool,
+) -> PyResult<Option<usize>> {
+    Ok(with_pair! { first, second, |a, b| levenshtein(a, b, max_distance, ambiguous) })
+}
+
+#[pyfunction]
+#[pyo3(signature = (sequences, metric = "hamming", *, max_distance = None, ambiguous = false))]
+pub fn distance_matrix(
+    sequences: Vec<AnySequence<'_>>,
+    metric: &str,
+    max_distance: Option<usize>,
+    ambiguous: bool,
+) -> PyResult<Vec<Vec<Option<usize>>>> {
+    let metric = Metric::try_from(metric)?;
+
+    match Rows::try_from(sequences)? {
+        Rows::DNASequence(rows) => matrix(&rows, metric, max_distance, ambiguous),
+        Rows::RNASequence(rows) => matrix(&rows, metric, max_distance, ambiguous),
+        Rows::AminoAcidSequence(rows) => matrix(&rows, metric, max_distance, ambiguous),
+    }
+}
@@ -1,17 +1,20 @@
 use crate::dnasequence::{DNASequence, DNASequenceInput};
-use crate::member::Member;
+use crate::member::{Member, MemberOrCode};
 use crate::rnabase::RNABase;
 use pyo3::prelude::*;
+use pyo3::types::{PyFrozenSet, PyType};
 use std::fmt;
 
 #[pyclass(
     eq,
     eq_int,
     frozen,
+    hash,
+    module = "haem",
     rename_all = "SCREAMING_SNAKE_CASE",
     from_py_object
 )]
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DNABase {
     Adenine,
     Cytosine,
@@ -31,7 +34,67 @@ pub enum DNABase {
     Gap,
 }
 
-impl Member for DNABase {}
+// Every member in declaration order, which is checked when compiling so that
+// the index of a member always finds that member in MEMBERS.
+const MEMBERS: [DNABase; 16] = [
+    DNABase::Adenine,
+    DNABase::Cytosine,
+    DNABase::Guanine,
+    DNABase::Thymine,
+    DNABase::AdenineCytosine,
+    DNABase::AdenineGuanine,
+    DNABase::AdenineThymine,
+    DNABase::CytosineGuanine,
+    DNABase::CytosineThymine,
+    DNABase::GuanineThymine,
+    DNABase::AdenineCytosineGuanine,
+    DNABase::AdenineCytosineThymine,
+    DNABase::AdenineGuanineThymine,
+    DNABase::CytosineGuanineThymine,
+    DNABase::Any,
+    DNABase::Gap,
+];
+
+const _: () = {
+    let mut index = 0;
+    while index < MEMBERS.len() {
+        assert!(MEMBERS[index] as usize == index);
+        index += 1;
+    }
+};
+
+impl Member for DNABase {
+    const GAP: Self = Self::Gap;
+
+    fn members() -> &'static [Self] {
+        &MEMBERS
+    }
+
+    fn expand(&self) -> &'static [Self] {
+        match self {
+            Self::Adenine => &[Self::Adenine],
+            Self::Cytosine => &[Self::Cytosine],
+            Self::Guanine => &[Self::Guanine],
+            Self::Thymine => &[Self::Thymine],
+            Self::AdenineCytosine => &[Self::Adenine, Self::Cytosine],
+            Self::AdenineGuanine => &[Self::Adenine, Self::Guanine],
+            Self::AdenineThymine => &[Self::Adenine, Self::Thymine],
+            Self::CytosineGuanine => &[Self::Cytosine, Self::Guanine],
+            Self::CytosineThymine => &[Self::Cytosine, Self::Thymine],
+            Self::GuanineThymine => &[Self::Guanine, Self::Thymine],
+            Self::AdenineCytosineGuanine => &[Self::Adenine, Self::Cytosine, Self::Guanine],
+            Self::AdenineCytosineThymine => &[Self::Adenine, Self::Cytosine, Self::Thymine],
+            Self::AdenineGuanineThymine => &[Self::Adenine, Self::Guanine, Self::Thymine],
+            Self::CytosineGuanineThymine => &[Self::Cytosine, Self::Guanine, Self::Thymine],
+            Self::Any => &[Self::Adenine, Self::Cytosine, Self::Guanine, Self::Thymine],
+            Self::Gap => &[],
+        }
+    }
+
+    fn index(&self) -> u8 {
+        *self as u8
+    }
+}
 
 #[pymethods]
 impl DNABase {
@@ -71,6 +134,16 @@ impl DNABase {
         self.into()
     }
 
+    #[getter]
+    fn get_bases<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyFrozenSet>> {
+        PyFrozenSet::new(py, self.expand().iter().copied())
+    }
+
+    #[pyo3(name = "matches")]
+    fn py_matches(&self, other: MemberOrCode<DNABase>) -> PyResult<bool> {
+        Ok(self.matches(&other.into_member()?))
+    }
+
     fn __bool__(&self) -> bool {
         *self != Self::Gap
     }
@@ -90,6 +163,18 @@ impl DNABase {
     fn __str__(&self) -> String {
         self.to_string()
     }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (char,)) {
+        (slf.py().get_type::<Self>(), (slf.get().get_code(),))
+    }
+
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: Bound<'py, PyAny>) -> Bound<'py, Self> {
+        slf
+    }
 }
 
 impl From<&DNABase> for char {
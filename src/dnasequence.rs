@@ -1,14 +1,23 @@
+use crate::aminoacidsequence::AminoAcidSequence;
 use crate::dnabase::DNABase;
+use crate::dnasequenceview::DNASequenceView;
+use crate::geneticcode::GeneticCodeInput;
 use crate::impl_sequence;
+use crate::matchiterator::{MatchIterator, MatchTarget};
 use crate::member::MemberOrMembers;
+use crate::orf::{OpenReadingFrame, OrfFinder};
 use crate::rnabase::RNABase;
 use crate::rnasequence::RNASequence;
-use crate::sequence::{Sequence, SequenceInput};
+use crate::sequence::{decode, Sequence, SequenceInput, Span};
+use crate::translation::{translate_frames, TranslationOptions};
 use crate::utils::IntOrSlice;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyBytes, PyIterator, PyType};
 use rayon::prelude::*;
 
-#[pyclass]
+#[pyclass(frozen, module = "haem")]
 #[derive(FromPyObject)]
 pub struct DNASequence {
     pub sequence: Vec<DNABase>,
@@ -22,6 +31,15 @@ impl DNASequence {
         sequence.try_into()
     }
 
+    #[classmethod]
+    fn from_bytes(_cls: &Bound<'_, PyType>, data: &[u8]) -> PyResult<Self> {
+        Ok(decode(data, "DNASequence")?.into())
+    }
+
+    fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.encode())
+    }
+
     #[getter]
     fn get_complement(&self) -> Self {
         self.sequence
@@ -39,14 +57,211 @@ impl DNASequence {
             .into()
     }
 
-    #[pyo3(name = "count", signature = (sequence, overlap = false))]
-    fn py_count(&self, sequence: DNASequenceInput, overlap: bool) -> PyResult<usize> {
-        self.count(&DNASequence::try_from(sequence)?, overlap)
+    #[pyo3(signature = (
+        table = GeneticCodeInput::Id(1),
+        *,
+        frame = None,
+        to_stop = true,
+        cds = false,
+        alternative_starts = false,
+    ))]
+    fn translate(
+        &self,
+        table: GeneticCodeInput,
+        frame: Option<usize>,
+        to_stop: bool,
+        cds: bool,
+        alternative_starts: bool,
+    ) -> PyResult<AminoAcidSequence> {
+        Ok(TranslationOptions {
+            table: table.try_into()?,
+            frame,
+            to_stop,
+            cds,
+            alternative_starts,
+        }
+        .translate(&self.sequence)?
+        .into())
+    }
+
+    #[pyo3(signature = (table = GeneticCodeInput::Id(1)))]
+    fn translate_frames(&self, table: GeneticCodeInput) -> PyResult<Vec<AminoAcidSequence>> {
+        Ok(translate_frames(
+            table.try_into()?,
+            &self.sequence,
+            &self.reverse_complement().sequence,
+        )
+        .into_iter()
+        .map(AminoAcidSequence::from)
+        .collect())
     }
 
-    #[pyo3(name = "find")]
-    fn py_find(&self, sequence: DNASequenceInput) -> PyResult<Option<usize>> {
-        self.find(&DNASequence::try_from(sequence)?)
+    #[pyo3(signature = (
+        table = GeneticCodeInput::Id(1),
+        *,
+        min_length = 0,
+        nested = false,
+        alternative_starts = false,
+    ))]
+    fn find_orfs(
+        &self,
+        table: GeneticCodeInput,
+        min_length: usize,
+        nested: bool,
+        alternative_starts: bool,
+    ) -> PyResult<Vec<OpenReadingFrame>> {
+        Ok(OrfFinder {
+            table: table.try_into()?,
+            min_length,
+            nested,
+            alternative_starts,
+        }
+        .find(&self.sequence, &self.reverse_complement().sequence))
+    }
+
+    pub fn reverse_complement(&self) -> Self {
+        self.sequence
+            .par_iter()
+            .rev()
+            .map(|base| base.get_complement())
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[pyo3(signature = (start = None, stop = None, step = None))]
+    fn view(
+        slf: Bound<'_, Self>,
+        start: Option<isize>,
+        stop: Option<isize>,
+        step: Option<isize>,
+    ) -> PyResult<DNASequenceView> {
+        let span = Span::new(slf.py(), slf.get().len(), start, stop, step)?;
+
+        Ok(DNASequenceView::new(slf.unbind(), span))
+    }
+
+    #[pyo3(name = "reverse")]
+    fn py_reverse(&self) -> Self {
+        self.reverse().into()
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: DNASequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.count(
+            &DNASequence::try_from(sequence)?,
+            overlap,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: DNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        self.find(
+            &DNASequence::try_from(sequence)?,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "rfind", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_rfind(
+        &self,
+        sequence: DNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        self.rfind(
+            &DNASequence::try_from(sequence)?,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn index(
+        &self,
+        sequence: DNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.py_find(sequence, start, end, ambiguous)?
+            .ok_or_else(|| PyValueError::new_err("subsequence not found"))
+    }
+
+    #[pyo3(signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn rindex(
+        &self,
+        sequence: DNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.py_rfind(sequence, start, end, ambiguous)?
+            .ok_or_else(|| PyValueError::new_err("subsequence not found"))
+    }
+
+    #[pyo3(
+        name = "find_all",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_find_all(
+        &self,
+        sequence: DNASequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Vec<usize>> {
+        self.find_all(
+            &DNASequence::try_from(sequence)?,
+            overlap,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false))]
+    fn finditer(
+        slf: PyRef<'_, Self>,
+        sequence: DNASequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<MatchIterator> {
+        let pattern = DNASequence::try_from(sequence)?.sequence;
+        let range = slf.bounds(start, end);
+
+        Ok(MatchIterator::new(
+            MatchTarget::DNASequence(slf.into(), pattern),
+            range,
+            overlap,
+            ambiguous,
+        ))
+    }
+
+    #[pyo3(name = "contains", signature = (sequence, *, ambiguous = false))]
+    fn py_contains(&self, sequence: DNASequenceInput, ambiguous: bool) -> PyResult<bool> {
+        self.contains(&DNASequence::try_from(sequence)?, ambiguous)
     }
 
     fn __invert__(&self) -> Self {
@@ -61,8 +276,33 @@ impl DNASequence {
         self.str()
     }
 
-    fn __eq__(&self, other: &Self) -> bool {
-        self.eq(other)
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        match op {
+            CompareOp::Eq => self.eq(other),
+            CompareOp::Ne => !self.eq(other),
+            _ => op.matches(self.cmp(other)),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.hash()
+    }
+
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyBytes>,))> {
+        Ok((
+            slf.py().get_type::<Self>().getattr("from_bytes")?,
+            (slf.get().to_bytes(slf.py()),),
+        ))
+    }
+
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: Bound<'py, PyAny>) -> Bound<'py, Self> {
+        slf
     }
 
     fn __bool__(&self) -> bool {
@@ -94,8 +334,12 @@ impl DNASequence {
         })
     }
 
+    fn __reversed__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        self.py_reverse().into_pyobject(py)?.try_iter()
+    }
+
     fn __contains__(&self, sequence: DNASequenceInput) -> PyResult<bool> {
-        self.contains(&DNASequence::try_from(sequence)?)
+        self.py_contains(sequence, false)
     }
 }
 
@@ -0,0 +1,151 @@
+use crate::aminoacidsequence::AminoAcidSequence;
+use crate::dnabase::DNABase;
+use crate::dnasequence::{DNASequence, DNASequenceInput};
+use crate::geneticcode::GeneticCodeInput;
+use crate::sequence::{bounds, Indexed, Span};
+use crate::translation::TranslationOptions;
+use crate::utils::IntOrSlice;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+#[pyclass(frozen, module = "haem")]
+pub struct DNASequenceView {
+    sequence: Py<DNASequence>,
+    span: Span,
+}
+
+impl DNASequenceView {
+    pub fn new(sequence: Py<DNASequence>, span: Span) -> Self {
+        Self { sequence, span }
+    }
+
+    fn members(&self) -> &[DNABase] {
+        &self.sequence.get().sequence
+    }
+}
+
+impl Indexed<DNABase> for DNASequenceView {
+    fn length(&self) -> usize {
+        self.span.length
+    }
+
+    fn get(&self, index: usize) -> DNABase {
+        self.members()[self.span.index(index)]
+    }
+}
+
+#[pymethods]
+impl DNASequenceView {
+    fn copy(&self) -> DNASequence {
+        self.span.members(self.members()).into_owned().into()
+    }
+
+    #[getter]
+    fn get_complement(&self) -> DNASequence {
+        (0..self.span.length)
+            .into_par_iter()
+            .map(|index| self.get(index).get_complement())
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[pyo3(signature = (
+        table = GeneticCodeInput::Id(1),
+        *,
+        frame = None,
+        to_stop = true,
+        cds = false,
+        alternative_starts = false,
+    ))]
+    fn translate(
+        &self,
+        table: GeneticCodeInput,
+        frame: Option<usize>,
+        to_stop: bool,
+        cds: bool,
+        alternative_starts: bool,
+    ) -> PyResult<AminoAcidSequence> {
+        Ok(TranslationOptions {
+            table: table.try_into()?,
+            frame,
+            to_stop,
+            cds,
+            alternative_starts,
+        }
+        .translate(&self.span.members(self.members()))?
+        .into())
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: DNASequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        Ok(self.count(
+            &DNASequence::try_from(sequence)?,
+            overlap,
+            bounds(self.span.length, start, end),
+            ambiguous,
+        ))
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: DNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        Ok(self.find(
+            &DNASequence::try_from(sequence)?,
+            bounds(self.span.length, start, end),
+            ambiguous,
+        ))
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr("DNASequenceView")
+    }
+
+    fn __str__(&self) -> String {
+        self.str()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.hash()
+    }
+
+    fn __len__(&self) -> usize {
+        self.span.length
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match index_or_slice {
+            IntOrSlice::Int(index) => self
+                .member(index, "DNASequenceView")?
+                .into_pyobject(py)?
+                .into_any(),
+            IntOrSlice::Slice(slice) => {
+                Self::new(self.sequence.clone_ref(py), self.span.slice(&slice)?)
+                    .into_pyobject(py)?
+                    .into_any()
+            }
+        })
+    }
+}
@@ -0,0 +1,172 @@
+use crate::sequence::{AnySequence, SequenceKind};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::PathBuf;
+
+#[pyclass(frozen, get_all)]
+pub struct FastaRecord {
+    pub id: String,
+    pub description: String,
+    pub sequence: Py<PyAny>,
+}
+
+#[pymethods]
+impl FastaRecord {
+    #[new]
+    #[pyo3(signature = (id, sequence, description = String::new()))]
+    fn __new__(id: String, sequence: Bound<'_, PyAny>, description: String) -> PyResult<Self> {
+        sequence.extract::<AnySequence>()?;
+
+        Ok(Self {
+            id,
+            description,
+            sequence: sequence.unbind(),
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<FastaRecord: {}>", self.id)
+    }
+}
+
+#[pyclass]
+pub struct FastaReader {
+    lines: Lines<BufReader<File>>,
+    header: Option<String>,
+    kind: SequenceKind,
+}
+
+#[pymethods]
+impl FastaReader {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<FastaRecord>> {
+        let header = match self.header.take() {
+            Some(header) => header,
+            None => match self.next_header()? {
+                Some(header) => header,
+                None => return Ok(None),
+            },
+        };
+
+        let mut codes = String::new();
+        for line in self.lines.by_ref() {
+            let line = line?;
+
+            match line.strip_prefix('>') {
+                Some(next) => {
+                    self.header = Some(next.trim_end().to_string());
+                    break;
+                }
+                None if line.starts_with(';') => continue,
+                None => codes.push_str(line.trim()),
+            }
+        }
+
+        // Soft-masked regions are written in lower case.
+        codes.make_ascii_uppercase();
+
+        let (id, description) = split_header(header);
+
+        Ok(Some(FastaRecord {
+            id,
+            description,
+            sequence: self.kind.parse(py, codes)?.unbind(),
+        }))
+    }
+}
+
+impl FastaReader {
+    fn next_header(&mut self) -> PyResult<Option<String>> {
+        for line in self.lines.by_ref() {
+            let line = line?;
+
+            match line.strip_prefix('>') {
+                Some(header) => return Ok(Some(header.trim_end().to_string())),
+                None if line.trim().is_empty() || line.starts_with(';') => continue,
+                None => return Err(PyValueError::new_err("invalid FASTA: expected header")),
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, kind = None))]
+pub fn read_fasta(path: PathBuf, kind: Option<Bound<'_, PyType>>) -> PyResult<FastaReader> {
+    let kind = match kind {
+        Some(kind) => SequenceKind::try_from(&kind)?,
+        None => SequenceKind::DNASequence,
+    };
+
+    Ok(FastaReader {
+        lines: BufReader::new(File::open(path)?).lines(),
+        header: None,
+        kind,
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, records, line_width = Some(60)))]
+pub fn write_fasta(
+    py: Python<'_>,
+    path: PathBuf,
+    records: Bound<'_, PyAny>,
+    line_width: Option<usize>,
+) -> PyResult<()> {
+    if line_width == Some(0) {
+        return Err(PyValueError::new_err("line_width must be positive"));
+    }
+
+    let mut file = BufWriter::new(File::create(path)?);
+
+    for record in records.try_iter()? {
+        let record = record?;
+        let record = record.cast::<FastaRecord>()?.get();
+        let codes = record.sequence.bind(py).extract::<AnySequence>()?.codes();
+
+        writeln!(file, ">{}", join_header(&record.id, &record.description))?;
+        write_wrapped(&mut file, &codes, line_width)?;
+    }
+
+    Ok(file.flush()?)
+}
+
+pub fn split_header(header: String) -> (String, String) {
+    match header.split_once(char::is_whitespace) {
+        Some((id, description)) => (id.to_string(), description.trim().to_string()),
+        None => (header, String::new()),
+    }
+}
+
+pub fn join_header(id: &str, description: &str) -> String {
+    match description.is_empty() {
+        true => id.to_string(),
+        false => format!("{id} {description}"),
+    }
+}
+
+pub fn write_wrapped(
+    file: &mut impl Write,
+    codes: &str,
+    line_width: Option<usize>,
+) -> PyResult<()> {
+    match line_width {
+        Some(width) => {
+            for line in codes.as_bytes().chunks(width) {
+                file.write_all(line)?;
+                file.write_all(b"\n")?;
+            }
+        }
+        None if codes.is_empty() => {}
+        None => writeln!(file, "{codes}")?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use crate::dnabase::DNABase;
+use crate::dnasequence::{DNASequence, DNASequenceInput};
+use crate::fasta::{join_header, split_header};
+use crate::member::MemberOrMembers;
+use crate::sequence::{getitem, Sequence, SequenceInput};
+use crate::utils::IntOrSlice;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::PathBuf;
+
+#[pyclass(frozen)]
+pub struct FastqRecord {
+    #[pyo3(get)]
+    pub id: String,
+    #[pyo3(get)]
+    pub description: String,
+    pub sequence: Vec<DNABase>,
+    pub qualities: Vec<u8>,
+}
+
+#[pymethods]
+impl FastqRecord {
+    #[new]
+    #[pyo3(signature = (id, sequence, qualities, description = String::new()))]
+    fn __new__(
+        id: String,
+        sequence: DNASequenceInput,
+        qualities: Vec<u8>,
+        description: String,
+    ) -> PyResult<Self> {
+        let sequence = DNASequence::try_from(sequence)?.sequence;
+
+        if sequence.len() != qualities.len() {
+            return Err(PyValueError::new_err(
+                "sequence and qualities differ in length",
+            ));
+        }
+
+        Ok(Self {
+            id,
+            description,
+            sequence,
+            qualities,
+        })
+    }
+
+    #[getter]
+    fn get_sequence(&self) -> DNASequence {
+        self.sequence.clone().into()
+    }
+
+    #[getter]
+    fn get_qualities<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        // Vec<u8> would otherwise be converted to bytes.
+        PyList::new(py, &self.qualities)
+    }
+
+    #[getter]
+    fn get_mean_quality(&self) -> Option<f64> {
+        match self.qualities.is_empty() {
+            true => None,
+            false => Some(
+                self.qualities.par_iter().map(|&q| q as f64).sum::<f64>()
+                    / self.qualities.len() as f64,
+            ),
+        }
+    }
+
+    #[getter]
+    fn get_min_quality(&self) -> Option<u8> {
+        self.qualities.par_iter().min().copied()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<FastqRecord: {}>", self.id)
+    }
+
+    fn __str__(&self) -> String {
+        self.str()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.description == other.description
+            && self.sequence == other.sequence
+            && self.qualities == other.qualities
+    }
+
+    fn __bool__(&self) -> bool {
+        self.bool()
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Ok(
+            match getitem(&self.sequence, self.name(), &index_or_slice)? {
+                MemberOrMembers::Member(base) => base.into_pyobject(py)?.into_any(),
+                MemberOrMembers::Sequence(sequence) => Self {
+                    id: self.id.clone(),
+                    description: self.description.clone(),
+                    sequence,
+                    qualities: match getitem(&self.qualities, self.name(), &index_or_slice)? {
+                        MemberOrMembers::Sequence(qualities) => qualities,
+                        MemberOrMembers::Member(_) => unreachable!(),
+                    },
+                }
+                .into_pyobject(py)?
+                .into_any(),
+            },
+        )
+    }
+}
+
+impl Sequence<DNABase> for FastqRecord {
+    #[inline]
+    fn members(&self) -> &Vec<DNABase> {
+        &self.sequence
+    }
+
+    #[inline]
+    fn name(&self) -> &str {
+        "FastqRecord"
+    }
+}
+
+#[pyclass]
+pub struct FastqReader {
+    lines: Lines<BufReader<File>>,
+    offset: u8,
+}
+
+#[pymethods]
+impl FastqReader {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> PyResult<Option<FastqRecord>> {
+        let header = loop {
+            match self.lines.next() {
+                None => return Ok(None),
+                Some(line) => {
+                    let line = line?;
+
+                    match line.strip_prefix('@') {
+                        Some(header) => break header.trim_end().to_string(),
+                        None if line.trim().is_empty() => continue,
+                        None => {
+                            return Err(PyValueError::new_err("invalid FASTQ: expected header"))
+                        }
+                    }
+                }
+            }
+        };
+
+        let mut codes = self.next_line()?;
+        codes.make_ascii_uppercase();
+
+        if !self.next_line()?.starts_with('+') {
+            return Err(PyValueError::new_err("invalid FASTQ: expected separator"));
+        }
+
+        let qualities = decode_qualities(&self.next_line()?, self.offset)?;
+        let (id, description) = split_header(header);
+
+        let sequence = Vec::try_from(SequenceInput::<DNABase>::Str(codes))?;
+        if sequence.len() != qualities.len() {
+            return Err(PyValueError::new_err(
+                "invalid FASTQ: sequence and qualities differ in length",
+            ));
+        }
+
+        Ok(Some(FastqRecord {
+            id,
+            description,
+            sequence,
+            qualities,
+        }))
+    }
+}
+
+impl FastqReader {
+    fn next_line(&mut self) -> PyResult<String> {
+        match self.lines.next() {
+            Some(line) => Ok(line?.trim_end().to_string()),
+            None => Err(PyValueError::new_err("invalid FASTQ: truncated record")),
+        }
+    }
+}
+
+fn check_offset(offset: u8) -> PyResult<u8> {
+    match offset {
+        33 | 64 => Ok(offset),
+        _ => Err(PyValueError::new_err("offset must be 33 or 64")),
+    }
+}
+
+pub fn decode_qualities(codes: &str, offset: u8) -> PyResult<Vec<u8>> {
+    codes
+        .bytes()
+        .map(|code| match code {
+            code if (offset..=b'~').contains(&code) => Ok(code - offset),
+            _ => Err(PyValueError::new_err(format!(
+                "invalid Phred+{offset} quality \"{}\"",
+                code as char
+            ))),
+        })
+        .collect()
+}
+
+pub fn encode_qualities(qualities: &[u8], offset: u8) -> PyResult<String> {
+    qualities
+        .iter()
+        .map(|&quality| match quality.checked_add(offset) {
+            Some(code) if code <= b'~' => Ok(code as char),
+            _ => Err(PyValueError::new_err(format!(
+                "quality {quality} cannot be encoded as Phred+{offset}"
+            ))),
+        })
+        .collect()
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, offset = 33))]
+pub fn read_fastq(path: PathBuf, offset: u8) -> PyResult<FastqReader> {
+    let offset = check_offset(offset)?;
+
+    Ok(FastqReader {
+        lines: BufReader::new(File::open(path)?).lines(),
+        offset,
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, records, offset = 33))]
+pub fn write_fastq(path: PathBuf, records: Bound<'_, PyAny>, offset: u8) -> PyResult<()> {
+    let offset = check_offset(offset)?;
+    let mut file = BufWriter::new(File::create(path)?);
+
+    for record in records.try_iter()? {
+        let record = record?;
+        let record = record.cast::<FastqRecord>()?.get();
+
+        writeln!(file, "@{}", join_header(&record.id, &record.description))?;
+        writeln!(
+            file,
+            "{}",
+            record
+                .sequence
+                .par_iter()
+                .map(char::from)
+                .collect::<String>()
+        )?;
+        writeln!(file, "+")?;
+        writeln!(file, "{}", encode_qualities(&record.qualities, offset)?)?;
+    }
+
+    Ok(file.flush()?)
+}
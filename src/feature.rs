@@ -0,0 +1,82 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+#[pyclass(frozen, get_all)]
+pub struct Feature {
+    pub kind: String,
+    pub start: usize,
+    pub end: usize,
+    pub strand: Option<i8>,
+    pub qualifiers: Py<PyDict>,
+}
+
+#[pymethods]
+impl Feature {
+    #[new]
+    #[pyo3(signature = (kind, start, end, strand = None, qualifiers = None))]
+    fn __new__(
+        py: Python<'_>,
+        kind: String,
+        start: usize,
+        end: usize,
+        strand: Option<i8>,
+        qualifiers: Option<Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        if start > end {
+            return Err(PyValueError::new_err("feature start is after its end"));
+        }
+
+        if !matches!(strand, None | Some(1) | Some(-1)) {
+            return Err(PyValueError::new_err(
+                "feature strand must be 1, -1 or None",
+            ));
+        }
+
+        Ok(Self {
+            kind,
+            start,
+            end,
+            strand,
+            qualifiers: match qualifiers {
+                Some(qualifiers) => qualifiers.unbind(),
+                None => PyDict::new(py).unbind(),
+            },
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Feature: {} {}..{}>", self.kind, self.start, self.end)
+    }
+
+    fn __eq__(&self, py: Python<'_>, other: &Self) -> PyResult<bool> {
+        Ok(self.kind == other.kind
+            && self.start == other.start
+            && self.end == other.end
+            && self.strand == other.strand
+            && self.qualifiers.bind(py).eq(other.qualifiers.bind(py))?)
+    }
+
+    fn __len__(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl Feature {
+    pub fn with_location(&self, py: Python<'_>, start: usize, end: usize) -> Self {
+        Self {
+            kind: self.kind.clone(),
+            start,
+            end,
+            strand: self.strand,
+            qualifiers: self.qualifiers.clone_ref(py),
+        }
+    }
+
+    pub fn reverse_complemented(&self, py: Python<'_>, length: usize) -> Self {
+        Self {
+            strand: self.strand.map(|strand| -strand),
+            ..self.with_location(py, length - self.end, length - self.start)
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use crate::dnasequence::{DNASequence, DNASequenceInput};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+const MAGIC: &[u8; 7] = b"HAEMFMI";
+const VERSION: u8 = 2;
+// Symbol 0 terminates each sequence, other symbols are DNABase discriminants
+// offset by one.
+const CODES: &[u8; 17] = b"$ACGTMRWSYKVHDBN-";
+const SYMBOLS: usize = CODES.len();
+const SAMPLE_RATE: usize = 64;
+
+#[derive(FromPyObject)]
+pub enum FMIndexInput<'py> {
+    Sequence(PyRef<'py, DNASequence>),
+    Sequences(Vec<PyRef<'py, DNASequence>>),
+}
+
+#[pyclass(frozen)]
+pub struct FMIndex {
+    starts: Vec<usize>,
+    suffix_array: Vec<usize>,
+    bwt: Vec<u8>,
+    counts: [usize; SYMBOLS],
+    occurrences: Vec<[usize; SYMBOLS]>,
+}
+
+#[pymethods]
+impl FMIndex {
+    #[new]
+    fn __new__(sequences: FMIndexInput) -> Self {
+        let sequences = match sequences {
+            FMIndexInput::Sequence(sequence) => vec![sequence],
+            FMIndexInput::Sequences(sequences) => sequences,
+        };
+
+        let mut text = vec![];
+
+        for sequence in &sequences {
+            text.extend(sequence.sequence.iter().map(|base| *base as u8 + 1));
+            text.push(0);
+        }
+
+        Self::build(text)
+    }
+
+    #[classmethod]
+    fn load(_cls: &Bound<'_, PyType>, path: PathBuf) -> PyResult<Self> {
+        let invalid = || PyValueError::new_err("invalid FM-index file");
+        let mut file = BufReader::new(File::open(path)?);
+
+        let mut header = [0; 8];
+        file.read_exact(&mut header).map_err(|_| invalid())?;
+        if &header[..7] != MAGIC || header[7] != VERSION {
+            return Err(invalid());
+        }
+
+        let index = Self::read(&mut file).map_err(|_| invalid())?;
+        match file.read(&mut [0])? == 0 && index.is_valid() {
+            true => Ok(index),
+            false => Err(invalid()),
+        }
+    }
+
+    fn save(&self, path: PathBuf) -> PyResult<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+
+        file.write_all(MAGIC)?;
+        file.write_all(&[VERSION])?;
+        write_numbers(&mut file, &self.starts)?;
+        write_numbers(&mut file, &self.suffix_array)?;
+        write_number(&mut file, self.bwt.len())?;
+        file.write_all(&self.bwt)?;
+        write_numbers(&mut file, &self.counts)?;
+        write_numbers(&mut file, self.occurrences.as_flattened())?;
+
+        Ok(file.flush()?)
+    }
+
+    #[getter]
+    fn get_bwt(&self) -> String {
+        self.bwt
+            .par_iter()
+            .map(|symbol| CODES[*symbol as usize] as char)
+            .collect()
+    }
+
+    #[getter]
+    fn get_suffix_array(&self) -> Vec<usize> {
+        self.suffix_array.clone()
+    }
+
+    fn count(&self, sequence: DNASequenceInput) -> PyResult<usize> {
+        let range = self.search(&DNASequence::try_from(sequence)?)?;
+        Ok(range.len())
+    }
+
+    fn locate(&self, sequence: DNASequenceInput) -> PyResult<Vec<(usize, usize)>> {
+        let range = self.search(&DNASequence::try_from(sequence)?)?;
+
+        let mut positions = self.suffix_array[range]
+            .par_iter()
+            .map(|position| {
+                let index = self.starts.partition_point(|start| start <= position) - 1;
+                (index, position - self.starts[index])
+            })
+            .collect::<Vec<_>>();
+
+        positions.par_sort_unstable();
+        Ok(positions)
+    }
+
+    fn contains(&self, sequence: DNASequenceInput) -> PyResult<bool> {
+        Ok(self.count(sequence)? > 0)
+    }
+
+    fn __contains__(&self, sequence: DNASequenceInput) -> PyResult<bool> {
+        self.contains(sequence)
+    }
+
+    fn __len__(&self) -> usize {
+        self.starts.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<FMIndex: {} sequences>", self.starts.len())
+    }
+}
+
+impl FMIndex {
+    fn build(text: Vec<u8>) -> Self {
+        // Each sequence begins after the terminator of the one before it.
+        let starts = (0..text.len())
+            .filter(|position| *position == 0 || text[position - 1] == 0)
+            .collect::<Vec<_>>();
+        let suffix_array = suffix_array(&text);
+
+        let bwt = suffix_array
+            .par_iter()
+            .map(|position| text[(position + text.len() - 1) % text.len()])
+            .collect::<Vec<_>>();
+
+        let mut counts = [0; SYMBOLS];
+        for symbol in &text {
+            counts[*symbol as usize] += 1;
+        }
+        let mut total = 0;
+        for count in counts.iter_mut() {
+            (*count, total) = (total, total + *count);
+        }
+
+        let mut occurrences = Vec::with_capacity(bwt.len() / SAMPLE_RATE + 1);
+        let mut running = [0; SYMBOLS];
+        for (index, symbol) in bwt.iter().enumerate() {
+            if index.is_multiple_of(SAMPLE_RATE) {
+                occurrences.push(running);
+            }
+            running[*symbol as usize] += 1;
+        }
+        if bwt.len().is_multiple_of(SAMPLE_RATE) {
+            occurrences.push(running);
+        }
+
+        Self {
+            starts,
+            suffix_array,
+            bwt,
+            counts,
+            occurrences,
+        }
+    }
+
+    // Sections are read in the order save writes them, each prefixed by its
+    // length.
+    fn read(file: &mut impl Read) -> io::Result<Self> {
+        let starts = read_numbers(file)?;
+        let suffix_array = read_numbers(file)?;
+        let length = read_number(file)?;
+        let bwt = read_bytes(file, length)?;
+        let counts = read_numbers(file)?
+            .try_into()
+            .map_err(|_| io::ErrorKind::InvalidData)?;
+        let occurrences = read_numbers(file)?;
+
+        if !occurrences.len().is_multiple_of(SYMBOLS) {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+
+        Ok(Self {
+            starts,
+            suffix_array,
+            bwt,
+            counts,
+            occurrences: occurrences
+                .chunks_exact(SYMBOLS)
+                .map(|sample| sample.try_into().unwrap())
+                .collect(),
+        })
+    }
+
+    // Whether a loaded index is one that build could have produced, so that a
+    // corrupt file cannot cause searches to index out of bounds.
+    fn is_valid(&self) -> bool {
+        let length = self.bwt.len();
+
+        let mut seen = vec![false; length];
+        let suffix_array = self.suffix_array.len() == length
+            && self.suffix_array.iter().all(|position| {
+                *position < length && !std::mem::replace(&mut seen[*position], true)
+            });
+
+        // Every suffix falls within a sequence, which locate relies on.
+        let starts = self.starts.first().map_or(length == 0, |start| *start == 0)
+            && self.starts.windows(2).all(|pair| pair[0] < pair[1])
+            && self.starts.last().is_none_or(|start| *start < length);
+
+        if !suffix_array || !starts || self.occurrences.len() != length / SAMPLE_RATE + 1 {
+            return false;
+        }
+
+        let mut running = [0; SYMBOLS];
+        for (index, symbol) in self.bwt.iter().enumerate() {
+            if index.is_multiple_of(SAMPLE_RATE) && self.occurrences[index / SAMPLE_RATE] != running
+            {
+                return false;
+            }
+            match running.get_mut(*symbol as usize) {
+                Some(count) => *count += 1,
+                None => return false,
+            }
+        }
+        if length.is_multiple_of(SAMPLE_RATE) && self.occurrences[length / SAMPLE_RATE] != running {
+            return false;
+        }
+
+        // Each count is the total of the symbols before it, so that together
+        // they sum to the length of the text.
+        let mut total = 0;
+        self.counts.iter().zip(running).all(|(count, occurrences)| {
+            let valid = *count == total;
+            total += occurrences;
+            valid
+        }) && total == length
+            && running[0] == self.starts.len()
+    }
+
+    // Occurrences of a symbol in the BWT before the given index.
+    fn occurrences(&self, symbol: usize, index: usize) -> usize {
+        let sample = index / SAMPLE_RATE;
+
+        self.occurrences[sample][symbol]
+            + self.bwt[sample * SAMPLE_RATE..index]
+                .iter()
+                .filter(|other| **other as usize == symbol)
+                .count()
+    }
+
+    // Backward search, narrowing the range of suffixes prefixed by the
+    // sequence one base at a time from its end.
+    fn search(&self, sequence: &DNASequence) -> PyResult<std::ops::Range<usize>> {
+        if sequence.sequence.is_empty() {
+            return Err(PyValueError::new_err("sequence must not be empty"));
+        }
+
+        let (mut low, mut high) = (0, self.bwt.len());
+
+        for base in sequence.sequence.iter().rev() {
+            let symbol = *base as usize + 1;
+            low = self.counts[symbol] + self.occurrences(symbol, low);
+            high = self.counts[symbol] + self.occurrences(symbol, high);
+
+            if low >= high {
+                return Ok(0..0);
+            }
+        }
+
+        Ok(low..high)
+    }
+}
+
+fn write_number(file: &mut impl Write, number: usize) -> io::Result<()> {
+    file.write_all(&(number as u64).to_le_bytes())
+}
+
+fn write_numbers(file: &mut impl Write, numbers: &[usize]) -> io::Result<()> {
+    write_number(file, numbers.len())?;
+    numbers
+        .iter()
+        .try_for_each(|number| write_number(file, *number))
+}
+
+fn read_number(file: &mut impl Read) -> io::Result<usize> {
+    let mut bytes = [0; 8];
+    file.read_exact(&mut bytes)?;
+
+    usize::try_from(u64::from_le_bytes(bytes)).map_err(|_| io::ErrorKind::InvalidData.into())
+}
+
+// Reads no more than the file holds, so that a corrupt length cannot cause a
+// huge allocation.
+fn read_bytes(file: &mut impl Read, length: usize) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![];
+    file.take(length as u64).read_to_end(&mut bytes)?;
+
+    match bytes.len() == length {
+        true => Ok(bytes),
+        false => Err(io::ErrorKind::UnexpectedEof.into()),
+    }
+}
+
+fn read_numbers(file: &mut impl Read) -> io::Result<Vec<usize>> {
+    let length = read_number(file)?
+        .checked_mul(8)
+        .ok_or(io::ErrorKind::InvalidData)?;
+
+    read_bytes(file, length)?
+        .chunks_exact(8)
+        .map(|bytes| {
+            usize::try_from(u64::from_le_bytes(bytes.try_into().unwrap()))
+                .map_err(|_| io::ErrorKind::InvalidData.into())
+        })
+        .collect()
+}
+
+// Prefix doubling: suffixes are sorted by their first 2^k symbols, using the
+// ranks of the previous round as sort keys.
+fn suffix_array(text: &[u8]) -> Vec<usize> {
+    let length = text.len();
+    let mut ranks = text
+        .iter()
+        .map(|symbol| *symbol as usize)
+        .collect::<Vec<_>>();
+    let mut suffix_array = (0..length).collect::<Vec<_>>();
+    let mut next = vec![0; length];
+    let mut step = 1;
+
+    loop {
+        let key = |position: usize| {
+            (
+                ranks[position],
+                ranks.get(position + step).map_or(0, |rank| rank + 1),
+            )
+        };
+
+        suffix_array.par_sort_unstable_by_key(|position| key(*position));
+
+        if let Some(first) = suffix_array.first() {
+            next[*first] = 0;
+        }
+        for index in 1..length {
+            next[suffix_array[index]] = next[suffix_array[index - 1]]
+                + (key(suffix_array[index]) != key(suffix_array[index - 1])) as usize;
+        }
+
+        if length == 0 || next[suffix_array[length - 1]] == length - 1 {
+            return suffix_array;
+        }
+
+        ranks.clone_from(&next);
+        step *= 2;
+    }
+}
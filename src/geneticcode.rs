@@ -0,0 +1,439 @@
+use crate::aminoacid::{AminoAcid, StopTranslation};
+use crate::dnabase::DNABase;
+use crate::member::Member;
+use crate::rnabase::RNABase;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::sync::OnceLock;
+
+struct Table {
+    id: u8,
+    name: &'static str,
+    amino_acids: &'static str,
+    starts: &'static str,
+}
+
+// Codons are ordered as in the NCBI tables: UUU, UUC, UUA, UUG, UCU, ...
+// Codons that NCBI lists as either stop or sense (tables 27, 28 and 31) are
+// treated as stops.
+const TABLES: [Table; 27] = [
+    Table {
+        id: 1,
+        name: "Standard",
+        amino_acids: "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "---M---------------M---------------M----------------------------",
+    },
+    Table {
+        id: 2,
+        name: "Vertebrate Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+        starts: "--------------------------------MMMM---------------M------------",
+    },
+    Table {
+        id: 3,
+        name: "Yeast Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "----------------------------------MM---------------M------------",
+    },
+    Table {
+        id: 4,
+        name: "Mold Mitochondrial; Protozoan Mitochondrial; Coelenterate Mitochondrial; Mycoplasma; Spiroplasma",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "--MM---------------M------------MMMM---------------M------------",
+    },
+    Table {
+        id: 5,
+        name: "Invertebrate Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+        starts: "---M----------------------------MMMM---------------M------------",
+    },
+    Table {
+        id: 6,
+        name: "Ciliate Nuclear; Dasycladacean Nuclear; Hexamita Nuclear",
+        amino_acids: "FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 9,
+        name: "Echinoderm Mitochondrial; Flatworm Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M---------------M------------",
+    },
+    Table {
+        id: 10,
+        name: "Euplotid Nuclear",
+        amino_acids: "FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 11,
+        name: "Bacterial, Archaeal and Plant Plastid",
+        amino_acids: "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "---M---------------M------------MMMM---------------M------------",
+    },
+    Table {
+        id: 12,
+        name: "Alternative Yeast Nuclear",
+        amino_acids: "FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-------------------M---------------M----------------------------",
+    },
+    Table {
+        id: 13,
+        name: "Ascidian Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+        starts: "---M------------------------------MM---------------M------------",
+    },
+    Table {
+        id: 14,
+        name: "Alternative Flatworm Mitochondrial",
+        amino_acids: "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 15,
+        name: "Blepharisma Macronuclear",
+        amino_acids: "FFLLSSSSYY*QCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 16,
+        name: "Chlorophycean Mitochondrial",
+        amino_acids: "FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 21,
+        name: "Trematode Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M---------------M------------",
+    },
+    Table {
+        id: 22,
+        name: "Scenedesmus obliquus Mitochondrial",
+        amino_acids: "FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 23,
+        name: "Thraustochytrium Mitochondrial",
+        amino_acids: "FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "--------------------------------M--M---------------M------------",
+    },
+    Table {
+        id: 24,
+        name: "Rhabdopleuridae Mitochondrial",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        starts: "---M---------------M---------------M---------------M------------",
+    },
+    Table {
+        id: 25,
+        name: "Candidate Division SR1 and Gracilibacteria",
+        amino_acids: "FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "---M-------------------------------M---------------M------------",
+    },
+    Table {
+        id: 26,
+        name: "Pachysolen tannophilus Nuclear",
+        amino_acids: "FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-------------------M---------------M----------------------------",
+    },
+    Table {
+        id: 27,
+        name: "Karyorelict Nuclear",
+        amino_acids: "FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 28,
+        name: "Condylostoma Nuclear",
+        amino_acids: "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 29,
+        name: "Mesodinium Nuclear",
+        amino_acids: "FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 30,
+        name: "Peritrich Nuclear",
+        amino_acids: "FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 31,
+        name: "Blastocrithidia Nuclear",
+        amino_acids: "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "-----------------------------------M----------------------------",
+    },
+    Table {
+        id: 32,
+        name: "Balanophoraceae Plastid",
+        amino_acids: "FFLLSSSSYY*WCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        starts: "---M---------------M------------MMMM---------------M------------",
+    },
+    Table {
+        id: 33,
+        name: "Cephalodiscidae Mitochondrial",
+        amino_acids: "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        starts: "---M---------------M---------------M---------------M------------",
+    },
+];
+
+static LOOKUPS: [OnceLock<Vec<Translation>>; TABLES.len()] =
+    [const { OnceLock::new() }; TABLES.len()];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Translation {
+    AminoAcid(AminoAcid),
+    Stop,
+    Ambiguous,
+    Gap,
+}
+
+impl Translation {
+    fn resolve(translations: &[Option<AminoAcid>]) -> Self {
+        let mut distinct = Vec::with_capacity(translations.len());
+        for translation in translations {
+            if !distinct.contains(translation) {
+                distinct.push(*translation);
+            }
+        }
+
+        match distinct.as_slice() {
+            [] => Self::Gap,
+            [Some(amino_acid)] => Self::AminoAcid(*amino_acid),
+            [None] => Self::Stop,
+            [Some(first), Some(second)] => match (first, second) {
+                (AminoAcid::AsparticAcid, AminoAcid::Asparagine)
+                | (AminoAcid::Asparagine, AminoAcid::AsparticAcid) => {
+                    Self::AminoAcid(AminoAcid::AsparticAcidAsparagine)
+                }
+                (AminoAcid::GlutamicAcid, AminoAcid::Glutamine)
+                | (AminoAcid::Glutamine, AminoAcid::GlutamicAcid) => {
+                    Self::AminoAcid(AminoAcid::GlutamineGlutamicAcid)
+                }
+                (AminoAcid::Leucine, AminoAcid::Isoleucine)
+                | (AminoAcid::Isoleucine, AminoAcid::Leucine) => {
+                    Self::AminoAcid(AminoAcid::LeucineIsoleucine)
+                }
+                _ => Self::Ambiguous,
+            },
+            _ => Self::Ambiguous,
+        }
+    }
+}
+
+impl TryFrom<Translation> for AminoAcid {
+    type Error = PyErr;
+
+    fn try_from(translation: Translation) -> PyResult<AminoAcid> {
+        match translation {
+            Translation::AminoAcid(amino_acid) => Ok(amino_acid),
+            Translation::Stop => Err(StopTranslation::new_err("stop translation")),
+            Translation::Ambiguous => Err(PyValueError::new_err("ambiguous codon")),
+            Translation::Gap => Err(PyValueError::new_err("codon contains gap")),
+        }
+    }
+}
+
+// DNA and RNA codons share lookups, keyed on the RNA equivalent of each base.
+pub trait CodonBase: Sync {
+    fn rna(&self) -> RNABase;
+}
+
+impl CodonBase for RNABase {
+    fn rna(&self) -> RNABase {
+        *self
+    }
+}
+
+impl CodonBase for DNABase {
+    fn rna(&self) -> RNABase {
+        self.into()
+    }
+}
+
+fn ncbi_index(codon: (&RNABase, &RNABase, &RNABase)) -> usize {
+    let index = |base: &RNABase| match base {
+        RNABase::Uracil => 0,
+        RNABase::Cytosine => 1,
+        RNABase::Adenine => 2,
+        RNABase::Guanine => 3,
+        _ => unreachable!("ambiguous bases are expanded before indexing"),
+    };
+
+    index(codon.0) * 16 + index(codon.1) * 4 + index(codon.2)
+}
+
+fn expand(codon: (&RNABase, &RNABase, &RNABase)) -> Vec<usize> {
+    let mut indices = vec![];
+
+    for first in codon.0.expand() {
+        for second in codon.1.expand() {
+            for third in codon.2.expand() {
+                indices.push(ncbi_index((first, second, third)));
+            }
+        }
+    }
+
+    indices
+}
+
+#[pyclass(frozen, eq, from_py_object)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct GeneticCode {
+    index: usize,
+}
+
+#[pymethods]
+impl GeneticCode {
+    #[new]
+    pub fn __new__(table: GeneticCodeInput) -> PyResult<Self> {
+        table.try_into()
+    }
+
+    #[getter]
+    fn get_id(&self) -> u8 {
+        self.table().id
+    }
+
+    #[getter]
+    fn get_name(&self) -> &'static str {
+        self.table().name
+    }
+
+    #[getter]
+    fn get_start_codons(&self) -> Vec<String> {
+        self.codons(|index| self.table().starts.as_bytes()[index] == b'M')
+    }
+
+    #[getter]
+    fn get_stop_codons(&self) -> Vec<String> {
+        self.codons(|index| self.table().amino_acids.as_bytes()[index] == b'*')
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<GeneticCode: {}>", self.table().id)
+    }
+
+    fn __str__(&self) -> String {
+        self.table().name.to_string()
+    }
+}
+
+impl GeneticCode {
+    pub fn standard() -> Self {
+        Self { index: 0 }
+    }
+
+    fn table(&self) -> &'static Table {
+        &TABLES[self.index]
+    }
+
+    fn codons(&self, predicate: impl Fn(usize) -> bool) -> Vec<String> {
+        let bases = [
+            RNABase::Uracil,
+            RNABase::Cytosine,
+            RNABase::Adenine,
+            RNABase::Guanine,
+        ];
+
+        (0..64)
+            .filter(|index| predicate(*index))
+            .map(|index| {
+                [index / 16, index / 4 % 4, index % 4]
+                    .iter()
+                    .map(|base| char::from(&bases[*base]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn amino_acid(&self, index: usize) -> Option<AminoAcid> {
+        match self.table().amino_acids.as_bytes()[index] {
+            b'*' => None,
+            code => Some(AminoAcid::try_from(code as char).expect("genetic code tables are valid")),
+        }
+    }
+
+    fn lookups(&self) -> &'static [Translation] {
+        LOOKUPS[self.index].get_or_init(|| {
+            let bases = (0..16)
+                .map(|code| RNABase::try_from("ACGUMRWSYKVHDBN-".as_bytes()[code] as char))
+                .collect::<PyResult<Vec<_>>>()
+                .expect("IUPAC RNA codes are valid");
+
+            let mut lookups = Vec::with_capacity(4096);
+            for first in &bases {
+                for second in &bases {
+                    for third in &bases {
+                        lookups.push(Translation::resolve(
+                            &expand((first, second, third))
+                                .into_iter()
+                                .map(|index| self.amino_acid(index))
+                                .collect::<Vec<_>>(),
+                        ));
+                    }
+                }
+            }
+
+            lookups
+        })
+    }
+
+    pub fn is_start<B: CodonBase>(&self, codon: (&B, &B, &B)) -> bool {
+        let indices = expand((&codon.0.rna(), &codon.1.rna(), &codon.2.rna()));
+
+        !indices.is_empty()
+            && indices
+                .iter()
+                .all(|index| self.table().starts.as_bytes()[*index] == b'M')
+    }
+
+    pub fn lookup<B: CodonBase>(&self, codon: (&B, &B, &B)) -> Translation {
+        self.lookups()
+            [codon.0.rna() as usize * 256 + codon.1.rna() as usize * 16 + codon.2.rna() as usize]
+    }
+
+    pub fn translate<B: CodonBase>(&self, codon: (&B, &B, &B)) -> PyResult<AminoAcid> {
+        self.lookup(codon).try_into()
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum GeneticCodeInput {
+    GeneticCode(GeneticCode),
+    Id(u8),
+    Name(String),
+}
+
+impl TryFrom<GeneticCodeInput> for GeneticCode {
+    type Error = PyErr;
+
+    fn try_from(table: GeneticCodeInput) -> PyResult<Self> {
+        let index = match &table {
+            GeneticCodeInput::GeneticCode(code) => return Ok(*code),
+            GeneticCodeInput::Id(id) => TABLES.iter().position(|table| table.id == *id),
+            GeneticCodeInput::Name(name) => TABLES.iter().position(|table| {
+                table.name.eq_ignore_ascii_case(name)
+                    || table
+                        .name
+                        .split("; ")
+                        .any(|alias| alias.eq_ignore_ascii_case(name))
+            }),
+        };
+
+        match (index, table) {
+            (Some(index), _) => Ok(Self { index }),
+            (None, GeneticCodeInput::Name(name)) => Err(PyValueError::new_err(format!(
+                "unknown genetic code \"{name}\""
+            ))),
+            (None, GeneticCodeInput::Id(id)) => {
+                Err(PyValueError::new_err(format!("unknown genetic code {id}")))
+            }
+            (None, GeneticCodeInput::GeneticCode(_)) => unreachable!(),
+        }
+    }
+}
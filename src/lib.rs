@@ -1,12 +1,38 @@
+mod alignment;
+mod alignmentformat;
 mod aminoacid;
 mod aminoacidsequence;
+mod aminoacidsequenceview;
+mod consensus;
+mod distance;
 mod dnabase;
 mod dnasequence;
+mod dnasequenceview;
+mod fasta;
+mod fastq;
+mod feature;
+mod fmindex;
+mod geneticcode;
+mod matchiterator;
 mod member;
+mod motif;
+mod multiplealignment;
+mod mutableaminoacidsequence;
+mod mutablednasequence;
+mod mutablernasequence;
+mod mutablesequence;
+mod orf;
+mod packeddnasequence;
+mod pairwisealignment;
+mod patternmatcher;
 mod rnabase;
 mod rnasequence;
+mod rnasequenceview;
 #[macro_use]
 mod sequence;
+mod sequencerecord;
+mod substitutionmatrix;
+mod translation;
 mod utils;
 
 use pyo3::prelude::*;
@@ -22,15 +48,87 @@ mod haem {
     #[pymodule_export]
     use crate::rnasequence::RNASequence;
 
+    #[pymodule_export]
+    use crate::rnasequenceview::RNASequenceView;
+
+    #[pymodule_export]
+    use crate::mutablernasequence::MutableRNASequence;
+
     #[pymodule_export]
     use crate::dnabase::DNABase;
 
     #[pymodule_export]
     use crate::dnasequence::DNASequence;
 
+    #[pymodule_export]
+    use crate::dnasequenceview::DNASequenceView;
+
+    #[pymodule_export]
+    use crate::packeddnasequence::PackedDNASequence;
+
+    #[pymodule_export]
+    use crate::mutablednasequence::MutableDNASequence;
+
     #[pymodule_export]
     use crate::aminoacid::AminoAcid;
 
     #[pymodule_export]
     use crate::aminoacidsequence::AminoAcidSequence;
+
+    #[pymodule_export]
+    use crate::aminoacidsequenceview::AminoAcidSequenceView;
+
+    #[pymodule_export]
+    use crate::mutableaminoacidsequence::MutableAminoAcidSequence;
+
+    #[pymodule_export]
+    use crate::geneticcode::GeneticCode;
+
+    #[pymodule_export]
+    use crate::orf::OpenReadingFrame;
+
+    #[pymodule_export]
+    use crate::matchiterator::MatchIterator;
+
+    #[pymodule_export]
+    use crate::patternmatcher::PatternMatcher;
+
+    #[pymodule_export]
+    use crate::fmindex::FMIndex;
+
+    #[pymodule_export]
+    use crate::motif::Motif;
+
+    #[pymodule_export]
+    use crate::pairwisealignment::{align_pairwise, PairwiseAlignment};
+
+    #[pymodule_export]
+    use crate::substitutionmatrix::SubstitutionMatrix;
+
+    #[pymodule_export]
+    use crate::alignment::Alignment;
+
+    #[pymodule_export]
+    use crate::alignmentformat::{read_alignment, write_alignment};
+
+    #[pymodule_export]
+    use crate::multiplealignment::align_multiple;
+
+    #[pymodule_export]
+    use crate::consensus::consensus;
+
+    #[pymodule_export]
+    use crate::distance::{distance_matrix, hamming_distance, levenshtein_distance};
+
+    #[pymodule_export]
+    use crate::fasta::{read_fasta, write_fasta, FastaReader, FastaRecord};
+
+    #[pymodule_export]
+    use crate::fastq::{read_fastq, write_fastq, FastqReader, FastqRecord};
+
+    #[pymodule_export]
+    use crate::feature::Feature;
+
+    #[pymodule_export]
+    use crate::sequencerecord::SequenceRecord;
 }
@@ -0,0 +1,77 @@
+use crate::aminoacid::AminoAcid;
+use crate::aminoacidsequence::AminoAcidSequence;
+use crate::dnabase::DNABase;
+use crate::dnasequence::DNASequence;
+use crate::member::Member;
+use crate::rnabase::RNABase;
+use crate::rnasequence::RNASequence;
+use crate::sequence::next_match;
+use pyo3::prelude::*;
+use std::ops::Range;
+
+#[allow(clippy::enum_variant_names)]
+pub enum MatchTarget {
+    DNASequence(Py<DNASequence>, Vec<DNABase>),
+    RNASequence(Py<RNASequence>, Vec<RNABase>),
+    AminoAcidSequence(Py<AminoAcidSequence>, Vec<AminoAcid>),
+}
+
+#[pyclass]
+pub struct MatchIterator {
+    target: MatchTarget,
+    position: usize,
+    end: usize,
+    overlap: bool,
+    ambiguous: bool,
+}
+
+#[pymethods]
+impl MatchIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<usize> {
+        let (index, length) = match &self.target {
+            MatchTarget::DNASequence(sequence, pattern) => (
+                self.next_match(&sequence.borrow(py).sequence, pattern),
+                pattern.len(),
+            ),
+            MatchTarget::RNASequence(sequence, pattern) => (
+                self.next_match(&sequence.borrow(py).sequence, pattern),
+                pattern.len(),
+            ),
+            MatchTarget::AminoAcidSequence(sequence, pattern) => (
+                self.next_match(&sequence.borrow(py).sequence, pattern),
+                pattern.len(),
+            ),
+        };
+
+        let index = index?;
+        self.position = match self.overlap {
+            true => index + 1,
+            false => index + length,
+        };
+
+        Some(index)
+    }
+}
+
+impl MatchIterator {
+    pub fn new(target: MatchTarget, range: Range<usize>, overlap: bool, ambiguous: bool) -> Self {
+        Self {
+            target,
+            position: range.start,
+            end: range.end,
+            overlap,
+            ambiguous,
+        }
+    }
+
+    fn next_match<T>(&self, members: &[T], pattern: &[T]) -> Option<usize>
+    where
+        T: Member + PartialEq + 'static,
+    {
+        next_match(&members[..self.end], pattern, self.position, self.ambiguous)
+    }
+}
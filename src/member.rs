@@ -3,6 +3,31 @@ use rayon::iter::{once, IntoParallelIterator};
 use rayon::prelude::*;
 
 pub trait Member {
+    const GAP: Self;
+
+    // Every member, ordered by index.
+    fn members() -> &'static [Self]
+    where
+        Self: Sized + 'static;
+
+    // The unambiguous members an IUPAC code stands for.
+    fn expand(&self) -> &'static [Self]
+    where
+        Self: Sized + 'static;
+
+    fn index(&self) -> u8;
+
+    fn matches(&self, other: &Self) -> bool
+    where
+        Self: Sized + PartialEq + 'static,
+    {
+        self == other
+            || self
+                .expand()
+                .iter()
+                .any(|member| other.expand().contains(member))
+    }
+
     fn add(&self, other: &[Self], swap: bool) -> Vec<Self>
     where
         Self: Sync + Send + Clone,
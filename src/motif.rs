@@ -0,0 +1,301 @@
+use crate::aminoacid::AminoAcid;
+use crate::aminoacidsequence::{AminoAcidSequence, AminoAcidSequenceInput};
+use crate::member::Member;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use rayon::prelude::*;
+use std::iter::Peekable;
+use std::str::Chars;
+
+// The amino acids matched by a single position, as a bit set of AminoAcid
+// discriminants.
+#[derive(Clone, Copy)]
+struct Class(u32);
+
+impl Class {
+    // Any amino acid, including ambiguous codes but neither stops nor gaps.
+    const ANY: Self = Self(((1 << AminoAcid::Gap as u32) - 1) & !(1 << AminoAcid::Stop as u32));
+
+    fn contains(&self, amino_acid: &AminoAcid) -> bool {
+        self.0 & 1 << *amino_acid as u32 != 0
+    }
+
+    // Ambiguous codes in a motif stand for themselves and each of their amino
+    // acids.
+    fn insert(&mut self, amino_acid: AminoAcid) {
+        if amino_acid == AminoAcid::Any {
+            self.0 |= Self::ANY.0;
+        } else {
+            self.0 |= 1 << amino_acid as u32;
+            for amino_acid in amino_acid.expand() {
+                self.0 |= 1 << *amino_acid as u32;
+            }
+        }
+    }
+
+    fn invert(self) -> Self {
+        Self(Self::ANY.0 & !self.0)
+    }
+}
+
+struct Element {
+    class: Class,
+    min: usize,
+    max: Option<usize>,
+}
+
+struct Parser<'a> {
+    pattern: &'a str,
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(pattern: &'a str) -> Self {
+        Self {
+            pattern,
+            chars: pattern.chars().peekable(),
+        }
+    }
+
+    fn invalid(&self) -> PyErr {
+        PyValueError::new_err(format!("invalid motif \"{}\"", self.pattern))
+    }
+
+    fn next(&mut self) -> PyResult<char> {
+        self.chars.next().ok_or_else(|| self.invalid())
+    }
+
+    fn accept(&mut self, expected: char) -> bool {
+        self.chars.next_if_eq(&expected).is_some()
+    }
+
+    fn expect(&mut self, expected: char) -> PyResult<()> {
+        match self.accept(expected) {
+            true => Ok(()),
+            false => Err(self.invalid()),
+        }
+    }
+
+    fn number(&mut self) -> PyResult<Option<usize>> {
+        let mut number: Option<usize> = None;
+
+        while let Some(digit) = self.chars.next_if(char::is_ascii_digit) {
+            number = Some(
+                number
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|number| number.checked_add(digit as usize - '0' as usize))
+                    .ok_or_else(|| PyValueError::new_err("repeat count too large"))?,
+            );
+        }
+
+        Ok(number)
+    }
+
+    // Codes up to the closing character of a character class.
+    fn class(&mut self, close: char) -> PyResult<Class> {
+        let mut class = Class(0);
+
+        loop {
+            match self.next()? {
+                code if code == close && class.0 == 0 => return Err(self.invalid()),
+                code if code == close => return Ok(class),
+                code => class.insert(AminoAcid::try_from(code)?),
+            }
+        }
+    }
+
+    // Bounds of a repeat, after its opening character.
+    fn repeat(&mut self, close: char) -> PyResult<(usize, Option<usize>)> {
+        let min = self.number()?.ok_or_else(|| self.invalid())?;
+        let max = match self.accept(',') {
+            true => self.number()?,
+            false => Some(min),
+        };
+        self.expect(close)?;
+
+        match max {
+            Some(max) if max < min => Err(self.invalid()),
+            _ => Ok((min, max)),
+        }
+    }
+
+    fn regex(mut self) -> PyResult<Motif> {
+        let start = self.accept('^');
+        let mut elements = vec![];
+        let mut end = false;
+
+        while let Some(code) = self.chars.next() {
+            let class = match code {
+                '$' if self.chars.peek().is_none() => {
+                    end = true;
+                    break;
+                }
+                '.' => Class::ANY,
+                '[' if self.accept('^') => self.class(']')?.invert(),
+                '[' => self.class(']')?,
+                code => {
+                    let mut class = Class(0);
+                    class.insert(AminoAcid::try_from(code)?);
+                    class
+                }
+            };
+
+            let (min, max) = match self.chars.next_if(|next| "?*+{".contains(*next)) {
+                Some('?') => (0, Some(1)),
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some(_) => self.repeat('}')?,
+                None => (1, Some(1)),
+            };
+            elements.push(Element { class, min, max });
+        }
+
+        Motif::new(self.pattern, elements, start, end)
+    }
+
+    fn prosite(mut self) -> PyResult<Motif> {
+        let start = self.accept('<');
+        let mut elements = vec![];
+
+        let end = loop {
+            let class = match self.next()? {
+                'x' => Class::ANY,
+                '[' => self.class(']')?,
+                '{' => self.class('}')?.invert(),
+                code => {
+                    let mut class = Class(0);
+                    class.insert(AminoAcid::try_from(code)?);
+                    class
+                }
+            };
+
+            let (min, max) = match self.accept('(') {
+                true => self.repeat(')')?,
+                false => (1, Some(1)),
+            };
+            elements.push(Element { class, min, max });
+
+            let end = self.accept('>');
+            match self.chars.next() {
+                Some('-') if !end => continue,
+                Some('.') | None if self.chars.peek().is_none() => break end,
+                _ => return Err(self.invalid()),
+            }
+        };
+
+        Motif::new(self.pattern, elements, start, end)
+    }
+}
+
+#[pyclass(frozen)]
+pub struct Motif {
+    #[pyo3(get)]
+    pattern: String,
+    elements: Vec<Element>,
+    start: bool,
+    end: bool,
+}
+
+#[pymethods]
+impl Motif {
+    #[new]
+    fn __new__(pattern: &str) -> PyResult<Self> {
+        Parser::new(pattern).regex()
+    }
+
+    #[classmethod]
+    fn from_prosite(_cls: &Bound<'_, PyType>, pattern: &str) -> PyResult<Self> {
+        Parser::new(pattern).prosite()
+    }
+
+    #[pyo3(signature = (sequence, overlap = false))]
+    fn find_all(
+        &self,
+        sequence: AminoAcidSequenceInput,
+        overlap: bool,
+    ) -> PyResult<Vec<(usize, usize)>> {
+        let sequence = AminoAcidSequence::try_from(sequence)?;
+        let members = &sequence.sequence;
+
+        let starts = match self.start {
+            true => 0..1,
+            false => 0..members.len() + 1,
+        };
+
+        let matches = starts
+            .into_par_iter()
+            .filter_map(|start| Some((start, self.match_at(members, start)?)))
+            .collect::<Vec<_>>();
+
+        if overlap {
+            return Ok(matches);
+        }
+
+        let mut position = 0;
+        Ok(matches
+            .into_iter()
+            .filter(|(start, end)| {
+                let next = *start >= position;
+                if next {
+                    position = *end;
+                }
+                next
+            })
+            .collect())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Motif: {}>", self.pattern)
+    }
+}
+
+impl Motif {
+    fn new(pattern: &str, elements: Vec<Element>, start: bool, end: bool) -> PyResult<Self> {
+        if elements.iter().all(|element| element.min == 0) {
+            return Err(PyValueError::new_err(
+                "motif must not match an empty sequence",
+            ));
+        }
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            elements,
+            start,
+            end,
+        })
+    }
+
+    // The end of the longest match beginning at the start position, found
+    // by tracking every position each element could leave the match at.
+    fn match_at(&self, members: &[AminoAcid], start: usize) -> Option<usize> {
+        let mut positions = vec![start];
+
+        for element in &self.elements {
+            let mut next = vec![];
+
+            for position in positions {
+                let run = members[position..]
+                    .iter()
+                    .take(element.max.unwrap_or(usize::MAX))
+                    .take_while(|member| element.class.contains(member))
+                    .count();
+
+                next.extend((element.min..=run).map(|length| position + length));
+            }
+
+            next.sort_unstable();
+            next.dedup();
+            positions = next;
+        }
+
+        match self.end {
+            true => positions
+                .last()
+                .filter(|end| **end == members.len())
+                .copied(),
+            false => positions.last().copied(),
+        }
+    }
+}
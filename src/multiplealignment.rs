@@ -0,0 +1,319 @@
+use crate::alignment::{Alignment, Row, Rows};
+use crate::pairwisealignment::{align, Mode, Operation};
+use crate::sequence::AnySequence;
+use crate::substitutionmatrix::{Alphabet, SubstitutionMatrix};
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+// Profile columns are scored by the mean score of their pairs of members,
+// which is kept to two decimal places by scaling every score.
+const SCALE: i32 = 100;
+
+struct Scores<'a> {
+    match_score: i32,
+    mismatch_score: i32,
+    matrix: Option<&'a SubstitutionMatrix>,
+}
+
+impl Scores<'_> {
+    // Members are scored by their row of the substitution matrix, or compared
+    // by code without one.
+    fn symbols<T>(&self, members: &[T], alphabet: Alphabet) -> PyResult<Vec<usize>>
+    where
+        for<'a> char: From<&'a T>,
+    {
+        match self.matrix {
+            Some(matrix) => matrix.indices(members, alphabet),
+            None => Ok(members
+                .iter()
+                .map(|member| char::from(member) as usize)
+                .collect()),
+        }
+    }
+
+    fn score(&self, first: usize, second: usize) -> i32 {
+        match (self.matrix, first == second) {
+            (Some(matrix), _) => matrix.score(first, second),
+            (None, true) => self.match_score,
+            (None, false) => self.mismatch_score,
+        }
+    }
+}
+
+// Aligned sequences as rows of positions in each sequence, None for gaps.
+struct Profile {
+    sequences: Vec<usize>,
+    rows: Vec<Vec<Option<usize>>>,
+}
+
+impl Profile {
+    fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    // The number of each symbol in each column, ignoring gaps.
+    fn counts(&self, symbols: &[Vec<usize>]) -> Vec<Vec<(usize, i32)>> {
+        (0..self.width())
+            .map(|column| {
+                let mut counts: Vec<(usize, i32)> = vec![];
+                for (sequence, row) in self.sequences.iter().zip(&self.rows) {
+                    if let Some(position) = row[column] {
+                        let symbol = symbols[*sequence][position];
+                        match counts.iter_mut().find(|(other, _)| *other == symbol) {
+                            Some((_, count)) => *count += 1,
+                            None => counts.push((symbol, 1)),
+                        }
+                    }
+                }
+                counts
+            })
+            .collect()
+    }
+
+    // Gotoh's algorithm over columns rather than members, where a pair of
+    // columns scores the mean over every pair of their members. Gaps within
+    // a column score nothing.
+    fn align(
+        self,
+        other: Self,
+        symbols: &[Vec<usize>],
+        scores: &Scores,
+        gap_open: i32,
+        gap_extend: i32,
+    ) -> Self {
+        let (first, second) = (self.counts(symbols), other.counts(symbols));
+        let pairs = (self.rows.len() * other.rows.len()) as f64;
+        let columns = |profile: &Self| (0..profile.width()).collect::<Vec<_>>();
+
+        let aligned = align(
+            &columns(&self),
+            &columns(&other),
+            Mode::Global,
+            gap_open * SCALE,
+            gap_extend * SCALE,
+            |i, j| {
+                let total = first[*i]
+                    .iter()
+                    .flat_map(|(a, a_count)| {
+                        second[*j]
+                            .iter()
+                            .map(move |(b, b_count)| a_count * b_count * scores.score(*a, *b))
+                    })
+                    .sum::<i32>();
+                (total as f64 * SCALE as f64 / pairs).round() as i32
+            },
+        );
+
+        let mut rows = vec![vec![]; self.rows.len() + other.rows.len()];
+        let (mut i, mut j) = (0, 0);
+        for operation in &aligned.operations {
+            let (left, right) = match operation {
+                Operation::Match => (Some(i), Some(j)),
+                Operation::Deletion => (Some(i), None),
+                _ => (None, Some(j)),
+            };
+
+            let columns = self
+                .rows
+                .iter()
+                .map(|row| left.and_then(|i| row[i]))
+                .chain(other.rows.iter().map(|row| right.and_then(|j| row[j])));
+            for (row, position) in rows.iter_mut().zip(columns) {
+                row.push(position);
+            }
+
+            i += left.is_some() as usize;
+            j += right.is_some() as usize;
+        }
+
+        Self {
+            sequences: [self.sequences, other.sequences].concat(),
+            rows,
+        }
+    }
+}
+
+// The fraction of aligned pairs of members that differ in the global
+// alignment of two sequences.
+fn distance(
+    first: &[usize],
+    second: &[usize],
+    scores: &Scores,
+    gap_open: i32,
+    gap_extend: i32,
+) -> f64 {
+    let aligned = align(first, second, Mode::Global, gap_open, gap_extend, |a, b| {
+        scores.score(*a, *b)
+    });
+
+    let (mut i, mut j, mut pairs, mut identical) = (0, 0, 0, 0);
+    for operation in &aligned.operations {
+        if *operation == Operation::Match {
+            pairs += 1;
+            identical += (first[i] == second[j]) as usize;
+        }
+        i += (*operation != Operation::Insertion) as usize;
+        j += (*operation != Operation::Deletion) as usize;
+    }
+
+    match pairs {
+        0 => 1.0,
+        _ => 1.0 - identical as f64 / pairs as f64,
+    }
+}
+
+// A guide tree built by UPGMA from pairwise distances, given as the order in
+// which pairs of clusters are joined.
+fn guide_tree(distances: &mut [Vec<f64>]) -> Vec<(usize, usize)> {
+    let mut sizes = vec![1.0; distances.len()];
+    let mut active = (0..distances.len()).collect::<Vec<_>>();
+    let mut joins = vec![];
+
+    while active.len() > 1 {
+        let (mut closest, mut nearest) = ((active[0], active[1]), f64::INFINITY);
+        for (index, i) in active.iter().enumerate() {
+            for j in &active[index + 1..] {
+                if distances[*i][*j] < nearest {
+                    (closest, nearest) = ((*i, *j), distances[*i][*j]);
+                }
+            }
+        }
+
+        let (i, j) = closest;
+        for k in active.iter().filter(|k| **k != i && **k != j) {
+            let distance =
+                (sizes[i] * distances[i][*k] + sizes[j] * distances[j][*k]) / (sizes[i] + sizes[j]);
+            distances[i][*k] = distance;
+            distances[*k][i] = distance;
+        }
+
+        sizes[i] += sizes[j];
+        active.retain(|k| *k != j);
+        joins.push((i, j));
+    }
+
+    joins
+}
+
+fn align_progressive<T>(
+    sequences: Vec<Vec<T>>,
+    scores: &Scores,
+    gap_open: i32,
+    gap_extend: i32,
+    alphabet: Alphabet,
+) -> PyResult<Vec<Vec<T>>>
+where
+    T: Row,
+    for<'a> char: From<&'a T>,
+{
+    // Existing gaps are removed so that sequences are aligned afresh.
+    let sequences = sequences
+        .into_iter()
+        .map(|sequence| {
+            sequence
+                .into_iter()
+                .filter(|member| *member != T::GAP)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let symbols = sequences
+        .iter()
+        .map(|sequence| scores.symbols(sequence, alphabet))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let pairs = (0..symbols.len())
+        .flat_map(|i| (i + 1..symbols.len()).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+    let pairwise = pairs
+        .par_iter()
+        .map(|(i, j)| distance(&symbols[*i], &symbols[*j], scores, gap_open, gap_extend))
+        .collect::<Vec<_>>();
+
+    let mut distances = vec![vec![0.0; symbols.len()]; symbols.len()];
+    for ((i, j), distance) in pairs.into_iter().zip(pairwise) {
+        distances[i][j] = distance;
+        distances[j][i] = distance;
+    }
+
+    let mut profiles = sequences
+        .iter()
+        .enumerate()
+        .map(|(index, sequence)| {
+            Some(Profile {
+                sequences: vec![index],
+                rows: vec![(0..sequence.len()).map(Some).collect()],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (i, j) in guide_tree(&mut distances) {
+        let (first, second) = (profiles[i].take().unwrap(), profiles[j].take().unwrap());
+        profiles[i] = Some(first.align(second, &symbols, scores, gap_open, gap_extend));
+    }
+
+    let mut rows = vec![vec![]; sequences.len()];
+    if let Some(profile) = profiles.into_iter().flatten().next() {
+        for (sequence, row) in profile.sequences.into_iter().zip(profile.rows) {
+            rows[sequence] = row
+                .into_iter()
+                .map(|position| position.map_or(T::GAP, |position| sequences[sequence][position]))
+                .collect();
+        }
+    }
+
+    Ok(rows)
+}
+
+#[pyfunction]
+#[pyo3(signature = (
+    sequences,
+    ids = None,
+    *,
+    match_score = 1,
+    mismatch_score = -1,
+    gap_open = -2,
+    gap_extend = -1,
+    matrix = None,
+))]
+pub fn align_multiple(
+    sequences: Vec<AnySequence<'_>>,
+    ids: Option<Vec<String>>,
+    match_score: i32,
+    mismatch_score: i32,
+    gap_open: i32,
+    gap_extend: i32,
+    matrix: Option<PyRef<'_, SubstitutionMatrix>>,
+) -> PyResult<Alignment> {
+    let scores = Scores {
+        match_score,
+        mismatch_score,
+        matrix: matrix.as_deref(),
+    };
+
+    let rows = match Rows::try_from(sequences)? {
+        Rows::DNASequence(sequences) => Rows::DNASequence(align_progressive(
+            sequences,
+            &scores,
+            gap_open,
+            gap_extend,
+            Alphabet::Nucleotide,
+        )?),
+        Rows::RNASequence(sequences) => Rows::RNASequence(align_progressive(
+            sequences,
+            &scores,
+            gap_open,
+            gap_extend,
+            Alphabet::Nucleotide,
+        )?),
+        Rows::AminoAcidSequence(sequences) => Rows::AminoAcidSequence(align_progressive(
+            sequences,
+            &scores,
+            gap_open,
+            gap_extend,
+            Alphabet::AminoAcid,
+        )?),
+    };
+
+    Alignment::new(ids, rows)
+}
@@ -0,0 +1,198 @@
+use crate::aminoacid::AminoAcid;
+use crate::aminoacidsequence::{AminoAcidSequence, AminoAcidSequenceInput};
+use crate::impl_sequence;
+use crate::member::{MemberOrCode, MemberOrMembers};
+use crate::mutablesequence::{delitem, insert, setitem};
+use crate::sequence::{Sequence, SequenceInput};
+use crate::utils::IntOrSlice;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+#[pyclass(module = "haem")]
+pub struct MutableAminoAcidSequence {
+    pub sequence: Vec<AminoAcid>,
+}
+
+#[pymethods]
+impl MutableAminoAcidSequence {
+    #[new]
+    #[pyo3(signature = (
+        sequence = MutableAminoAcidSequenceInput::Sequence(
+            AminoAcidSequenceInput::Sequence(SequenceInput::Seq(vec![]))
+        )
+    ))]
+    fn __new__(sequence: MutableAminoAcidSequenceInput) -> PyResult<Self> {
+        Ok(Vec::try_from(sequence)?.into())
+    }
+
+    fn freeze(&self) -> AminoAcidSequence {
+        self.sequence.clone().into()
+    }
+
+    fn append(&mut self, amino_acid: MemberOrCode<AminoAcid>) -> PyResult<()> {
+        self.sequence.push(amino_acid.into_member()?);
+        Ok(())
+    }
+
+    fn extend(slf: &Bound<'_, Self>, sequence: MutableAminoAcidSequenceInput) -> PyResult<()> {
+        let amino_acids = Vec::try_from(sequence)?;
+        slf.borrow_mut().sequence.extend(amino_acids);
+        Ok(())
+    }
+
+    #[pyo3(name = "insert")]
+    fn py_insert(&mut self, index: isize, amino_acid: MemberOrCode<AminoAcid>) -> PyResult<()> {
+        insert(&mut self.sequence, index, amino_acid.into_member()?);
+        Ok(())
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: MutableAminoAcidSequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.count(
+            &Vec::try_from(sequence)?,
+            overlap,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: MutableAminoAcidSequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        self.find(
+            &Vec::try_from(sequence)?,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "contains", signature = (sequence, *, ambiguous = false))]
+    fn py_contains(
+        &self,
+        sequence: MutableAminoAcidSequenceInput,
+        ambiguous: bool,
+    ) -> PyResult<bool> {
+        self.contains(&Vec::try_from(sequence)?, ambiguous)
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+
+    fn __str__(&self) -> String {
+        self.str()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (AminoAcidSequence,)) {
+        (slf.py().get_type::<Self>(), (slf.borrow().freeze(),))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.sequence.clone().into()
+    }
+
+    fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+        self.__copy__()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.bool()
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __iadd__(slf: &Bound<'_, Self>, other: MutableAminoAcidSequenceInput) -> PyResult<()> {
+        Self::extend(slf, other)
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match self.getitem(index_or_slice)? {
+            MemberOrMembers::Member(amino_acid) => amino_acid.into_pyobject(py)?.into_any(),
+            MemberOrMembers::Sequence(sequence) => {
+                Self::from(sequence).into_pyobject(py)?.into_any()
+            }
+        })
+    }
+
+    fn __setitem__(
+        slf: &Bound<'_, Self>,
+        index_or_slice: IntOrSlice,
+        sequence: MutableAminoAcidSequenceInput,
+    ) -> PyResult<()> {
+        let amino_acids = Vec::try_from(sequence)?;
+        setitem(
+            &mut slf.borrow_mut().sequence,
+            "MutableAminoAcidSequence",
+            index_or_slice,
+            amino_acids,
+        )
+    }
+
+    fn __delitem__(&mut self, index_or_slice: IntOrSlice) -> PyResult<()> {
+        delitem(
+            &mut self.sequence,
+            "MutableAminoAcidSequence",
+            index_or_slice,
+        )
+    }
+
+    fn __contains__(&self, sequence: MutableAminoAcidSequenceInput) -> PyResult<bool> {
+        self.py_contains(sequence, false)
+    }
+}
+
+impl_sequence!(
+    MutableAminoAcidSequence,
+    AminoAcid,
+    "MutableAminoAcidSequence"
+);
+
+// Sequences of either kind are copied directly rather than being iterated
+// over as other sequence inputs are.
+#[derive(FromPyObject)]
+pub enum MutableAminoAcidSequenceInput<'py> {
+    AminoAcidSequence(PyRef<'py, AminoAcidSequence>),
+    MutableAminoAcidSequence(PyRef<'py, MutableAminoAcidSequence>),
+    Sequence(AminoAcidSequenceInput<'py>),
+}
+
+impl<'py> TryFrom<MutableAminoAcidSequenceInput<'py>> for Vec<AminoAcid> {
+    type Error = PyErr;
+
+    fn try_from(sequence: MutableAminoAcidSequenceInput<'py>) -> PyResult<Self> {
+        Ok(match sequence {
+            MutableAminoAcidSequenceInput::AminoAcidSequence(sequence) => sequence.sequence.clone(),
+            MutableAminoAcidSequenceInput::MutableAminoAcidSequence(sequence) => {
+                sequence.sequence.clone()
+            }
+            MutableAminoAcidSequenceInput::Sequence(sequence) => {
+                AminoAcidSequence::try_from(sequence)?.sequence
+            }
+        })
+    }
+}
@@ -0,0 +1,193 @@
+use crate::dnabase::DNABase;
+use crate::dnasequence::{DNASequence, DNASequenceInput};
+use crate::impl_sequence;
+use crate::member::{MemberOrCode, MemberOrMembers};
+use crate::mutablesequence::{delitem, insert, setitem};
+use crate::sequence::{Sequence, SequenceInput};
+use crate::utils::IntOrSlice;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use rayon::prelude::*;
+
+#[pyclass(module = "haem")]
+pub struct MutableDNASequence {
+    pub sequence: Vec<DNABase>,
+}
+
+#[pymethods]
+impl MutableDNASequence {
+    #[new]
+    #[pyo3(signature = (
+        sequence = MutableDNASequenceInput::Sequence(
+            DNASequenceInput::Sequence(SequenceInput::Seq(vec![]))
+        )
+    ))]
+    fn __new__(sequence: MutableDNASequenceInput) -> PyResult<Self> {
+        Ok(Vec::try_from(sequence)?.into())
+    }
+
+    fn freeze(&self) -> DNASequence {
+        self.sequence.clone().into()
+    }
+
+    fn append(&mut self, base: MemberOrCode<DNABase>) -> PyResult<()> {
+        self.sequence.push(base.into_member()?);
+        Ok(())
+    }
+
+    fn extend(slf: &Bound<'_, Self>, sequence: MutableDNASequenceInput) -> PyResult<()> {
+        let bases = Vec::try_from(sequence)?;
+        slf.borrow_mut().sequence.extend(bases);
+        Ok(())
+    }
+
+    #[pyo3(name = "insert")]
+    fn py_insert(&mut self, index: isize, base: MemberOrCode<DNABase>) -> PyResult<()> {
+        insert(&mut self.sequence, index, base.into_member()?);
+        Ok(())
+    }
+
+    fn reverse_complement_inplace(&mut self) {
+        self.sequence.reverse();
+        self.sequence
+            .par_iter_mut()
+            .for_each(|base| *base = base.get_complement());
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: MutableDNASequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.count(
+            &Vec::try_from(sequence)?,
+            overlap,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: MutableDNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        self.find(
+            &Vec::try_from(sequence)?,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "contains", signature = (sequence, *, ambiguous = false))]
+    fn py_contains(&self, sequence: MutableDNASequenceInput, ambiguous: bool) -> PyResult<bool> {
+        self.contains(&Vec::try_from(sequence)?, ambiguous)
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+
+    fn __str__(&self) -> String {
+        self.str()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    // Pickled as the frozen sequence, which has a compact encoding of its own.
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (DNASequence,)) {
+        (slf.py().get_type::<Self>(), (slf.borrow().freeze(),))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.sequence.clone().into()
+    }
+
+    fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+        self.__copy__()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.bool()
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __iadd__(slf: &Bound<'_, Self>, other: MutableDNASequenceInput) -> PyResult<()> {
+        Self::extend(slf, other)
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match self.getitem(index_or_slice)? {
+            MemberOrMembers::Member(base) => base.into_pyobject(py)?.into_any(),
+            MemberOrMembers::Sequence(sequence) => {
+                Self::from(sequence).into_pyobject(py)?.into_any()
+            }
+        })
+    }
+
+    fn __setitem__(
+        slf: &Bound<'_, Self>,
+        index_or_slice: IntOrSlice,
+        sequence: MutableDNASequenceInput,
+    ) -> PyResult<()> {
+        let bases = Vec::try_from(sequence)?;
+        setitem(
+            &mut slf.borrow_mut().sequence,
+            "MutableDNASequence",
+            index_or_slice,
+            bases,
+        )
+    }
+
+    fn __delitem__(&mut self, index_or_slice: IntOrSlice) -> PyResult<()> {
+        delitem(&mut self.sequence, "MutableDNASequence", index_or_slice)
+    }
+
+    fn __contains__(&self, sequence: MutableDNASequenceInput) -> PyResult<bool> {
+        self.py_contains(sequence, false)
+    }
+}
+
+impl_sequence!(MutableDNASequence, DNABase, "MutableDNASequence");
+
+// Sequences of either kind are copied directly rather than being iterated
+// over as other sequence inputs are.
+#[derive(FromPyObject)]
+pub enum MutableDNASequenceInput<'py> {
+    DNASequence(PyRef<'py, DNASequence>),
+    MutableDNASequence(PyRef<'py, MutableDNASequence>),
+    Sequence(DNASequenceInput<'py>),
+}
+
+impl<'py> TryFrom<MutableDNASequenceInput<'py>> for Vec<DNABase> {
+    type Error = PyErr;
+
+    fn try_from(sequence: MutableDNASequenceInput<'py>) -> PyResult<Self> {
+        Ok(match sequence {
+            MutableDNASequenceInput::DNASequence(sequence) => sequence.sequence.clone(),
+            MutableDNASequenceInput::MutableDNASequence(sequence) => sequence.sequence.clone(),
+            MutableDNASequenceInput::Sequence(sequence) => {
+                DNASequence::try_from(sequence)?.sequence
+            }
+        })
+    }
+}
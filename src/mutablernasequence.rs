@@ -0,0 +1,192 @@
+use crate::impl_sequence;
+use crate::member::{MemberOrCode, MemberOrMembers};
+use crate::mutablesequence::{delitem, insert, setitem};
+use crate::rnabase::RNABase;
+use crate::rnasequence::{RNASequence, RNASequenceInput};
+use crate::sequence::{Sequence, SequenceInput};
+use crate::utils::IntOrSlice;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use rayon::prelude::*;
+
+#[pyclass(module = "haem")]
+pub struct MutableRNASequence {
+    pub sequence: Vec<RNABase>,
+}
+
+#[pymethods]
+impl MutableRNASequence {
+    #[new]
+    #[pyo3(signature = (
+        sequence = MutableRNASequenceInput::Sequence(
+            RNASequenceInput::Sequence(SequenceInput::Seq(vec![]))
+        )
+    ))]
+    fn __new__(sequence: MutableRNASequenceInput) -> PyResult<Self> {
+        Ok(Vec::try_from(sequence)?.into())
+    }
+
+    fn freeze(&self) -> RNASequence {
+        self.sequence.clone().into()
+    }
+
+    fn append(&mut self, base: MemberOrCode<RNABase>) -> PyResult<()> {
+        self.sequence.push(base.into_member()?);
+        Ok(())
+    }
+
+    fn extend(slf: &Bound<'_, Self>, sequence: MutableRNASequenceInput) -> PyResult<()> {
+        let bases = Vec::try_from(sequence)?;
+        slf.borrow_mut().sequence.extend(bases);
+        Ok(())
+    }
+
+    #[pyo3(name = "insert")]
+    fn py_insert(&mut self, index: isize, base: MemberOrCode<RNABase>) -> PyResult<()> {
+        insert(&mut self.sequence, index, base.into_member()?);
+        Ok(())
+    }
+
+    fn reverse_complement_inplace(&mut self) {
+        self.sequence.reverse();
+        self.sequence
+            .par_iter_mut()
+            .for_each(|base| *base = base.get_complement());
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: MutableRNASequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        self.count(
+            &Vec::try_from(sequence)?,
+            overlap,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: MutableRNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        self.find(
+            &Vec::try_from(sequence)?,
+            self.bounds(start, end),
+            ambiguous,
+        )
+    }
+
+    #[pyo3(name = "contains", signature = (sequence, *, ambiguous = false))]
+    fn py_contains(&self, sequence: MutableRNASequenceInput, ambiguous: bool) -> PyResult<bool> {
+        self.contains(&Vec::try_from(sequence)?, ambiguous)
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr()
+    }
+
+    fn __str__(&self) -> String {
+        self.str()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.eq(other)
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (RNASequence,)) {
+        (slf.py().get_type::<Self>(), (slf.borrow().freeze(),))
+    }
+
+    fn __copy__(&self) -> Self {
+        self.sequence.clone().into()
+    }
+
+    fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+        self.__copy__()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.bool()
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __iadd__(slf: &Bound<'_, Self>, other: MutableRNASequenceInput) -> PyResult<()> {
+        Self::extend(slf, other)
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match self.getitem(index_or_slice)? {
+            MemberOrMembers::Member(base) => base.into_pyobject(py)?.into_any(),
+            MemberOrMembers::Sequence(sequence) => {
+                Self::from(sequence).into_pyobject(py)?.into_any()
+            }
+        })
+    }
+
+    fn __setitem__(
+        slf: &Bound<'_, Self>,
+        index_or_slice: IntOrSlice,
+        sequence: MutableRNASequenceInput,
+    ) -> PyResult<()> {
+        let bases = Vec::try_from(sequence)?;
+        setitem(
+            &mut slf.borrow_mut().sequence,
+            "MutableRNASequence",
+            index_or_slice,
+            bases,
+        )
+    }
+
+    fn __delitem__(&mut self, index_or_slice: IntOrSlice) -> PyResult<()> {
+        delitem(&mut self.sequence, "MutableRNASequence", index_or_slice)
+    }
+
+    fn __contains__(&self, sequence: MutableRNASequenceInput) -> PyResult<bool> {
+        self.py_contains(sequence, false)
+    }
+}
+
+impl_sequence!(MutableRNASequence, RNABase, "MutableRNASequence");
+
+// Sequences of either kind are copied directly rather than being iterated
+// over as other sequence inputs are.
+#[derive(FromPyObject)]
+pub enum MutableRNASequenceInput<'py> {
+    RNASequence(PyRef<'py, RNASequence>),
+    MutableRNASequence(PyRef<'py, MutableRNASequence>),
+    Sequence(RNASequenceInput<'py>),
+}
+
+impl<'py> TryFrom<MutableRNASequenceInput<'py>> for Vec<RNABase> {
+    type Error = PyErr;
+
+    fn try_from(sequence: MutableRNASequenceInput<'py>) -> PyResult<Self> {
+        Ok(match sequence {
+            MutableRNASequenceInput::RNASequence(sequence) => sequence.sequence.clone(),
+            MutableRNASequenceInput::MutableRNASequence(sequence) => sequence.sequence.clone(),
+            MutableRNASequenceInput::Sequence(sequence) => {
+                RNASequence::try_from(sequence)?.sequence
+            }
+        })
+    }
+}
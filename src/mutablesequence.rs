@@ -0,0 +1,114 @@
+use crate::utils::IntOrSlice;
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PySlice;
+
+pub fn index(length: usize, index: isize, name: &str) -> PyResult<usize> {
+    let resolved = match index {
+        index if index < 0 => length.checked_sub(index.unsigned_abs()),
+        index => Some(index as usize),
+    };
+
+    resolved
+        .filter(|index| *index < length)
+        .ok_or_else(|| PyIndexError::new_err(format!("{name} index out of range")))
+}
+
+// The positions a slice selects, in the order it selects them, or the range
+// it covers where it is contiguous so that it may be spliced.
+enum Positions {
+    Contiguous(usize, usize),
+    Extended(Vec<usize>),
+}
+
+fn positions(length: usize, slice: &Bound<'_, PySlice>) -> PyResult<Positions> {
+    let indices = slice.indices(length as isize)?;
+
+    Ok(match indices.step {
+        1 => Positions::Contiguous(
+            indices.start as usize,
+            indices.stop.max(indices.start) as usize,
+        ),
+        step => Positions::Extended(
+            (0..indices.slicelength)
+                .map(|i| (indices.start + i as isize * step) as usize)
+                .collect(),
+        ),
+    })
+}
+
+// As for Python's lists, a single member may be assigned to an index and any
+// number of members to a contiguous slice, but an extended slice must be
+// assigned as many members as it selects.
+pub fn setitem<T>(
+    members: &mut Vec<T>,
+    name: &str,
+    index_or_slice: IntOrSlice,
+    values: Vec<T>,
+) -> PyResult<()> {
+    match index_or_slice {
+        IntOrSlice::Int(i) => {
+            let i = index(members.len(), i, name)?;
+            match <[T; 1]>::try_from(values) {
+                Ok([value]) => members[i] = value,
+                Err(_) => {
+                    return Err(PyValueError::new_err(
+                        "only a single member can be assigned to an index",
+                    ))
+                }
+            }
+        }
+        IntOrSlice::Slice(slice) => match positions(members.len(), &slice)? {
+            Positions::Contiguous(start, end) => {
+                members.splice(start..end, values);
+            }
+            Positions::Extended(positions) => {
+                if positions.len() != values.len() {
+                    return Err(PyValueError::new_err(format!(
+                        "attempt to assign sequence of size {} to extended slice of size {}",
+                        values.len(),
+                        positions.len()
+                    )));
+                }
+                for (position, value) in positions.into_iter().zip(values) {
+                    members[position] = value;
+                }
+            }
+        },
+    }
+
+    Ok(())
+}
+
+pub fn delitem<T>(members: &mut Vec<T>, name: &str, index_or_slice: IntOrSlice) -> PyResult<()> {
+    match index_or_slice {
+        IntOrSlice::Int(i) => {
+            members.remove(index(members.len(), i, name)?);
+        }
+        IntOrSlice::Slice(slice) => match positions(members.len(), &slice)? {
+            Positions::Contiguous(start, end) => {
+                members.drain(start..end);
+            }
+            Positions::Extended(positions) => {
+                let mut removed = vec![false; members.len()];
+                for position in positions {
+                    removed[position] = true;
+                }
+                let mut removed = removed.into_iter();
+                members.retain(|_| !removed.next().unwrap_or(false));
+            }
+        },
+    }
+
+    Ok(())
+}
+
+// Indices past either end insert at that end, as with list.insert.
+pub fn insert<T>(members: &mut Vec<T>, index: isize, value: T) {
+    let index = match index {
+        index if index < 0 => members.len().saturating_sub(index.unsigned_abs()),
+        index => (index as usize).min(members.len()),
+    };
+
+    members.insert(index, value);
+}
@@ -0,0 +1,129 @@
+use crate::aminoacid::AminoAcid;
+use crate::aminoacidsequence::AminoAcidSequence;
+use crate::geneticcode::{CodonBase, GeneticCode, Translation};
+use crate::translation::{lenient_amino_acid, translate_start};
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+#[pyclass(frozen)]
+pub struct OpenReadingFrame {
+    #[pyo3(get)]
+    pub start: usize,
+    #[pyo3(get)]
+    pub end: usize,
+    #[pyo3(get)]
+    pub strand: i8,
+    #[pyo3(get)]
+    pub frame: usize,
+    pub sequence: Vec<AminoAcid>,
+}
+
+#[pymethods]
+impl OpenReadingFrame {
+    #[getter]
+    fn get_sequence(&self) -> AminoAcidSequence {
+        self.sequence.clone().into()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<OpenReadingFrame: {}..{} {}>",
+            self.start,
+            self.end,
+            if self.strand == 1 { '+' } else { '-' }
+        )
+    }
+
+    fn __len__(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+pub struct OrfFinder {
+    pub table: GeneticCode,
+    pub min_length: usize,
+    pub nested: bool,
+    pub alternative_starts: bool,
+}
+
+impl OrfFinder {
+    // Coordinates on the reverse strand are reported relative to the forward
+    // strand, so `reverse` must be the reverse complement of `forward`.
+    pub fn find<B: CodonBase>(&self, forward: &[B], reverse: &[B]) -> Vec<OpenReadingFrame> {
+        let mut orfs = [(1, forward), (-1, reverse)]
+            .into_par_iter()
+            .flat_map(|(strand, bases)| {
+                (0..3)
+                    .into_par_iter()
+                    .flat_map(move |frame| self.find_in_frame(bases, strand, frame))
+            })
+            .collect::<Vec<_>>();
+
+        orfs.sort_by_key(|orf| (orf.start, orf.end));
+        orfs
+    }
+
+    fn find_in_frame<B: CodonBase>(
+        &self,
+        bases: &[B],
+        strand: i8,
+        frame: usize,
+    ) -> Vec<OpenReadingFrame> {
+        let translations = bases
+            .get(frame..)
+            .unwrap_or_default()
+            .chunks_exact(3)
+            .map(|bases| (self.table.lookup((&bases[0], &bases[1], &bases[2])), bases))
+            .collect::<Vec<_>>();
+
+        let mut orfs = vec![];
+        let mut starts: Vec<usize> = vec![];
+
+        for (index, (translation, codon)) in translations.iter().enumerate() {
+            if *translation == Translation::Stop {
+                for &start in &starts {
+                    if index - start < self.min_length {
+                        continue;
+                    }
+
+                    let (begin, end) = (frame + start * 3, frame + index * 3 + 3);
+                    let (begin, end) = match strand {
+                        1 => (begin, end),
+                        _ => (bases.len() - end, bases.len() - begin),
+                    };
+
+                    orfs.push(OpenReadingFrame {
+                        start: begin,
+                        end,
+                        strand,
+                        frame,
+                        sequence: Self::amino_acids(&translations[start..index]),
+                    });
+                }
+
+                starts.clear();
+            } else if (self.nested || starts.is_empty()) && self.is_start(codon, translation) {
+                starts.push(index);
+            }
+        }
+
+        orfs
+    }
+
+    fn is_start<B: CodonBase>(&self, codon: &[B], translation: &Translation) -> bool {
+        match self.alternative_starts {
+            true => self.table.is_start((&codon[0], &codon[1], &codon[2])),
+            false => *translation == Translation::AminoAcid(AminoAcid::Methionine),
+        }
+    }
+
+    fn amino_acids<B>(translations: &[(Translation, &[B])]) -> Vec<AminoAcid> {
+        let mut amino_acids = translations
+            .iter()
+            .map(|(translation, _)| lenient_amino_acid(*translation))
+            .collect::<Vec<_>>();
+
+        translate_start(&mut amino_acids);
+        amino_acids
+    }
+}
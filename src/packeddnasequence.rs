@@ -0,0 +1,327 @@
+use crate::dnabase::DNABase;
+use crate::dnasequence::{DNASequence, DNASequenceInput};
+use crate::member::{Member, MemberOrCode};
+use crate::sequence::{bounds, Indexed, SequenceInput};
+use crate::utils::IntOrSlice;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+// Bases are stored by index, in two bits each where the sequence is only A, C,
+// G and T and in four bits otherwise. The first base of each byte is held in
+// its lowest bits.
+#[pyclass(frozen, module = "haem")]
+pub struct PackedDNASequence {
+    bits: usize,
+    length: usize,
+    bytes: Vec<u8>,
+}
+
+impl PackedDNASequence {
+    // Packs the bases whose indices `index` gives for each position, checking
+    // every index first to choose between two and four bits.
+    fn pack<F>(length: usize, index: F) -> PyResult<Self>
+    where
+        F: Fn(usize) -> PyResult<u8> + Sync,
+    {
+        let widest = (0..length)
+            .into_par_iter()
+            .map(&index)
+            .try_reduce(|| 0, |a, b| Ok(a.max(b)))?;
+        let bits = match widest < 4 {
+            true => 2,
+            false => 4,
+        };
+        let per_byte = 8 / bits;
+
+        let bytes = (0..length.div_ceil(per_byte))
+            .into_par_iter()
+            .map(|byte| {
+                (byte * per_byte..length.min((byte + 1) * per_byte)).try_fold(0, |packed, i| {
+                    Ok(packed | index(i)? << (i % per_byte * bits))
+                })
+            })
+            .collect::<PyResult<_>>()?;
+
+        Ok(Self {
+            bits,
+            length,
+            bytes,
+        })
+    }
+
+    // Packs each part in turn, as a single sequence.
+    fn concat(parts: &[Bases]) -> PyResult<Self> {
+        Self::pack(parts.iter().map(Bases::length).sum(), |mut i| {
+            for part in parts {
+                if i < part.length() {
+                    return part.index(i);
+                }
+                i -= part.length();
+            }
+            unreachable!()
+        })
+    }
+
+    // Iterators can only be read once, so their bases are packed as they come,
+    // moving to four bits at the first base that is not A, C, G or T.
+    fn push(&mut self, index: u8) {
+        if self.bits == 2 && index >= 4 {
+            self.bytes = self
+                .bytes
+                .iter()
+                .flat_map(|byte| {
+                    [
+                        byte & 0b11 | (byte & 0b1100) << 2,
+                        byte >> 4 & 0b11 | byte >> 6 << 4,
+                    ]
+                })
+                .take(self.length.div_ceil(2))
+                .collect();
+            self.bits = 4;
+        }
+
+        let per_byte = 8 / self.bits;
+        let offset = self.length % per_byte;
+        if offset == 0 {
+            self.bytes.push(0);
+        }
+        if let Some(byte) = self.bytes.last_mut() {
+            *byte |= index << (offset * self.bits);
+        }
+        self.length += 1;
+    }
+}
+
+impl Indexed<DNABase> for PackedDNASequence {
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn get(&self, index: usize) -> DNABase {
+        let per_byte = 8 / self.bits;
+        let byte = self.bytes[index / per_byte] >> (index % per_byte * self.bits);
+
+        DNABase::members()[(byte & ((1 << self.bits) - 1)) as usize]
+    }
+}
+
+#[pymethods]
+impl PackedDNASequence {
+    #[new]
+    #[pyo3(signature = (
+        sequence = PackedDNASequenceInput::Sequence(
+            DNASequenceInput::Sequence(SequenceInput::Seq(vec![]))
+        )
+    ))]
+    pub fn __new__(sequence: PackedDNASequenceInput) -> PyResult<Self> {
+        sequence.with_bases(|bases| Self::concat(&[bases]))
+    }
+
+    #[pyo3(name = "unpack")]
+    fn py_unpack(&self) -> DNASequence {
+        self.collect(0..self.length).into()
+    }
+
+    #[getter]
+    fn get_bits_per_base(&self) -> usize {
+        self.bits
+    }
+
+    #[getter]
+    fn get_nbytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    #[pyo3(
+        name = "count",
+        signature = (sequence, overlap = false, start = None, end = None, *, ambiguous = false),
+    )]
+    fn py_count(
+        &self,
+        sequence: PackedDNASequenceInput,
+        overlap: bool,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        Ok(self.count(
+            &Vec::try_from(sequence)?,
+            overlap,
+            bounds(self.length, start, end),
+            ambiguous,
+        ))
+    }
+
+    #[pyo3(name = "find", signature = (sequence, start = None, end = None, *, ambiguous = false))]
+    fn py_find(
+        &self,
+        sequence: PackedDNASequenceInput,
+        start: Option<isize>,
+        end: Option<isize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        Ok(self.find(
+            &Vec::try_from(sequence)?,
+            bounds(self.length, start, end),
+            ambiguous,
+        ))
+    }
+
+    #[pyo3(signature = (sequence, *, ambiguous = false))]
+    fn contains(&self, sequence: PackedDNASequenceInput, ambiguous: bool) -> PyResult<bool> {
+        let sequence = Vec::try_from(sequence)?;
+
+        Ok(sequence.is_empty() || self.find(&sequence, 0..self.length, ambiguous).is_some())
+    }
+
+    fn __repr__(&self) -> String {
+        self.repr("PackedDNASequence")
+    }
+
+    fn __str__(&self) -> String {
+        self.str()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.length == other.length && self.bits == other.bits && self.bytes == other.bytes
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.hash()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.length > 0
+    }
+
+    fn __len__(&self) -> usize {
+        self.length
+    }
+
+    fn __add__(&self, other: PackedDNASequenceInput) -> PyResult<Self> {
+        other.with_bases(|other| Self::concat(&[Bases::Packed(self), other]))
+    }
+
+    fn __radd__(&self, other: PackedDNASequenceInput) -> PyResult<Self> {
+        other.with_bases(|other| Self::concat(&[other, Bases::Packed(self)]))
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match index_or_slice {
+            IntOrSlice::Int(index) => Ok(self
+                .member(index, "PackedDNASequence")?
+                .into_pyobject(py)?
+                .into_any()),
+            IntOrSlice::Slice(slice) => {
+                let indices = slice.indices(self.length as isize)?;
+
+                Ok(Self::pack(indices.slicelength, |i| {
+                    Ok(self
+                        .get((indices.start + i as isize * indices.step) as usize)
+                        .index())
+                })?
+                .into_pyobject(py)?
+                .into_any())
+            }
+        }
+    }
+
+    fn __contains__(&self, sequence: PackedDNASequenceInput) -> PyResult<bool> {
+        self.contains(sequence, false)
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum PackedDNASequenceInput<'py> {
+    PackedDNASequence(PyRef<'py, PackedDNASequence>),
+    DNASequence(PyRef<'py, DNASequence>),
+    Sequence(DNASequenceInput<'py>),
+}
+
+impl PackedDNASequenceInput<'_> {
+    // Reads the bases in place where the input can be indexed, so that they are
+    // not converted to a `Vec<DNABase>` only to be packed.
+    fn with_bases<R>(self, f: impl FnOnce(Bases) -> PyResult<R>) -> PyResult<R> {
+        match self {
+            PackedDNASequenceInput::PackedDNASequence(sequence) => f(Bases::Packed(&sequence)),
+            PackedDNASequenceInput::DNASequence(sequence) => f(Bases::Members(&sequence.sequence)),
+            PackedDNASequenceInput::Sequence(DNASequenceInput::DNASequence(sequence)) => {
+                f(Bases::Members(&sequence.sequence))
+            }
+            PackedDNASequenceInput::Sequence(DNASequenceInput::Sequence(sequence)) => {
+                match sequence {
+                    SequenceInput::Str(codes) if codes.is_ascii() => {
+                        f(Bases::Codes(codes.as_bytes()))
+                    }
+                    SequenceInput::Str(codes) => {
+                        f(Bases::Chars(&codes.chars().collect::<Vec<_>>()))
+                    }
+                    SequenceInput::Iter(bases) => {
+                        let mut packed = PackedDNASequence {
+                            bits: 2,
+                            length: 0,
+                            bytes: vec![],
+                        };
+                        for member_or_code in bases {
+                            let base = member_or_code?
+                                .extract::<MemberOrCode<DNABase>>()?
+                                .into_member()?;
+                            packed.push(base.index());
+                        }
+                        f(Bases::Packed(&packed))
+                    }
+                    SequenceInput::Seq(bases) => f(Bases::Members(&bases)),
+                    SequenceInput::SeqStr(codes) => f(Bases::Chars(&codes)),
+                    SequenceInput::Member(base) => f(Bases::Members(&[base])),
+                }
+            }
+        }
+    }
+}
+
+enum Bases<'a> {
+    Packed(&'a PackedDNASequence),
+    Members(&'a [DNABase]),
+    Codes(&'a [u8]),
+    Chars(&'a [char]),
+}
+
+impl Bases<'_> {
+    fn length(&self) -> usize {
+        match self {
+            Bases::Packed(sequence) => sequence.length,
+            Bases::Members(bases) => bases.len(),
+            Bases::Codes(codes) => codes.len(),
+            Bases::Chars(codes) => codes.len(),
+        }
+    }
+
+    fn index(&self, i: usize) -> PyResult<u8> {
+        Ok(match self {
+            Bases::Packed(sequence) => sequence.get(i).index(),
+            Bases::Members(bases) => bases[i].index(),
+            Bases::Codes(codes) => DNABase::try_from(codes[i] as char)?.index(),
+            Bases::Chars(codes) => DNABase::try_from(codes[i])?.index(),
+        })
+    }
+}
+
+impl<'py> TryFrom<PackedDNASequenceInput<'py>> for Vec<DNABase> {
+    type Error = PyErr;
+
+    fn try_from(sequence: PackedDNASequenceInput<'py>) -> PyResult<Self> {
+        match sequence {
+            PackedDNASequenceInput::PackedDNASequence(sequence) => {
+                Ok(sequence.collect(0..sequence.length))
+            }
+            PackedDNASequenceInput::DNASequence(sequence) => Ok(sequence.sequence.clone()),
+            PackedDNASequenceInput::Sequence(sequence) => {
+                Ok(DNASequence::try_from(sequence)?.sequence)
+            }
+        }
+    }
+}
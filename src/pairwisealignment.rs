@@ -0,0 +1,390 @@
+use crate::aminoacidsequence::AminoAcidSequence;
+use crate::dnasequence::DNASequence;
+use crate::member::Member;
+use crate::rnasequence::RNASequence;
+use crate::sequence::AnySequence;
+use crate::substitutionmatrix::{Alphabet, SubstitutionMatrix};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::PyClass;
+use std::ops::Range;
+
+const NEGATIVE: i32 = i32::MIN / 4;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Mode {
+    Global,
+    Local,
+    SemiGlobal,
+}
+
+impl TryFrom<&str> for Mode {
+    type Error = PyErr;
+
+    fn try_from(mode: &str) -> PyResult<Self> {
+        match mode {
+            "global" => Ok(Self::Global),
+            "local" => Ok(Self::Local),
+            "semi-global" => Ok(Self::SemiGlobal),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown alignment mode \"{mode}\""
+            ))),
+        }
+    }
+}
+
+// The state of an alignment column: a pair of aligned members, a member of
+// the first sequence against a gap or a member of the second against a gap.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Operation {
+    Match,
+    Deletion,
+    Insertion,
+    Start,
+}
+
+pub struct Aligned {
+    pub score: i32,
+    pub operations: Vec<Operation>,
+    pub first: Range<usize>,
+    pub second: Range<usize>,
+}
+
+impl Aligned {
+    pub fn gapped<T: Member + Copy>(&self, first: &[T], second: &[T]) -> (Vec<T>, Vec<T>) {
+        let (mut i, mut j) = (self.first.start, self.second.start);
+        let mut gapped = (vec![], vec![]);
+
+        for operation in &self.operations {
+            let (a, b) = match operation {
+                Operation::Match => (first[i], second[j]),
+                Operation::Deletion => (first[i], T::GAP),
+                _ => (T::GAP, second[j]),
+            };
+
+            gapped.0.push(a);
+            gapped.1.push(b);
+            i += (*operation != Operation::Insertion) as usize;
+            j += (*operation != Operation::Deletion) as usize;
+        }
+
+        gapped
+    }
+
+    pub fn cigar(&self) -> String {
+        let mut cigar = String::new();
+
+        for run in self.operations.chunk_by(|a, b| a == b) {
+            let code = match run[0] {
+                Operation::Match => 'M',
+                Operation::Deletion => 'D',
+                _ => 'I',
+            };
+            cigar.push_str(&format!("{}{code}", run.len()));
+        }
+
+        cigar
+    }
+}
+
+fn best(candidates: [(i32, Operation); 3]) -> (i32, Operation) {
+    candidates
+        .into_iter()
+        .reduce(|best, candidate| match candidate.0 > best.0 {
+            true => candidate,
+            false => best,
+        })
+        .unwrap()
+}
+
+// Gotoh's algorithm, keeping separate scores for alignments ending in each
+// operation so that gaps are opened and extended at different costs. A gap
+// of length k scores `gap_open + (k - 1) * gap_extend`.
+pub fn align<T>(
+    first: &[T],
+    second: &[T],
+    mode: Mode,
+    gap_open: i32,
+    gap_extend: i32,
+    score: impl Fn(&T, &T) -> i32,
+) -> Aligned {
+    let width = second.len() + 1;
+    let free = mode != Mode::Global;
+    let gap = |length: usize| gap_open + (length as i32 - 1) * gap_extend;
+
+    let mut trace = vec![[Operation::Start; 3]; (first.len() + 1) * width];
+    let mut previous = vec![[NEGATIVE; 3]; width];
+    let mut current = vec![[NEGATIVE; 3]; width];
+
+    // Alignments start from the top left cell, or in local and semi-global
+    // modes from any cell on the top or left edges.
+    previous[0][Operation::Match as usize] = 0;
+    for j in 1..width {
+        match free {
+            true => previous[j][Operation::Match as usize] = 0,
+            false => {
+                previous[j][Operation::Insertion as usize] = gap(j);
+                trace[j][Operation::Insertion as usize] = match j {
+                    1 => Operation::Match,
+                    _ => Operation::Insertion,
+                };
+            }
+        }
+    }
+
+    let mut end = match mode {
+        Mode::SemiGlobal => (0, (0, second.len(), Operation::Match)),
+        _ => (0, (0, 0, Operation::Match)),
+    };
+
+    for i in 1..=first.len() {
+        current[0] = [NEGATIVE; 3];
+        match free {
+            true => current[0][Operation::Match as usize] = 0,
+            false => {
+                current[0][Operation::Deletion as usize] = gap(i);
+                trace[i * width][Operation::Deletion as usize] = match i {
+                    1 => Operation::Match,
+                    _ => Operation::Deletion,
+                };
+            }
+        }
+
+        for j in 1..width {
+            let cell = i * width + j;
+            let [diagonal_match, diagonal_deletion, diagonal_insertion] = previous[j - 1];
+            let [up_match, up_deletion, up_insertion] = previous[j];
+            let [left_match, left_deletion, left_insertion] = current[j - 1];
+
+            let (mut diagonal, mut from) = best([
+                (diagonal_match, Operation::Match),
+                (diagonal_deletion, Operation::Deletion),
+                (diagonal_insertion, Operation::Insertion),
+            ]);
+            if mode == Mode::Local && diagonal < 0 {
+                (diagonal, from) = (0, Operation::Start);
+            }
+
+            let deletion = best([
+                (up_match + gap_open, Operation::Match),
+                (up_deletion + gap_extend, Operation::Deletion),
+                (up_insertion + gap_open, Operation::Insertion),
+            ]);
+            let insertion = best([
+                (left_match + gap_open, Operation::Match),
+                (left_deletion + gap_open, Operation::Deletion),
+                (left_insertion + gap_extend, Operation::Insertion),
+            ]);
+
+            let matched = diagonal + score(&first[i - 1], &second[j - 1]);
+            current[j] = [matched, deletion.0, insertion.0];
+            trace[cell] = [from, deletion.1, insertion.1];
+
+            let candidate = match mode {
+                Mode::Global => false,
+                Mode::Local => true,
+                Mode::SemiGlobal => i == first.len() || j == second.len(),
+            };
+            if candidate && matched > end.0 {
+                end = (matched, (i, j, Operation::Match));
+            }
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    if mode == Mode::Global {
+        let [matched, deletion, insertion] = previous[second.len()];
+        let (score, operation) = best([
+            (matched, Operation::Match),
+            (deletion, Operation::Deletion),
+            (insertion, Operation::Insertion),
+        ]);
+        end = (score, (first.len(), second.len(), operation));
+    }
+
+    let (score, (mut i, mut j, mut operation)) = end;
+    let (first_end, second_end) = (i, j);
+    let mut operations = vec![];
+
+    // Cells on the top or left edges in the match state are where the
+    // alignment began, rather than pairs of aligned members.
+    while operation != Operation::Match || (i > 0 && j > 0) {
+        let from = trace[i * width + j][operation as usize];
+        operations.push(operation);
+
+        i -= (operation != Operation::Insertion) as usize;
+        j -= (operation != Operation::Deletion) as usize;
+
+        if from == Operation::Start {
+            break;
+        }
+        operation = from;
+    }
+
+    operations.reverse();
+    Aligned {
+        score,
+        operations,
+        first: i..first_end,
+        second: j..second_end,
+    }
+}
+
+#[pyclass(frozen, get_all)]
+pub struct PairwiseAlignment {
+    pub score: i32,
+    pub first: Py<PyAny>,
+    pub second: Py<PyAny>,
+    pub first_start: usize,
+    pub first_end: usize,
+    pub second_start: usize,
+    pub second_end: usize,
+    pub cigar: String,
+}
+
+#[pymethods]
+impl PairwiseAlignment {
+    fn __len__(&self, py: Python<'_>) -> PyResult<usize> {
+        self.first.bind(py).len()
+    }
+
+    fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+        let first = self.first.bind(py).str()?.to_string();
+        let second = self.second.bind(py).str()?.to_string();
+        let matches = first
+            .chars()
+            .zip(second.chars())
+            .map(|(a, b)| match (a, b) {
+                ('-', _) | (_, '-') => ' ',
+                (a, b) if a == b => '|',
+                _ => '.',
+            })
+            .collect::<String>();
+
+        Ok(format!("{first}\n{matches}\n{second}"))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<PairwiseAlignment: score={}>", self.score)
+    }
+}
+
+impl PairwiseAlignment {
+    fn new<T, S>(py: Python<'_>, aligned: Aligned, first: &[T], second: &[T]) -> PyResult<Self>
+    where
+        T: Member + Copy,
+        S: PyClass + From<Vec<T>> + Into<PyClassInitializer<S>>,
+    {
+        let (first, second) = aligned.gapped(first, second);
+
+        Ok(Self {
+            score: aligned.score,
+            first: Py::new(py, S::from(first))?.into_any(),
+            second: Py::new(py, S::from(second))?.into_any(),
+            first_start: aligned.first.start,
+            first_end: aligned.first.end,
+            second_start: aligned.second.start,
+            second_end: aligned.second.end,
+            cigar: aligned.cigar(),
+        })
+    }
+}
+
+struct Scoring<'py> {
+    mode: Mode,
+    match_score: i32,
+    mismatch_score: i32,
+    gap_open: i32,
+    gap_extend: i32,
+    matrix: Option<PyRef<'py, SubstitutionMatrix>>,
+}
+
+impl Scoring<'_> {
+    fn align<T>(&self, first: &[T], second: &[T], alphabet: Alphabet) -> PyResult<Aligned>
+    where
+        T: PartialEq,
+        for<'a> char: From<&'a T>,
+    {
+        let (mode, gap_open, gap_extend) = (self.mode, self.gap_open, self.gap_extend);
+
+        Ok(match &self.matrix {
+            Some(matrix) => align(
+                &matrix.indices(first, alphabet)?,
+                &matrix.indices(second, alphabet)?,
+                mode,
+                gap_open,
+                gap_extend,
+                |a, b| matrix.score(*a, *b),
+            ),
+            None => align(first, second, mode, gap_open, gap_extend, |a, b| {
+                match a == b {
+                    true => self.match_score,
+                    false => self.mismatch_score,
+                }
+            }),
+        })
+    }
+}
+
+#[pyfunction]
+#[pyo3(name = "align", signature = (
+    first,
+    second,
+    *,
+    mode = "global",
+    match_score = 1,
+    mismatch_score = -1,
+    gap_open = -2,
+    gap_extend = -1,
+    matrix = None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn align_pairwise<'py>(
+    py: Python<'py>,
+    first: AnySequence<'py>,
+    second: AnySequence<'py>,
+    mode: &str,
+    match_score: i32,
+    mismatch_score: i32,
+    gap_open: i32,
+    gap_extend: i32,
+    matrix: Option<PyRef<'py, SubstitutionMatrix>>,
+) -> PyResult<PairwiseAlignment> {
+    let scoring = Scoring {
+        mode: Mode::try_from(mode)?,
+        match_score,
+        mismatch_score,
+        gap_open,
+        gap_extend,
+        matrix,
+    };
+
+    match (&first, &second) {
+        (AnySequence::DNASequence(first), AnySequence::DNASequence(second)) => {
+            PairwiseAlignment::new::<_, DNASequence>(
+                py,
+                scoring.align(&first.sequence, &second.sequence, Alphabet::Nucleotide)?,
+                &first.sequence,
+                &second.sequence,
+            )
+        }
+        (AnySequence::RNASequence(first), AnySequence::RNASequence(second)) => {
+            PairwiseAlignment::new::<_, RNASequence>(
+                py,
+                scoring.align(&first.sequence, &second.sequence, Alphabet::Nucleotide)?,
+                &first.sequence,
+                &second.sequence,
+            )
+        }
+        (AnySequence::AminoAcidSequence(first), AnySequence::AminoAcidSequence(second)) => {
+            PairwiseAlignment::new::<_, AminoAcidSequence>(
+                py,
+                scoring.align(&first.sequence, &second.sequence, Alphabet::AminoAcid)?,
+                &first.sequence,
+                &second.sequence,
+            )
+        }
+        _ => Err(PyTypeError::new_err("sequences must be of the same type")),
+    }
+}
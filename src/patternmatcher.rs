@@ -0,0 +1,354 @@
+use crate::aminoacid::AminoAcid;
+use crate::dnabase::DNABase;
+use crate::member::Member;
+use crate::rnabase::RNABase;
+use crate::sequence::AnySequence;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+const MAX_VARIANTS: usize = 1 << 16;
+const CHUNK_SIZE: usize = 1 << 16;
+
+struct Node<T> {
+    next: HashMap<T, usize>,
+    fail: usize,
+    outputs: Vec<usize>,
+}
+
+struct Entry {
+    pattern: usize,
+    strand: i8,
+    length: usize,
+}
+
+struct Automaton<T> {
+    nodes: Vec<Node<T>>,
+    entries: Vec<Entry>,
+    ambiguous: bool,
+}
+
+// A gap has no concrete members, but still matches another gap.
+fn concrete<T: Member + Copy + 'static>(member: &T, ambiguous: bool) -> Vec<T> {
+    match (ambiguous, member.expand()) {
+        (true, members) if !members.is_empty() => members.to_vec(),
+        _ => vec![*member],
+    }
+}
+
+impl<T> Automaton<T>
+where
+    T: Member + Copy + Eq + Hash + Send + Sync + 'static,
+{
+    fn new(patterns: Vec<(usize, i8, Vec<T>)>, ambiguous: bool) -> PyResult<Self> {
+        let mut automaton = Self {
+            nodes: vec![Node {
+                next: HashMap::new(),
+                fail: 0,
+                outputs: vec![],
+            }],
+            entries: vec![],
+            ambiguous,
+        };
+
+        for (pattern, strand, members) in patterns {
+            if members.is_empty() {
+                return Err(PyValueError::new_err(format!("pattern {pattern} is empty")));
+            }
+
+            let mut variants: Vec<Vec<T>> = vec![vec![]];
+            for member in &members {
+                let options = concrete(member, ambiguous);
+
+                if variants.len() * options.len() > MAX_VARIANTS {
+                    return Err(PyValueError::new_err(format!(
+                        "pattern {pattern} has too many ambiguous expansions"
+                    )));
+                }
+
+                variants = variants
+                    .into_iter()
+                    .flat_map(|variant| {
+                        options.iter().map(move |option| {
+                            let mut variant = variant.clone();
+                            variant.push(*option);
+                            variant
+                        })
+                    })
+                    .collect();
+            }
+
+            automaton.entries.push(Entry {
+                pattern,
+                strand,
+                length: members.len(),
+            });
+
+            for variant in variants {
+                automaton.insert(&variant, automaton.entries.len() - 1);
+            }
+        }
+
+        automaton.link();
+        Ok(automaton)
+    }
+
+    fn insert(&mut self, members: &[T], entry: usize) {
+        let mut state = 0;
+
+        for member in members {
+            state = match self.nodes[state].next.get(member) {
+                Some(next) => *next,
+                None => {
+                    self.nodes.push(Node {
+                        next: HashMap::new(),
+                        fail: 0,
+                        outputs: vec![],
+                    });
+                    let next = self.nodes.len() - 1;
+                    self.nodes[state].next.insert(*member, next);
+                    next
+                }
+            };
+        }
+
+        if !self.nodes[state].outputs.contains(&entry) {
+            self.nodes[state].outputs.push(entry);
+        }
+    }
+
+    // Failure links are found breadth first, so that each node's failure
+    // target has already been linked and its outputs can be inherited.
+    fn link(&mut self) {
+        let mut queue = self.nodes[0]
+            .next
+            .values()
+            .copied()
+            .collect::<VecDeque<_>>();
+
+        while let Some(state) = queue.pop_front() {
+            let children = self.nodes[state]
+                .next
+                .iter()
+                .map(|(member, child)| (*member, *child))
+                .collect::<Vec<_>>();
+
+            for (member, child) in children {
+                let fail = match state {
+                    0 => 0,
+                    _ => self.transition(self.nodes[state].fail, &member),
+                };
+
+                self.nodes[child].fail = fail;
+                let outputs = self.nodes[fail].outputs.clone();
+                for output in outputs {
+                    if !self.nodes[child].outputs.contains(&output) {
+                        self.nodes[child].outputs.push(output);
+                    }
+                }
+
+                queue.push_back(child);
+            }
+        }
+    }
+
+    fn transition(&self, mut state: usize, member: &T) -> usize {
+        loop {
+            if let Some(next) = self.nodes[state].next.get(member) {
+                return *next;
+            }
+
+            if state == 0 {
+                return 0;
+            }
+
+            state = self.nodes[state].fail;
+        }
+    }
+
+    fn max_length(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|entry| entry.length)
+            .max()
+            .unwrap_or(0)
+    }
+
+    // Ambiguous members of the searched sequence may stand for several
+    // concrete members, so the automaton may be in more than one state.
+    fn scan(&self, members: &[T], offset: usize, limit: usize) -> Vec<(usize, usize, i8)> {
+        let mut hits = vec![];
+        let mut states = vec![0];
+
+        for (index, member) in members.iter().enumerate() {
+            let options = concrete(member, self.ambiguous);
+
+            states = states
+                .iter()
+                .flat_map(|state| options.iter().map(|option| self.transition(*state, option)))
+                .collect();
+            states.sort_unstable();
+            states.dedup();
+
+            for state in &states {
+                for output in &self.nodes[*state].outputs {
+                    let entry = &self.entries[*output];
+                    let position = offset + index + 1 - entry.length;
+
+                    if position < limit {
+                        hits.push((position, entry.pattern, entry.strand));
+                    }
+                }
+            }
+        }
+
+        hits
+    }
+
+    // The sequence is searched in parallel chunks, each extended so that
+    // matches starting within it are found in full.
+    fn find_all(&self, members: &[T]) -> Vec<(usize, usize, i8)> {
+        let overlap = self.max_length().saturating_sub(1);
+
+        let mut hits = (0..members.len())
+            .into_par_iter()
+            .step_by(CHUNK_SIZE)
+            .flat_map_iter(|start| {
+                let end = (start + CHUNK_SIZE + overlap).min(members.len());
+                self.scan(&members[start..end], start, start + CHUNK_SIZE)
+            })
+            .collect::<Vec<_>>();
+
+        hits.par_sort_unstable();
+        hits.dedup();
+        hits
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+enum Automata {
+    DNASequence(Automaton<DNABase>),
+    RNASequence(Automaton<RNABase>),
+    AminoAcidSequence(Automaton<AminoAcid>),
+}
+
+#[pyclass(frozen)]
+pub struct PatternMatcher {
+    automata: Automata,
+    patterns: usize,
+    #[pyo3(get)]
+    both_strands: bool,
+    #[pyo3(get)]
+    ambiguous: bool,
+}
+
+#[pymethods]
+impl PatternMatcher {
+    #[new]
+    #[pyo3(signature = (patterns, *, both_strands = false, ambiguous = false))]
+    fn __new__(
+        patterns: Vec<AnySequence<'_>>,
+        both_strands: bool,
+        ambiguous: bool,
+    ) -> PyResult<Self> {
+        let mismatch = || PyTypeError::new_err("patterns must all be of the same sequence type");
+
+        let automata = match patterns.first() {
+            None | Some(AnySequence::DNASequence(_)) => {
+                let mut members = vec![];
+                for (index, pattern) in patterns.iter().enumerate() {
+                    let AnySequence::DNASequence(pattern) = pattern else {
+                        return Err(mismatch());
+                    };
+
+                    members.push((index, 1, pattern.sequence.clone()));
+                    if both_strands {
+                        members.push((index, -1, pattern.reverse_complement().sequence));
+                    }
+                }
+
+                Automata::DNASequence(Automaton::new(members, ambiguous)?)
+            }
+            Some(AnySequence::RNASequence(_)) => {
+                let mut members = vec![];
+                for (index, pattern) in patterns.iter().enumerate() {
+                    let AnySequence::RNASequence(pattern) = pattern else {
+                        return Err(mismatch());
+                    };
+
+                    members.push((index, 1, pattern.sequence.clone()));
+                    if both_strands {
+                        members.push((index, -1, pattern.reverse_complement().sequence));
+                    }
+                }
+
+                Automata::RNASequence(Automaton::new(members, ambiguous)?)
+            }
+            Some(AnySequence::AminoAcidSequence(_)) => {
+                if both_strands {
+                    return Err(PyValueError::new_err(
+                        "both_strands requires nucleotide patterns",
+                    ));
+                }
+
+                let mut members = vec![];
+                for (index, pattern) in patterns.iter().enumerate() {
+                    let AnySequence::AminoAcidSequence(pattern) = pattern else {
+                        return Err(mismatch());
+                    };
+
+                    members.push((index, 1, pattern.sequence.clone()));
+                }
+
+                Automata::AminoAcidSequence(Automaton::new(members, ambiguous)?)
+            }
+        };
+
+        Ok(Self {
+            automata,
+            patterns: patterns.len(),
+            both_strands,
+            ambiguous,
+        })
+    }
+
+    fn find_all(&self, sequence: AnySequence<'_>) -> PyResult<Vec<(usize, usize, i8)>> {
+        let hits = match (&self.automata, &sequence) {
+            (Automata::DNASequence(automaton), AnySequence::DNASequence(sequence)) => {
+                automaton.find_all(&sequence.sequence)
+            }
+            (Automata::RNASequence(automaton), AnySequence::RNASequence(sequence)) => {
+                automaton.find_all(&sequence.sequence)
+            }
+            (Automata::AminoAcidSequence(automaton), AnySequence::AminoAcidSequence(sequence)) => {
+                automaton.find_all(&sequence.sequence)
+            }
+            _ => {
+                return Err(PyTypeError::new_err(
+                    "sequence must be of the same type as the patterns",
+                ))
+            }
+        };
+
+        Ok(hits
+            .into_iter()
+            .map(|(position, pattern, strand)| (pattern, position, strand))
+            .collect())
+    }
+
+    fn __repr__(&self) -> String {
+        let kind = match &self.automata {
+            Automata::DNASequence(_) => "DNASequence",
+            Automata::RNASequence(_) => "RNASequence",
+            Automata::AminoAcidSequence(_) => "AminoAcidSequence",
+        };
+
+        format!("<PatternMatcher: {} {kind} patterns>", self.patterns)
+    }
+
+    fn __len__(&self) -> usize {
+        self.patterns
+    }
+}
@@ -1,17 +1,20 @@
 use crate::dnabase::DNABase;
-use crate::member::Member;
+use crate::member::{Member, MemberOrCode};
 use crate::rnasequence::{RNASequence, RNASequenceInput};
 use pyo3::prelude::*;
+use pyo3::types::{PyFrozenSet, PyType};
 use std::fmt;
 
 #[pyclass(
     eq,
     eq_int,
     frozen,
+    hash,
+    module = "haem",
     rename_all = "SCREAMING_SNAKE_CASE",
     from_py_object
 )]
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RNABase {
     Adenine,
     Cytosine,
@@ -31,7 +34,67 @@ pub enum RNABase {
     Gap,
 }
 
-impl Member for RNABase {}
+// Every member in declaration order, which is checked when compiling so that
+// the index of a member always finds that member in MEMBERS.
+const MEMBERS: [RNABase; 16] = [
+    RNABase::Adenine,
+    RNABase::Cytosine,
+    RNABase::Guanine,
+    RNABase::Uracil,
+    RNABase::AdenineCytosine,
+    RNABase::AdenineGuanine,
+    RNABase::AdenineUracil,
+    RNABase::CytosineGuanine,
+    RNABase::CytosineUracil,
+    RNABase::GuanineUracil,
+    RNABase::AdenineCytosineGuanine,
+    RNABase::AdenineCytosineUracil,
+    RNABase::AdenineGuanineUracil,
+    RNABase::CytosineGuanineUracil,
+    RNABase::Any,
+    RNABase::Gap,
+];
+
+const _: () = {
+    let mut index = 0;
+    while index < MEMBERS.len() {
+        assert!(MEMBERS[index] as usize == index);
+        index += 1;
+    }
+};
+
+impl Member for RNABase {
+    const GAP: Self = Self::Gap;
+
+    fn members() -> &'static [Self] {
+        &MEMBERS
+    }
+
+    fn expand(&self) -> &'static [Self] {
+        match self {
+            Self::Adenine => &[Self::Adenine],
+            Self::Cytosine => &[Self::Cytosine],
+            Self::Guanine => &[Self::Guanine],
+            Self::Uracil => &[Self::Uracil],
+            Self::AdenineCytosine => &[Self::Adenine, Self::Cytosine],
+            Self::AdenineGuanine => &[Self::Adenine, Self::Guanine],
+            Self::AdenineUracil => &[Self::Adenine, Self::Uracil],
+            Self::CytosineGuanine => &[Self::Cytosine, Self::Guanine],
+            Self::CytosineUracil => &[Self::Cytosine, Self::Uracil],
+            Self::GuanineUracil => &[Self::Guanine, Self::Uracil],
+            Self::AdenineCytosineGuanine => &[Self::Adenine, Self::Cytosine, Self::Guanine],
+            Self::AdenineCytosineUracil => &[Self::Adenine, Self::Cytosine, Self::Uracil],
+            Self::AdenineGuanineUracil => &[Self::Adenine, Self::Guanine, Self::Uracil],
+            Self::CytosineGuanineUracil => &[Self::Cytosine, Self::Guanine, Self::Uracil],
+            Self::Any => &[Self::Adenine, Self::Cytosine, Self::Guanine, Self::Uracil],
+            Self::Gap => &[],
+        }
+    }
+
+    fn index(&self) -> u8 {
+        *self as u8
+    }
+}
 
 #[pymethods]
 impl RNABase {
@@ -71,6 +134,16 @@ impl RNABase {
         self.into()
     }
 
+    #[getter]
+    fn get_bases<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyFrozenSet>> {
+        PyFrozenSet::new(py, self.expand().iter().copied())
+    }
+
+    #[pyo3(name = "matches")]
+    fn py_matches(&self, other: MemberOrCode<RNABase>) -> PyResult<bool> {
+        Ok(self.matches(&other.into_member()?))
+    }
+
     fn __bool__(&self) -> bool {
         *self != Self::Gap
     }
@@ -90,6 +163,18 @@ impl RNABase {
     fn __str__(&self) -> String {
         self.to_string()
     }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> (Bound<'py, PyType>, (char,)) {
+        (slf.py().get_type::<Self>(), (slf.get().get_code(),))
+    }
+
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: Bound<'py, PyAny>) -> Bound<'py, Self> {
+        slf
+    }
 }
 
 impl From<&RNABase> for char {
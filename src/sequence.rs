@@ -1,12 +1,24 @@
-use crate::member::{MemberOrCode, MemberOrMembers};
+use crate::aminoacidsequence::AminoAcidSequence;
+use crate::dnasequence::DNASequence;
+use crate::member::{Member, MemberOrCode, MemberOrMembers};
+use crate::rnasequence::RNASequence;
 use crate::utils::IntOrSlice;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyIterator;
+use pyo3::types::{PyIterator, PySlice, PyType};
 use rayon::prelude::*;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Range;
+
+// Written first by encode, so that bytes from a later encoding are rejected
+// rather than decoded as the wrong members.
+const ENCODING_VERSION: u8 = 1;
 
 pub trait Sequence<T>
 where
-    T: PartialEq + Clone + Sync,
+    T: Member + PartialEq + Clone + Sync + 'static,
     for<'a> char: From<&'a T>,
 {
     fn members(&self) -> &Vec<T>;
@@ -20,6 +32,42 @@ where
         self.members() == other.members()
     }
 
+    // Sequences sort as their codes would, so that "AC" < "ACG" < "AG".
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.members()
+            .iter()
+            .map(char::from)
+            .cmp(other.members().iter().map(char::from))
+    }
+
+    // Hashed by code rather than by member so that the hash is the same from
+    // one run to the next.
+    fn hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for member in self.members() {
+            char::from(member).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    // A version, the number of members and then the index of each, packed two
+    // to a byte where every index fits in four bits.
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![ENCODING_VERSION];
+        bytes.extend((self.len() as u64).to_le_bytes());
+
+        match T::members().len() {
+            length if length <= 16 => bytes.extend(
+                self.members()
+                    .chunks(2)
+                    .map(|pair| pair[0].index() << 4 | pair.get(1).map_or(0, Member::index)),
+            ),
+            _ => bytes.extend(self.members().iter().map(Member::index)),
+        }
+
+        bytes
+    }
+
     fn repr(&self) -> String {
         match self.members().is_empty() {
             true => format!("<{}>", self.name()),
@@ -51,13 +99,13 @@ where
         }
     }
 
-    fn contains(&self, sequence: &[T]) -> PyResult<bool> {
+    fn contains(&self, sequence: &[T], ambiguous: bool) -> PyResult<bool> {
         Ok(match sequence.is_empty() {
             true => true,
             false => self
                 .members()
                 .par_windows(sequence.len())
-                .any(|w| w == sequence),
+                .any(|w| matches(w, sequence, ambiguous)),
         })
     }
 
@@ -82,61 +130,47 @@ where
     }
 
     fn getitem(&self, index_or_slice: IntOrSlice) -> PyResult<MemberOrMembers<T>> {
-        match index_or_slice {
-            IntOrSlice::Int(index) => {
-                let index = match index {
-                    index if index < 0 => self.len() - index.unsigned_abs(),
-                    _ => index as usize,
-                };
-
-                match index {
-                    index if index < self.len() => {
-                        Ok(MemberOrMembers::Member(self.members()[index].clone()))
-                    }
-                    _ => Err(pyo3::exceptions::PyIndexError::new_err(format!(
-                        "{} index out of range",
-                        self.name()
-                    ))),
-                }
-            }
-            IntOrSlice::Slice(slice) => {
-                let indices = slice.indices(self.len() as isize)?;
-
-                Ok(MemberOrMembers::Sequence(match indices.step {
-                    s if s < 0 => (indices.stop + 1..indices.start + 1)
-                        .rev()
-                        .step_by(indices.step.unsigned_abs())
-                        .map(|i| self.members()[i as usize].clone())
-                        .collect(),
-                    _ => (indices.start..indices.stop)
-                        .step_by(indices.step as usize)
-                        .map(|i| self.members()[i as usize].clone())
-                        .collect(),
-                }))
-            }
-        }
+        getitem(self.members(), self.name(), &index_or_slice)
     }
 
     fn len(&self) -> usize {
         self.members().len()
     }
 
-    fn count(&self, sequence: &[T], overlap: bool) -> PyResult<usize> {
+    fn reverse(&self) -> Vec<T>
+    where
+        T: Send,
+    {
+        self.members().par_iter().rev().cloned().collect()
+    }
+
+    fn bounds(&self, start: Option<isize>, end: Option<isize>) -> Range<usize> {
+        bounds(self.len(), start, end)
+    }
+
+    fn count(
+        &self,
+        sequence: &[T],
+        overlap: bool,
+        range: Range<usize>,
+        ambiguous: bool,
+    ) -> PyResult<usize> {
+        let members = &self.members()[range];
+
         Ok(match (sequence.len(), overlap) {
             // Special case, empty sequences always return 0.
             (0, _) => 0,
             // With a sequence lenth of 1 or when overlap is allowed, optimisation is possible.
-            (len @ 1, _) | (len, true) => self
-                .members()
+            (len @ 1, _) | (len, true) => members
                 .par_windows(len)
-                .filter(|w| *w == sequence)
+                .filter(|w| matches(w, sequence, ambiguous))
                 .count(),
             (len, _) => {
                 let mut count = 0;
 
-                let mut iter = self.members().windows(len);
+                let mut iter = members.windows(len);
                 while let Some(item) = iter.next() {
-                    if item == sequence {
+                    if matches(item, sequence, ambiguous) {
                         count += 1;
                         iter.nth(sequence.len() - 2);
                     }
@@ -147,17 +181,373 @@ where
         })
     }
 
-    fn find(&self, sequence: &[T]) -> PyResult<Option<usize>> {
-        Ok(if self.members().is_empty() || sequence.is_empty() {
+    fn find(
+        &self,
+        sequence: &[T],
+        range: Range<usize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        Ok(if range.is_empty() || sequence.is_empty() {
+            None
+        } else {
+            self.members()[range.clone()]
+                .par_windows(sequence.len())
+                .position_first(|w| matches(w, sequence, ambiguous))
+                .map(|index| index + range.start)
+        })
+    }
+
+    fn rfind(
+        &self,
+        sequence: &[T],
+        range: Range<usize>,
+        ambiguous: bool,
+    ) -> PyResult<Option<usize>> {
+        Ok(if range.is_empty() || sequence.is_empty() {
             None
         } else {
-            self.members()
+            self.members()[range.clone()]
                 .par_windows(sequence.len())
-                .position_first(|w| w == sequence)
+                .position_last(|w| matches(w, sequence, ambiguous))
+                .map(|index| index + range.start)
+        })
+    }
+
+    fn find_all(
+        &self,
+        sequence: &[T],
+        overlap: bool,
+        range: Range<usize>,
+        ambiguous: bool,
+    ) -> PyResult<Vec<usize>> {
+        Ok(match (sequence.len(), overlap) {
+            (0, _) => vec![],
+            (len @ 1, _) | (len, true) => self.members()[range.clone()]
+                .par_windows(len)
+                .enumerate()
+                .filter(|(_, w)| matches(w, sequence, ambiguous))
+                .map(|(index, _)| index + range.start)
+                .collect(),
+            (len, _) => {
+                let mut positions = vec![];
+                let mut position = range.start;
+
+                while let Some(index) =
+                    next_match(&self.members()[..range.end], sequence, position, ambiguous)
+                {
+                    positions.push(index);
+                    position = index + len;
+                }
+
+                positions
+            }
         })
     }
 }
 
+// Sequences whose members are read one at a time by index rather than from a
+// slice, such as packed sequences and views.
+pub trait Indexed<T>: Sync
+where
+    T: Member + PartialEq + Send + Sync + 'static,
+    for<'a> char: From<&'a T>,
+{
+    fn length(&self) -> usize;
+    fn get(&self, index: usize) -> T;
+
+    // The member at an index that may count back from the end, as Python's
+    // sequences allow.
+    fn member(&self, index: isize, name: &str) -> PyResult<T> {
+        let index = match index {
+            index if index < 0 => self.length().checked_sub(index.unsigned_abs()),
+            index => Some(index as usize),
+        };
+
+        match index {
+            Some(index) if index < self.length() => Ok(self.get(index)),
+            _ => Err(PyIndexError::new_err(format!("{name} index out of range"))),
+        }
+    }
+
+    fn collect(&self, range: Range<usize>) -> Vec<T> {
+        range.into_par_iter().map(|index| self.get(index)).collect()
+    }
+
+    fn codes(&self, range: Range<usize>) -> String {
+        range
+            .into_par_iter()
+            .map(|index| char::from(&self.get(index)))
+            .collect()
+    }
+
+    fn repr(&self, name: &str) -> String {
+        match self.length() {
+            0 => format!("<{name}>"),
+            length => format!("<{name}: {}>", self.codes(0..length)),
+        }
+    }
+
+    fn str(&self) -> String {
+        match self.length() {
+            length if length < 21 => self.codes(0..length),
+            length => format!(
+                "{}...{}",
+                self.codes(0..10),
+                self.codes(length - 10..length)
+            ),
+        }
+    }
+
+    fn eq(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.length() == other.length()
+            && (0..self.length())
+                .into_par_iter()
+                .all(|index| self.get(index) == other.get(index))
+    }
+
+    // Hashed by code, as Sequence is.
+    fn hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for index in 0..self.length() {
+            char::from(&self.get(index)).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    fn matches_at(&self, position: usize, sequence: &[T], ambiguous: bool) -> bool {
+        sequence.iter().enumerate().all(|(offset, other)| {
+            let member = self.get(position + offset);
+            match ambiguous {
+                true => member.matches(other),
+                false => member == *other,
+            }
+        })
+    }
+
+    // The positions within the range at which the sequence could start.
+    fn starts(&self, sequence: &[T], range: Range<usize>) -> Range<usize> {
+        let end = (range.end + 1).saturating_sub(sequence.len());
+
+        range.start..end.max(range.start)
+    }
+
+    fn count(&self, sequence: &[T], overlap: bool, range: Range<usize>, ambiguous: bool) -> usize {
+        let starts = self.starts(sequence, range);
+
+        match (sequence.len(), overlap) {
+            (0, _) => 0,
+            (1, _) | (_, true) => starts
+                .into_par_iter()
+                .filter(|position| self.matches_at(*position, sequence, ambiguous))
+                .count(),
+            (len, _) => {
+                let mut count = 0;
+                let mut position = starts.start;
+
+                while let Some(index) = (position..starts.end)
+                    .find(|position| self.matches_at(*position, sequence, ambiguous))
+                {
+                    count += 1;
+                    position = index + len;
+                }
+
+                count
+            }
+        }
+    }
+
+    fn find(&self, sequence: &[T], range: Range<usize>, ambiguous: bool) -> Option<usize> {
+        match sequence.is_empty() {
+            true => None,
+            false => self
+                .starts(sequence, range)
+                .into_par_iter()
+                .find_first(|position| self.matches_at(*position, sequence, ambiguous)),
+        }
+    }
+}
+
+// The members of a sequence selected by a slice, which may be strided or
+// reversed, held as positions in the sequence rather than as members.
+#[derive(Clone, Copy)]
+pub struct Span {
+    start: isize,
+    step: isize,
+    pub length: usize,
+}
+
+impl Span {
+    pub fn new(
+        py: Python<'_>,
+        length: usize,
+        start: Option<isize>,
+        stop: Option<isize>,
+        step: Option<isize>,
+    ) -> PyResult<Self> {
+        let slice = py
+            .get_type::<PySlice>()
+            .call1((start, stop, step))?
+            .cast_into::<PySlice>()?;
+
+        Self::from_slice(&slice, length)
+    }
+
+    fn from_slice(slice: &Bound<'_, PySlice>, length: usize) -> PyResult<Self> {
+        let indices = slice.indices(length as isize)?;
+
+        Ok(Self {
+            start: indices.start,
+            step: indices.step,
+            length: indices.slicelength,
+        })
+    }
+
+    pub fn index(&self, index: usize) -> usize {
+        (self.start + index as isize * self.step) as usize
+    }
+
+    // A slice of the span, which is itself a span of the same sequence.
+    pub fn slice(&self, slice: &Bound<'_, PySlice>) -> PyResult<Self> {
+        let inner = Self::from_slice(slice, self.length)?;
+
+        Ok(Self {
+            start: self.start + inner.start * self.step,
+            step: self.step * inner.step,
+            length: inner.length,
+        })
+    }
+
+    // Contiguous spans are borrowed from the sequence, while others must be
+    // gathered.
+    pub fn members<'a, T: Clone + Send + Sync>(&self, members: &'a [T]) -> Cow<'a, [T]> {
+        match self.step {
+            1 => Cow::Borrowed(&members[self.index(0)..self.index(self.length)]),
+            _ => Cow::Owned(
+                (0..self.length)
+                    .into_par_iter()
+                    .map(|index| members[self.index(index)].clone())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+// Resolves optional start and end indices as Python's str.find does, with
+// negative indices counting from the end of the sequence.
+pub fn bounds(length: usize, start: Option<isize>, end: Option<isize>) -> Range<usize> {
+    let resolve = |index: isize| match index {
+        index if index < 0 => (index + length as isize).max(0) as usize,
+        index => (index as usize).min(length),
+    };
+
+    let start = start.map_or(0, resolve);
+    let end = end.map_or(length, resolve);
+
+    start..end.max(start)
+}
+
+// Sequential search used where matches must be found in order, such as
+// non-overlapping matches or lazy iteration.
+pub fn next_match<T: Member + PartialEq + 'static>(
+    members: &[T],
+    sequence: &[T],
+    position: usize,
+    ambiguous: bool,
+) -> Option<usize> {
+    match sequence.is_empty() || position > members.len() {
+        true => None,
+        false => members[position..]
+            .windows(sequence.len())
+            .position(|w| matches(w, sequence, ambiguous))
+            .map(|index| index + position),
+    }
+}
+
+// In ambiguous mode, IUPAC codes match any member they share with the other
+// sequence, so R matches A or G and N matches anything but a gap.
+fn matches<T: Member + PartialEq + 'static>(window: &[T], sequence: &[T], ambiguous: bool) -> bool {
+    match ambiguous {
+        true => window
+            .iter()
+            .zip(sequence)
+            .all(|(member, other)| member.matches(other)),
+        false => window == sequence,
+    }
+}
+
+pub fn decode<T: Member + Clone + 'static>(bytes: &[u8], name: &str) -> PyResult<Vec<T>> {
+    let invalid = || PyValueError::new_err(format!("invalid {name} bytes"));
+
+    let (length, packed) = match bytes.split_first() {
+        Some((&ENCODING_VERSION, bytes)) => bytes.split_first_chunk::<8>().ok_or_else(invalid)?,
+        _ => return Err(invalid()),
+    };
+    let length = usize::try_from(u64::from_le_bytes(*length)).map_err(|_| invalid())?;
+
+    let indices = match T::members().len() {
+        members if members <= 16 && packed.len() == length.div_ceil(2) => packed
+            .iter()
+            .flat_map(|byte| [byte >> 4, byte & 0x0f])
+            .take(length)
+            .collect::<Vec<_>>(),
+        members if members > 16 && packed.len() == length => packed.to_vec(),
+        _ => return Err(invalid()),
+    };
+
+    indices
+        .into_iter()
+        .map(|index| {
+            T::members()
+                .get(index as usize)
+                .cloned()
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+pub fn getitem<T: Clone>(
+    members: &[T],
+    name: &str,
+    index_or_slice: &IntOrSlice,
+) -> PyResult<MemberOrMembers<T>> {
+    match index_or_slice {
+        IntOrSlice::Int(index) => {
+            let index = match *index {
+                index if index < 0 => members.len() - index.unsigned_abs(),
+                index => index as usize,
+            };
+
+            match index {
+                index if index < members.len() => {
+                    Ok(MemberOrMembers::Member(members[index].clone()))
+                }
+                _ => Err(pyo3::exceptions::PyIndexError::new_err(format!(
+                    "{} index out of range",
+                    name
+                ))),
+            }
+        }
+        IntOrSlice::Slice(slice) => {
+            let indices = slice.indices(members.len() as isize)?;
+
+            Ok(MemberOrMembers::Sequence(match indices.step {
+                s if s < 0 => (indices.stop + 1..indices.start + 1)
+                    .rev()
+                    .step_by(indices.step.unsigned_abs())
+                    .map(|i| members[i as usize].clone())
+                    .collect(),
+                _ => (indices.start..indices.stop)
+                    .step_by(indices.step as usize)
+                    .map(|i| members[i as usize].clone())
+                    .collect(),
+            }))
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! impl_sequence {
     ($struct_name:ident, $member_type:ty, $name:expr) => {
@@ -227,3 +617,62 @@ where
         })
     }
 }
+
+#[allow(clippy::enum_variant_names)]
+#[derive(FromPyObject)]
+pub enum AnySequence<'py> {
+    DNASequence(PyRef<'py, DNASequence>),
+    RNASequence(PyRef<'py, RNASequence>),
+    AminoAcidSequence(PyRef<'py, AminoAcidSequence>),
+}
+
+impl AnySequence<'_> {
+    pub fn codes(&self) -> String {
+        match self {
+            Self::DNASequence(sequence) => sequence.par_iter().map(char::from).collect(),
+            Self::RNASequence(sequence) => sequence.par_iter().map(char::from).collect(),
+            Self::AminoAcidSequence(sequence) => sequence.par_iter().map(char::from).collect(),
+        }
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy)]
+pub enum SequenceKind {
+    DNASequence,
+    RNASequence,
+    AminoAcidSequence,
+}
+
+impl SequenceKind {
+    pub fn parse<'py>(&self, py: Python<'py>, codes: String) -> PyResult<Bound<'py, PyAny>> {
+        Ok(match self {
+            Self::DNASequence => DNASequence::from(Vec::try_from(SequenceInput::Str(codes))?)
+                .into_pyobject(py)?
+                .into_any(),
+            Self::RNASequence => RNASequence::from(Vec::try_from(SequenceInput::Str(codes))?)
+                .into_pyobject(py)?
+                .into_any(),
+            Self::AminoAcidSequence => {
+                AminoAcidSequence::from(Vec::try_from(SequenceInput::Str(codes))?)
+                    .into_pyobject(py)?
+                    .into_any()
+            }
+        })
+    }
+}
+
+impl TryFrom<&Bound<'_, PyType>> for SequenceKind {
+    type Error = PyErr;
+
+    fn try_from(kind: &Bound<'_, PyType>) -> PyResult<Self> {
+        match kind {
+            kind if kind.is_subclass_of::<DNASequence>()? => Ok(Self::DNASequence),
+            kind if kind.is_subclass_of::<RNASequence>()? => Ok(Self::RNASequence),
+            kind if kind.is_subclass_of::<AminoAcidSequence>()? => Ok(Self::AminoAcidSequence),
+            _ => Err(pyo3::exceptions::PyTypeError::new_err(
+                "kind must be DNASequence, RNASequence or AminoAcidSequence",
+            )),
+        }
+    }
+}
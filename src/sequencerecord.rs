@@ -0,0 +1,204 @@
+use crate::feature::Feature;
+use crate::sequence::AnySequence;
+use crate::utils::IntOrSlice;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+#[pyclass]
+pub struct SequenceRecord {
+    #[pyo3(get, set)]
+    pub id: String,
+    #[pyo3(get, set)]
+    pub description: String,
+    #[pyo3(get)]
+    pub sequence: Py<PyAny>,
+    #[pyo3(get)]
+    pub annotations: Py<PyDict>,
+    #[pyo3(get)]
+    pub features: Py<PyList>,
+}
+
+#[pymethods]
+impl SequenceRecord {
+    #[new]
+    #[pyo3(signature = (
+        sequence,
+        id = String::new(),
+        description = String::new(),
+        annotations = None,
+        features = None,
+    ))]
+    fn __new__<'py>(
+        py: Python<'py>,
+        sequence: Bound<'py, PyAny>,
+        id: String,
+        description: String,
+        annotations: Option<Bound<'py, PyDict>>,
+        features: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Self> {
+        sequence.extract::<AnySequence>()?;
+
+        let features = match features {
+            Some(features) => PyList::new(
+                py,
+                features
+                    .try_iter()?
+                    .map(|feature| Ok(feature?.cast_into::<Feature>()?))
+                    .collect::<PyResult<Vec<_>>>()?,
+            )?,
+            None => PyList::empty(py),
+        };
+
+        Ok(Self {
+            id,
+            description,
+            sequence: sequence.unbind(),
+            annotations: annotations.unwrap_or_else(|| PyDict::new(py)).unbind(),
+            features: features.unbind(),
+        })
+    }
+
+    #[getter]
+    fn get_complement(&self, py: Python<'_>) -> PyResult<Self> {
+        self.derive(
+            py,
+            self.sequence.bind(py).getattr("complement")?,
+            self.copy_features(py),
+        )
+    }
+
+    fn reverse_complement(&self, py: Python<'_>) -> PyResult<Self> {
+        let length = self.__len__(py)?;
+        let features = PyList::empty(py);
+
+        // Features are mirrored onto the opposite strand.
+        for feature in self.features.bind(py).iter().rev() {
+            let feature = feature.cast_into::<Feature>()?;
+            let feature = feature.get();
+
+            if feature.end > length {
+                return Err(PyValueError::new_err("feature extends beyond the sequence"));
+            }
+
+            features.append(feature.reverse_complemented(py, length))?;
+        }
+
+        self.derive(
+            py,
+            self.sequence.bind(py).call_method0("reverse_complement")?,
+            features,
+        )
+    }
+
+    fn transcribe(&self, py: Python<'_>) -> PyResult<Self> {
+        self.derive(
+            py,
+            self.sequence.bind(py).call_method0("transcribe")?,
+            self.copy_features(py),
+        )
+    }
+
+    fn retro_transcribe(&self, py: Python<'_>) -> PyResult<Self> {
+        self.derive(
+            py,
+            self.sequence.bind(py).call_method0("retro_transcribe")?,
+            self.copy_features(py),
+        )
+    }
+
+    #[pyo3(signature = (*args, **kwargs))]
+    fn translate<'py>(
+        &self,
+        py: Python<'py>,
+        args: &Bound<'py, PyTuple>,
+        kwargs: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Self> {
+        // Feature coordinates refer to bases, so they are not carried over to
+        // the amino acid sequence.
+        self.derive(
+            py,
+            self.sequence
+                .bind(py)
+                .call_method("translate", args, kwargs)?,
+            PyList::empty(py),
+        )
+    }
+
+    fn __invert__(&self, py: Python<'_>) -> PyResult<Self> {
+        self.get_complement(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<SequenceRecord: {}>", self.id)
+    }
+
+    fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(self.sequence.bind(py).str()?.to_string())
+    }
+
+    fn __len__(&self, py: Python<'_>) -> PyResult<usize> {
+        self.sequence.bind(py).len()
+    }
+
+    fn __getitem__<'py>(
+        &self,
+        py: Python<'py>,
+        index_or_slice: IntOrSlice<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let sequence = self.sequence.bind(py);
+
+        Ok(match index_or_slice {
+            IntOrSlice::Int(index) => sequence.get_item(index)?,
+            IntOrSlice::Slice(slice) => {
+                let indices = slice.indices(sequence.len()? as isize)?;
+                let features = PyList::empty(py);
+
+                // Features are kept only when they lie entirely within a
+                // contiguous slice, shifted to the start of the slice.
+                if indices.step == 1 {
+                    let (start, stop) = (indices.start as usize, indices.stop as usize);
+
+                    for feature in self.features.bind(py).iter() {
+                        let feature = feature.cast_into::<Feature>()?;
+                        let feature = feature.get();
+
+                        if feature.start >= start && feature.end <= stop {
+                            features.append(feature.with_location(
+                                py,
+                                feature.start - start,
+                                feature.end - start,
+                            ))?;
+                        }
+                    }
+                }
+
+                self.derive(py, sequence.get_item(slice)?, features)?
+                    .into_pyobject(py)?
+                    .into_any()
+            }
+        })
+    }
+}
+
+impl SequenceRecord {
+    fn copy_features<'py>(&self, py: Python<'py>) -> Bound<'py, PyList> {
+        let features = self.features.bind(py);
+        features.get_slice(0, features.len())
+    }
+
+    fn derive<'py>(
+        &self,
+        py: Python<'py>,
+        sequence: Bound<'py, PyAny>,
+        features: Bound<'py, PyList>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            id: self.id.clone(),
+            description: self.description.clone(),
+            sequence: sequence.unbind(),
+            annotations: self.annotations.bind(py).copy()?.unbind(),
+            features: features.unbind(),
+        })
+    }
+}
@@ -0,0 +1,278 @@
+use crate::aminoacid::AminoAcid;
+use crate::dnabase::DNABase;
+use crate::member::Member;
+use crate::rnabase::RNABase;
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+const MATRICES: [(&str, &str); 9] = [
+    ("BLOSUM45", include_str!("matrices/BLOSUM45")),
+    ("BLOSUM50", include_str!("matrices/BLOSUM50")),
+    ("BLOSUM62", include_str!("matrices/BLOSUM62")),
+    ("BLOSUM80", include_str!("matrices/BLOSUM80")),
+    ("BLOSUM90", include_str!("matrices/BLOSUM90")),
+    ("PAM30", include_str!("matrices/PAM30")),
+    ("PAM70", include_str!("matrices/PAM70")),
+    ("PAM250", include_str!("matrices/PAM250")),
+    ("NUC.4.4", include_str!("matrices/NUC.4.4")),
+];
+
+const NUCLEOTIDES: [DNABase; 15] = [
+    DNABase::Adenine,
+    DNABase::Cytosine,
+    DNABase::Guanine,
+    DNABase::Thymine,
+    DNABase::AdenineCytosine,
+    DNABase::AdenineGuanine,
+    DNABase::AdenineThymine,
+    DNABase::CytosineGuanine,
+    DNABase::CytosineThymine,
+    DNABase::GuanineThymine,
+    DNABase::AdenineCytosineGuanine,
+    DNABase::AdenineCytosineThymine,
+    DNABase::AdenineGuanineThymine,
+    DNABase::CytosineGuanineThymine,
+    DNABase::Any,
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Alphabet {
+    Nucleotide,
+    AminoAcid,
+}
+
+#[derive(FromPyObject)]
+pub enum MatrixCode {
+    DNABase(DNABase),
+    RNABase(RNABase),
+    AminoAcid(AminoAcid),
+    Code(char),
+}
+
+#[pyclass(frozen)]
+pub struct SubstitutionMatrix {
+    #[pyo3(get)]
+    name: String,
+    codes: Vec<char>,
+    indices: HashMap<char, usize>,
+    scores: Vec<i32>,
+    alphabet: Alphabet,
+}
+
+#[pymethods]
+impl SubstitutionMatrix {
+    #[new]
+    fn __new__(name: &str) -> PyResult<Self> {
+        match MATRICES
+            .iter()
+            .find(|(other, _)| other.eq_ignore_ascii_case(name))
+        {
+            Some((name, text)) => Self::parse(name.to_string(), text),
+            None => Err(PyValueError::new_err(format!(
+                "unknown substitution matrix \"{name}\""
+            ))),
+        }
+    }
+
+    #[classmethod]
+    fn load(_cls: &Bound<'_, PyType>, path: PathBuf) -> PyResult<Self> {
+        let text = fs::read_to_string(&path)?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Self::parse(name, &text)
+    }
+
+    // Scores between ambiguous codes are the mean score of the pairs of bases
+    // they stand for, rounded to the nearest integer.
+    #[classmethod]
+    #[pyo3(signature = (match_score = 1, mismatch_score = -1))]
+    fn nucleotide(_cls: &Bound<'_, PyType>, match_score: i32, mismatch_score: i32) -> Self {
+        let scores = NUCLEOTIDES
+            .iter()
+            .flat_map(|a| {
+                NUCLEOTIDES.iter().map(move |b| {
+                    let pairs = a.expand().len() * b.expand().len();
+                    let total = a
+                        .expand()
+                        .iter()
+                        .flat_map(|a| {
+                            b.expand().iter().map(move |b| match a == b {
+                                true => match_score,
+                                false => mismatch_score,
+                            })
+                        })
+                        .sum::<i32>();
+
+                    (total as f64 / pairs as f64).round() as i32
+                })
+            })
+            .collect();
+
+        Self::new(
+            "nucleotide".to_string(),
+            NUCLEOTIDES.iter().map(char::from).collect(),
+            scores,
+        )
+    }
+
+    #[getter]
+    fn get_alphabet(&self) -> String {
+        self.codes.iter().collect()
+    }
+
+    fn __getitem__(&self, key: (MatrixCode, MatrixCode)) -> PyResult<i32> {
+        let index = |code: MatrixCode| {
+            let (alphabet, code) = match code {
+                MatrixCode::DNABase(base) => (Some(Alphabet::Nucleotide), char::from(&base)),
+                MatrixCode::RNABase(base) => (Some(Alphabet::Nucleotide), char::from(&base)),
+                MatrixCode::AminoAcid(amino_acid) => {
+                    (Some(Alphabet::AminoAcid), char::from(&amino_acid))
+                }
+                MatrixCode::Code(code) => (None, code),
+            };
+
+            match alphabet {
+                Some(alphabet) if alphabet != self.alphabet => None,
+                _ => self.index(code),
+            }
+            .ok_or_else(|| PyKeyError::new_err(code.to_string()))
+        };
+
+        Ok(self.score(index(key.0)?, index(key.1)?))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<SubstitutionMatrix: {}>", self.name)
+    }
+}
+
+impl SubstitutionMatrix {
+    fn new(name: String, codes: Vec<char>, scores: Vec<i32>) -> Self {
+        // Matrices loaded from files are assumed to score amino acids unless
+        // every code is a nucleotide.
+        let alphabet = match codes
+            .iter()
+            .all(|code| DNABase::try_from(*code).is_ok() || RNABase::try_from(*code).is_ok())
+        {
+            true => Alphabet::Nucleotide,
+            false => Alphabet::AminoAcid,
+        };
+
+        Self {
+            name,
+            indices: codes
+                .iter()
+                .enumerate()
+                .map(|(index, code)| (*code, index))
+                .collect(),
+            codes,
+            scores,
+            alphabet,
+        }
+    }
+
+    // Matrices in the NCBI format: a header row of codes followed by a row of
+    // scores for each code, ignoring lines starting with #.
+    fn parse(name: String, text: &str) -> PyResult<Self> {
+        let invalid = || PyValueError::new_err("invalid substitution matrix");
+        let code = |field: &str| {
+            let mut chars = field.chars();
+            match (chars.next(), chars.next()) {
+                (Some(code), None) => Ok(code),
+                _ => Err(invalid()),
+            }
+        };
+
+        let mut lines = text
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'));
+
+        let codes = lines
+            .next()
+            .ok_or_else(invalid)?
+            .split_whitespace()
+            .map(code)
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let mut rows = vec![None; codes.len()];
+        for line in lines {
+            let mut fields = line.split_whitespace();
+            let row = fields.next().map(code).ok_or_else(invalid)??;
+            let scores = fields
+                .map(|score| score.parse::<i32>().map_err(|_| invalid()))
+                .collect::<PyResult<Vec<_>>>()?;
+
+            match codes.iter().position(|code| *code == row) {
+                Some(index) if rows[index].is_none() && scores.len() == codes.len() => {
+                    rows[index] = Some(scores)
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        let matrix = Self::new(
+            name,
+            codes,
+            rows.into_iter()
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(invalid)?
+                .concat(),
+        );
+
+        match matrix.indices.len() == matrix.codes.len() && !matrix.codes.is_empty() {
+            true => Ok(matrix),
+            false => Err(invalid()),
+        }
+    }
+
+    // Nucleotide matrices score uracil as thymine if it isn't listed.
+    fn index(&self, code: char) -> Option<usize> {
+        match (self.indices.get(&code), self.alphabet, code) {
+            (Some(index), _, _) => Some(*index),
+            (None, Alphabet::Nucleotide, 'U') => self.indices.get(&'T').copied(),
+            _ => None,
+        }
+    }
+
+    pub fn score(&self, first: usize, second: usize) -> i32 {
+        self.scores[first * self.codes.len() + second]
+    }
+
+    // The row of the matrix for each member, which are checked up front so
+    // that alignment never has to handle a missing score.
+    pub fn indices<T>(&self, members: &[T], alphabet: Alphabet) -> PyResult<Vec<usize>>
+    where
+        for<'a> char: From<&'a T>,
+    {
+        if alphabet != self.alphabet {
+            return Err(PyValueError::new_err(format!(
+                "substitution matrix {} does not score {}",
+                self.name,
+                match alphabet {
+                    Alphabet::Nucleotide => "nucleotides",
+                    Alphabet::AminoAcid => "amino acids",
+                }
+            )));
+        }
+
+        members
+            .iter()
+            .map(|member| {
+                let code = char::from(member);
+                self.index(code).ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "substitution matrix {} has no score for \"{code}\"",
+                        self.name
+                    ))
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,154 @@
+use crate::aminoacid::AminoAcid;
+use crate::geneticcode::{CodonBase, GeneticCode, Translation};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+#[derive(Clone, Copy)]
+pub struct TranslationOptions {
+    pub table: GeneticCode,
+    pub frame: Option<usize>,
+    pub to_stop: bool,
+    pub cds: bool,
+    pub alternative_starts: bool,
+}
+
+fn codon<B>(bases: &[B]) -> (&B, &B, &B) {
+    (&bases[0], &bases[1], &bases[2])
+}
+
+impl TranslationOptions {
+    pub fn translate<B: CodonBase>(&self, bases: &[B]) -> PyResult<Vec<AminoAcid>> {
+        if self.cds {
+            return match self.frame {
+                Some(_) => Err(PyValueError::new_err(
+                    "frame cannot be used with cds translation",
+                )),
+                None => self.translate_cds(bases),
+            };
+        }
+
+        let start = match self.frame {
+            Some(frame @ 0..=2) => frame.min(bases.len()),
+            Some(_) => return Err(PyValueError::new_err("frame must be 0, 1 or 2")),
+            None => bases
+                .par_windows(3)
+                .position_first(|bases| self.is_start(codon(bases)))
+                .ok_or_else(|| PyValueError::new_err("no start codon found"))?,
+        };
+
+        let codons = bases[start..].chunks_exact(3);
+        let end = match self.to_stop {
+            true => match codons
+                .clone()
+                .position(|bases| self.table.lookup(codon(bases)) == Translation::Stop)
+            {
+                Some(stop) => stop,
+                // A stop codon must follow a start codon found by searching.
+                None if self.frame.is_none() => {
+                    return Err(PyValueError::new_err("no stop codon found"))
+                }
+                None => codons.len(),
+            },
+            false => codons.len(),
+        };
+
+        let mut amino_acids = bases[start..start + end * 3]
+            .par_chunks_exact(3)
+            .map(|bases| match self.table.lookup(codon(bases)) {
+                Translation::Stop => Ok(AminoAcid::Stop),
+                translation => translation.try_into(),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        if self.frame.is_none() {
+            translate_start(&mut amino_acids);
+        }
+
+        Ok(amino_acids)
+    }
+
+    fn translate_cds<B: CodonBase>(&self, bases: &[B]) -> PyResult<Vec<AminoAcid>> {
+        if !bases.len().is_multiple_of(3) {
+            return Err(PyValueError::new_err(
+                "CDS length is not a multiple of three",
+            ));
+        }
+
+        let codons = bases.chunks_exact(3).collect::<Vec<_>>();
+
+        match codons.first() {
+            Some(bases) if self.table.is_start(codon(bases)) => {}
+            _ => {
+                return Err(PyValueError::new_err(
+                    "CDS does not begin with a start codon",
+                ))
+            }
+        }
+
+        match codons.last() {
+            Some(bases)
+                if codons.len() > 1 && self.table.lookup(codon(bases)) == Translation::Stop => {}
+            _ => return Err(PyValueError::new_err("CDS does not end with a stop codon")),
+        }
+
+        let mut amino_acids = codons[1..codons.len() - 1]
+            .par_iter()
+            .map(|bases| match self.table.lookup(codon(bases)) {
+                Translation::Stop => {
+                    Err(PyValueError::new_err("CDS contains an internal stop codon"))
+                }
+                translation => translation.try_into(),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        amino_acids.insert(0, AminoAcid::Methionine);
+
+        Ok(amino_acids)
+    }
+
+    fn is_start<B: CodonBase>(&self, codon: (&B, &B, &B)) -> bool {
+        match self.alternative_starts {
+            true => self.table.is_start(codon),
+            false => self.table.lookup(codon) == Translation::AminoAcid(AminoAcid::Methionine),
+        }
+    }
+}
+
+// Real contigs contain ambiguous bases, so where whole contigs are translated
+// ambiguous codons are reported as any amino acid rather than raising.
+pub fn lenient_amino_acid(translation: Translation) -> AminoAcid {
+    match translation {
+        Translation::AminoAcid(amino_acid) => amino_acid,
+        Translation::Stop => AminoAcid::Stop,
+        Translation::Gap => AminoAcid::Gap,
+        Translation::Ambiguous => AminoAcid::Any,
+    }
+}
+
+// Alternative start codons are translated as methionine.
+pub fn translate_start(amino_acids: &mut [AminoAcid]) {
+    if let Some(first) = amino_acids.first_mut() {
+        *first = AminoAcid::Methionine;
+    }
+}
+
+// Frames 0, 1 and 2 of the forward strand, followed by those of the reverse
+// strand, each translated through stop codons.
+pub fn translate_frames<B: CodonBase>(
+    table: GeneticCode,
+    forward: &[B],
+    reverse: &[B],
+) -> Vec<Vec<AminoAcid>> {
+    [forward, reverse]
+        .into_par_iter()
+        .flat_map(|bases| {
+            (0..3).into_par_iter().map(move |frame| {
+                bases[frame.min(bases.len())..]
+                    .chunks_exact(3)
+                    .map(|bases| lenient_amino_acid(table.lookup(codon(bases))))
+                    .collect()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,211 @@
+import typing
+
+import pytest
+
+import haem
+
+
+def test_align() -> None:
+    alignment = haem.align(haem.DNASequence("GATTACA"), haem.DNASequence("GCATGCA"))
+
+    assert alignment.score == 1
+    assert alignment.first == haem.DNASequence("GATTACA")
+    assert alignment.second == haem.DNASequence("GCATGCA")
+    assert alignment.cigar == "7M"
+
+
+@pytest.mark.parametrize(
+    "first,second,score,aligned_first,aligned_second,cigar",
+    [
+        ("ACGTACGT", "ACGACGT", 5, "ACGTACGT", "ACG-ACGT", "3M1D4M"),
+        ("ACGACGT", "ACGTACGT", 5, "ACG-ACGT", "ACGTACGT", "3M1I4M"),
+        ("ACGTTTACGT", "ACGACGT", 3, "ACGTTTACGT", "ACG---ACGT", "3M3D4M"),
+        ("", "ACG", -4, "---", "ACG", "3I"),
+        ("AC", "", -3, "AC", "--", "2D"),
+        ("", "", 0, "", "", ""),
+    ],
+)
+def test_align_global(
+    first: str,
+    second: str,
+    score: int,
+    aligned_first: str,
+    aligned_second: str,
+    cigar: str,
+) -> None:
+    alignment = haem.align(haem.DNASequence(first), haem.DNASequence(second))
+
+    assert alignment.score == score
+    assert str(alignment.first) == aligned_first
+    assert str(alignment.second) == aligned_second
+    assert alignment.cigar == cigar
+    assert (alignment.first_start, alignment.first_end) == (0, len(first))
+    assert (alignment.second_start, alignment.second_end) == (0, len(second))
+
+
+@pytest.mark.parametrize("gap_open,gap_extend,score", [(-2, -1, 10), (-5, 0, 9)])
+def test_align_affine_gaps(gap_open: int, gap_extend: int, score: int) -> None:
+    alignment = haem.align(
+        haem.DNASequence("ACGTTTACGT"),
+        haem.DNASequence("ACGACGT"),
+        match_score=2,
+        gap_open=gap_open,
+        gap_extend=gap_extend,
+    )
+
+    assert alignment.score == score
+    assert str(alignment.second) == "ACG---ACGT"
+
+
+def test_align_local() -> None:
+    alignment = haem.align(
+        haem.DNASequence("TTTTACGTACGTTTT"),
+        haem.DNASequence("GGACGTACGGG"),
+        mode="local",
+    )
+
+    assert alignment.score == 7
+    assert str(alignment.first) == "ACGTACG"
+    assert str(alignment.second) == "ACGTACG"
+    assert (alignment.first_start, alignment.first_end) == (4, 11)
+    assert (alignment.second_start, alignment.second_end) == (2, 9)
+    assert alignment.cigar == "7M"
+
+
+def test_align_local_no_match() -> None:
+    alignment = haem.align(
+        haem.DNASequence("AAAA"), haem.DNASequence("CCCC"), mode="local"
+    )
+
+    assert alignment.score == 0
+    assert len(alignment) == 0
+
+
+@pytest.mark.parametrize(
+    "first,second,score,first_range,second_range",
+    [
+        ("AAAACGT", "CGTGGGG", 3, (4, 7), (0, 3)),
+        ("CGTGGGG", "AAAACGT", 3, (0, 3), (4, 7)),
+        ("AACGTAA", "CGT", 3, (2, 5), (0, 3)),
+        ("AAAA", "CCCC", 0, (0, 0), (4, 4)),
+    ],
+)
+def test_align_semi_global(
+    first: str,
+    second: str,
+    score: int,
+    first_range: typing.Tuple[int, int],
+    second_range: typing.Tuple[int, int],
+) -> None:
+    alignment = haem.align(
+        haem.DNASequence(first), haem.DNASequence(second), mode="semi-global"
+    )
+
+    assert alignment.score == score
+    assert (alignment.first_start, alignment.first_end) == first_range
+    assert (alignment.second_start, alignment.second_end) == second_range
+
+
+@pytest.mark.parametrize(
+    "first,second,aligned",
+    [
+        (haem.RNASequence("ACGU"), haem.RNASequence("AGU"), "A-GU"),
+        (haem.AminoAcidSequence("MKVL"), haem.AminoAcidSequence("MVL"), "M-VL"),
+    ],
+)
+def test_align_sequence_types(
+    first: typing.Union[haem.RNASequence, haem.AminoAcidSequence],
+    second: typing.Union[haem.RNASequence, haem.AminoAcidSequence],
+    aligned: str,
+) -> None:
+    alignment = haem.align(first, second)
+
+    assert type(alignment.second) is type(second)
+    assert str(alignment.second) == aligned
+
+
+def test_align_different_types() -> None:
+    with pytest.raises(TypeError) as excinfo:
+        haem.align(haem.DNASequence("A"), haem.RNASequence("A"))
+
+    assert str(excinfo.value) == "sequences must be of the same type"
+
+
+def test_align_unknown_mode() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.align(haem.DNASequence("A"), haem.DNASequence("A"), mode="overlap")
+
+    assert str(excinfo.value) == 'unknown alignment mode "overlap"'
+
+
+def test__len__() -> None:
+    alignment = haem.align(haem.DNASequence("ACGTACGT"), haem.DNASequence("ACGACGT"))
+
+    assert len(alignment) == 8
+
+
+def test__str__() -> None:
+    alignment = haem.align(haem.DNASequence("ACGTACGT"), haem.DNASequence("ACGAAGT"))
+
+    assert str(alignment) == "ACGTACGT\n||| |.||\nACG-AAGT"
+
+
+def test__repr__() -> None:
+    alignment = haem.align(haem.DNASequence("ACGT"), haem.DNASequence("ACGT"))
+
+    assert repr(alignment) == "<PairwiseAlignment: score=4>"
+
+
+@pytest.mark.parametrize(
+    "mode,score,first,second",
+    [("global", 1, "HEAGAWGHE-E", "--P-AW-HEAE"), ("local", 28, "AWGHE", "AW-HE")],
+)
+def test_align_matrix(mode: str, score: int, first: str, second: str) -> None:
+    alignment = haem.align(
+        haem.AminoAcidSequence("HEAGAWGHEE"),
+        haem.AminoAcidSequence("PAWHEAE"),
+        mode=mode,
+        gap_open=-8,
+        gap_extend=-8,
+        matrix=haem.SubstitutionMatrix("BLOSUM50"),
+    )
+
+    assert alignment.score == score
+    assert str(alignment.first) == first
+    assert str(alignment.second) == second
+
+
+def test_align_nucleotide_matrix() -> None:
+    alignment = haem.align(
+        haem.RNASequence("ACGU"),
+        haem.RNASequence("ACNU"),
+        matrix=haem.SubstitutionMatrix("NUC.4.4"),
+    )
+
+    assert alignment.score == 13
+
+
+@pytest.mark.parametrize(
+    "first,second,message",
+    [
+        (
+            haem.DNASequence("ACGT"),
+            haem.DNASequence("ACGT"),
+            "substitution matrix BLOSUM62 does not score nucleotides",
+        ),
+        (
+            haem.AminoAcidSequence("MKU"),
+            haem.AminoAcidSequence("MK"),
+            'substitution matrix BLOSUM62 has no score for "U"',
+        ),
+    ],
+)
+def test_align_matrix_invalid(
+    first: typing.Union[haem.DNASequence, haem.AminoAcidSequence],
+    second: typing.Union[haem.DNASequence, haem.AminoAcidSequence],
+    message: str,
+) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.align(first, second, matrix=haem.SubstitutionMatrix("BLOSUM62"))
+
+    assert str(excinfo.value) == message
@@ -0,0 +1,85 @@
+import typing
+
+import pytest
+
+import haem
+
+
+@pytest.mark.parametrize(
+    "sequences,expected",
+    [
+        (
+            [haem.DNASequence("ACGT"), haem.DNASequence("AGT")],
+            [haem.DNASequence("ACGT"), haem.DNASequence("A-GT")],
+        ),
+        (
+            [haem.RNASequence("ACGU"), haem.RNASequence("ACGU")],
+            [haem.RNASequence("ACGU"), haem.RNASequence("ACGU")],
+        ),
+        (
+            [
+                haem.AminoAcidSequence("MKVLA"),
+                haem.AminoAcidSequence("MKLA"),
+                haem.AminoAcidSequence("MKVLA"),
+            ],
+            [
+                haem.AminoAcidSequence("MKVLA"),
+                haem.AminoAcidSequence("MK-LA"),
+                haem.AminoAcidSequence("MKVLA"),
+            ],
+        ),
+        ([haem.DNASequence("AC-GT")], [haem.DNASequence("ACGT")]),
+        ([], []),
+    ],
+)
+def test_align_multiple(
+    sequences: typing.List[
+        typing.Union[haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence]
+    ],
+    expected: typing.List[
+        typing.Union[haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence]
+    ],
+) -> None:
+    assert haem.align_multiple(sequences).sequences == expected
+
+
+def test_align_multiple_preserves_sequences() -> None:
+    sequences = [
+        haem.AminoAcidSequence("HEAGAWGHEE"),
+        haem.AminoAcidSequence("PAWHEAE"),
+        haem.AminoAcidSequence("HEAGAWHEE"),
+        haem.AminoAcidSequence("PAWHEE"),
+    ]
+
+    alignment = haem.align_multiple(
+        sequences,
+        ids=["a", "b", "c", "d"],
+        matrix=haem.SubstitutionMatrix("BLOSUM62"),
+        gap_open=-10,
+        gap_extend=-1,
+    )
+
+    assert alignment.ids == ["a", "b", "c", "d"]
+    assert len({len(sequence) for sequence in alignment.sequences}) == 1
+    assert [
+        haem.AminoAcidSequence([member for member in sequence if member])
+        for sequence in alignment.sequences
+    ] == sequences
+
+
+def test_align_multiple_mixed_types() -> None:
+    with pytest.raises(TypeError) as excinfo:
+        haem.align_multiple([haem.DNASequence("A"), haem.RNASequence("A")])
+
+    assert str(excinfo.value) == "sequences must all be of the same type"
+
+
+def test_align_multiple_matrix_alphabet() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.align_multiple(
+            [haem.DNASequence("A")], matrix=haem.SubstitutionMatrix("BLOSUM62")
+        )
+
+    assert (
+        str(excinfo.value) == "substitution matrix BLOSUM62 does not score nucleotides"
+    )
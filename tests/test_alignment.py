@@ -0,0 +1,277 @@
+import pathlib
+import typing
+
+import pytest
+
+import haem
+
+
+ALIGNMENT = haem.Alignment(
+    [
+        haem.DNASequence("AC-GT"),
+        haem.DNASequence("ACTGT"),
+        haem.DNASequence("GC-GA"),
+    ],
+    ids=["a", "b", "c"],
+)
+
+
+def test_alignment() -> None:
+    assert ALIGNMENT.ids == ["a", "b", "c"]
+    assert ALIGNMENT.width == 5
+    assert len(ALIGNMENT) == 3
+    assert ALIGNMENT.sequences == [
+        haem.DNASequence("AC-GT"),
+        haem.DNASequence("ACTGT"),
+        haem.DNASequence("GC-GA"),
+    ]
+
+
+def test_alignment_default_ids() -> None:
+    alignment = haem.Alignment([haem.RNASequence("AU"), haem.RNASequence("A-")])
+
+    assert alignment.ids == ["seq1", "seq2"]
+
+
+def test_alignment_empty() -> None:
+    alignment = haem.Alignment([])
+
+    assert len(alignment) == 0
+    assert alignment.width == 0
+
+
+def test_alignment_mixed_types() -> None:
+    with pytest.raises(TypeError) as excinfo:
+        haem.Alignment([haem.DNASequence("A"), haem.RNASequence("A")])
+
+    assert str(excinfo.value) == "sequences must all be of the same type"
+
+
+def test_alignment_different_lengths() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Alignment([haem.DNASequence("AC"), haem.DNASequence("A")])
+
+    assert str(excinfo.value) == "sequences must all be the same length"
+
+
+def test_alignment_ids_length() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Alignment([haem.DNASequence("A")], ids=["a", "b"])
+
+    assert str(excinfo.value) == "ids must be the same length as sequences"
+
+
+def test_alignment_repr() -> None:
+    assert repr(ALIGNMENT) == "<Alignment: 3 sequences, 5 columns>"
+
+
+@pytest.mark.parametrize(
+    "key,expected",
+    [
+        (0, haem.DNASequence("AC-GT")),
+        (-1, haem.DNASequence("GC-GA")),
+        ((1, 2), haem.DNABase.THYMINE),
+        ((1, slice(1, 4)), haem.DNASequence("CTG")),
+        ((slice(None), 0), haem.DNASequence("AAG")),
+        ((slice(None, None, -1), -1), haem.DNASequence("ATT")),
+        ((0, slice(None, None, -2)), haem.DNASequence("T-A")),
+        ((2, slice(10, 0, -1)), haem.DNASequence("AG-C")),
+    ],
+)
+def test_alignment_getitem(
+    key: typing.Union[int, typing.Tuple[typing.Union[int, slice], ...]],
+    expected: typing.Union[haem.DNABase, haem.DNASequence],
+) -> None:
+    assert ALIGNMENT[key] == expected
+
+
+def test_alignment_getitem_slice() -> None:
+    selection = ALIGNMENT[1:, 1:3]
+
+    assert isinstance(selection, haem.Alignment)
+    assert selection.ids == ["b", "c"]
+    assert selection.sequences == [haem.DNASequence("CT"), haem.DNASequence("C-")]
+
+
+@pytest.mark.parametrize(
+    "key,message",
+    [
+        (3, "row index out of range"),
+        (-4, "row index out of range"),
+        ((0, 5), "column index out of range"),
+        ((0, -6), "column index out of range"),
+    ],
+)
+def test_alignment_getitem_out_of_range(
+    key: typing.Union[int, typing.Tuple[int, int]],
+    message: str,
+) -> None:
+    with pytest.raises(IndexError) as excinfo:
+        ALIGNMENT[key]
+
+    assert str(excinfo.value) == message
+
+
+def test_alignment_composition() -> None:
+    composition = ALIGNMENT.composition()
+
+    assert len(composition) == 5
+    assert composition[0] == {haem.DNABase.ADENINE: 2, haem.DNABase.GUANINE: 1}
+    assert composition[2] == {haem.DNABase.GAP: 2, haem.DNABase.THYMINE: 1}
+
+
+def test_alignment_consensus() -> None:
+    assert ALIGNMENT.consensus() == haem.DNASequence("AC-GT")
+
+
+def test_alignment_consensus_tie() -> None:
+    alignment = haem.Alignment(
+        [haem.AminoAcidSequence("MW"), haem.AminoAcidSequence("LV")]
+    )
+
+    assert alignment.consensus() == haem.AminoAcidSequence("MW")
+
+
+def test_alignment_gap_fractions() -> None:
+    assert ALIGNMENT.gap_fractions() == [0, 0, 2 / 3, 0, 0]
+
+
+@pytest.mark.parametrize("format", ["fasta", "clustal", "stockholm", "phylip"])
+def test_alignment_round_trip(tmp_path: pathlib.Path, format: str) -> None:
+    path = tmp_path / "alignment"
+    haem.write_alignment(path, ALIGNMENT, format)
+
+    read = haem.read_alignment(path, format)
+
+    assert read.ids == ALIGNMENT.ids
+    assert read.sequences == ALIGNMENT.sequences
+
+
+@pytest.mark.parametrize("format", ["fasta", "clustal", "stockholm", "phylip"])
+def test_alignment_round_trip_wide(tmp_path: pathlib.Path, format: str) -> None:
+    alignment = haem.Alignment(
+        [haem.AminoAcidSequence("MV-R" * 40), haem.AminoAcidSequence("MVKR" * 40)],
+        ids=["first", "second"],
+    )
+    path = tmp_path / "alignment"
+    haem.write_alignment(path, alignment, format)
+
+    read = haem.read_alignment(path, format, kind=haem.AminoAcidSequence)
+
+    assert read.ids == alignment.ids
+    assert read.sequences == alignment.sequences
+
+
+def test_read_alignment_fasta(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "alignment.fa"
+    path.write_text(">a first\nAC-\ngt\n>b\nACTGT\n")
+
+    alignment = haem.read_alignment(path)
+
+    assert alignment.ids == ["a", "b"]
+    assert alignment.sequences == [
+        haem.DNASequence("AC-GT"),
+        haem.DNASequence("ACTGT"),
+    ]
+
+
+def test_read_alignment_clustal(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "alignment.aln"
+    path.write_text(
+        "CLUSTAL W (1.83) multiple sequence alignment\n\n"
+        "a    AC-G 4\n"
+        "b    ACTG 4\n"
+        "      ** *\n\n"
+        "a    T 5\n"
+        "b    T 5\n"
+        "     *\n"
+    )
+
+    alignment = haem.read_alignment(path, "clustal")
+
+    assert alignment.ids == ["a", "b"]
+    assert alignment.sequences == [
+        haem.DNASequence("AC-GT"),
+        haem.DNASequence("ACTGT"),
+    ]
+
+
+def test_read_alignment_stockholm(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "alignment.sto"
+    path.write_text(
+        "# STOCKHOLM 1.0\n"
+        "#=GF ID example\n\n"
+        "a    AC.G\n"
+        "b    ACUG\n"
+        "#=GC SS_cons <<>>\n\n"
+        "a    u\n"
+        "b    U\n"
+        "//\n"
+    )
+
+    alignment = haem.read_alignment(path, "stockholm", kind=haem.RNASequence)
+
+    assert alignment.ids == ["a", "b"]
+    assert alignment.sequences == [
+        haem.RNASequence("AC-GU"),
+        haem.RNASequence("ACUGU"),
+    ]
+
+
+def test_read_alignment_phylip_interleaved(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "alignment.phy"
+    path.write_text(" 2 11\nfirst  ACGTA CG\nsecond ACGTT CG\n\nTTAG\nTT-G\n")
+
+    alignment = haem.read_alignment(path, "phylip")
+
+    assert alignment.ids == ["first", "second"]
+    assert alignment.sequences == [
+        haem.DNASequence("ACGTACGTTAG"),
+        haem.DNASequence("ACGTTCGTT-G"),
+    ]
+
+
+@pytest.mark.parametrize(
+    "format,text,message",
+    [
+        ("fasta", "AC\n", "invalid FASTA: expected header"),
+        ("clustal", "a AC\n", "invalid Clustal: expected header"),
+        ("stockholm", "a AC\n//\n", "invalid Stockholm: expected header"),
+        (
+            "stockholm",
+            "# STOCKHOLM 1.0\na AC\n",
+            "invalid Stockholm: expected terminator",
+        ),
+        ("phylip", "a AC\n", "invalid PHYLIP: expected header"),
+        (
+            "phylip",
+            "2 3\na ACG\nb AC\n",
+            "invalid PHYLIP: expected 2 sequences of length 3",
+        ),
+        ("phylip", "0 0\na AC\n", "invalid PHYLIP: expected 0 sequences of length 0"),
+        (
+            "phylip",
+            "2 2\na A\na C\nA\nC\n",
+            "invalid PHYLIP: expected 2 sequences of length 2",
+        ),
+        ("fasta", ">a\nAC\n>b\nA\n", "sequences must all be the same length"),
+        ("fasta", ">a\nAJ\n", 'invalid IUPAC DNA code "J"'),
+    ],
+)
+def test_read_alignment_invalid(
+    tmp_path: pathlib.Path, format: str, text: str, message: str
+) -> None:
+    path = tmp_path / "alignment"
+    path.write_text(text)
+
+    with pytest.raises(ValueError) as excinfo:
+        haem.read_alignment(path, format)
+
+    assert str(excinfo.value) == message
+
+
+def test_alignment_unknown_format(tmp_path: pathlib.Path) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.write_alignment(tmp_path / "alignment", ALIGNMENT, "nexus")
+
+    assert str(excinfo.value) == 'unknown alignment format "nexus"'
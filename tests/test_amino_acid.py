@@ -414,6 +414,26 @@ def test__new__glutamine_glutamic_acid() -> None:
         )
 
 
+@pytest.mark.parametrize(
+    "code,amino_acid",
+    [
+        ("*", haem.AminoAcid.STOP),
+        ("U", haem.AminoAcid.SELENOCYSTEINE),
+        ("O", haem.AminoAcid.PYRROLYSINE),
+        ("J", haem.AminoAcid.LEUCINE_ISOLEUCINE),
+        ("-", haem.AminoAcid.GAP),
+        (".", haem.AminoAcid.GAP),
+    ],
+)
+def test__new__extended_code(code: str, amino_acid: haem.AminoAcid) -> None:
+    assert haem.AminoAcid(code) == amino_acid
+
+
+@pytest.mark.parametrize("codon", ["MUU", "MUC", "MUA", "HUA", "WUA"])
+def test__new__leucine_isoleucine(codon: str) -> None:
+    assert haem.AminoAcid(codon) == haem.AminoAcid.LEUCINE_ISOLEUCINE
+
+
 def test__new__sequence_str() -> None:
     assert haem.AminoAcid(("U", "A", "C")) == haem.AminoAcid.TYROSINE
 
@@ -437,7 +457,7 @@ def test__new__stop() -> None:
 @pytest.mark.parametrize(
     "code,message",
     [
-        ("J", 'invalid IUPAC amino acid code "J"'),
+        ("!", 'invalid IUPAC amino acid code "!"'),
         ("JJ", "invalid amino acid codon"),
         ("NNN", "ambiguous codon"),
         ("---", "codon contains gap"),
@@ -480,6 +500,11 @@ def test__repr__() -> None:
         (haem.AminoAcid.ANY, "any"),
         (haem.AminoAcid.TYROSINE, "tyrosine"),
         (haem.AminoAcid.GLUTAMINE_GLUTAMIC_ACID, "glutamine/glutamic acid"),
+        (haem.AminoAcid.STOP, "stop"),
+        (haem.AminoAcid.SELENOCYSTEINE, "selenocysteine"),
+        (haem.AminoAcid.PYRROLYSINE, "pyrrolysine"),
+        (haem.AminoAcid.LEUCINE_ISOLEUCINE, "leucine/isoleucine"),
+        (haem.AminoAcid.GAP, "gap"),
     ],
 )
 def test__str__(amino_acid: haem.AminoAcid, text: str) -> None:
@@ -512,6 +537,11 @@ def test__str__(amino_acid: haem.AminoAcid, text: str) -> None:
         (haem.AminoAcid.ANY, "X"),
         (haem.AminoAcid.TYROSINE, "Y"),
         (haem.AminoAcid.GLUTAMINE_GLUTAMIC_ACID, "Z"),
+        (haem.AminoAcid.STOP, "*"),
+        (haem.AminoAcid.SELENOCYSTEINE, "U"),
+        (haem.AminoAcid.PYRROLYSINE, "O"),
+        (haem.AminoAcid.LEUCINE_ISOLEUCINE, "J"),
+        (haem.AminoAcid.GAP, "-"),
     ],
 )
 def test_code(amino_acid: haem.AminoAcid, code: str) -> None:
@@ -544,12 +574,74 @@ def test_code(amino_acid: haem.AminoAcid, code: str) -> None:
         (haem.AminoAcid.ANY, "xaa"),
         (haem.AminoAcid.TYROSINE, "tyr"),
         (haem.AminoAcid.GLUTAMINE_GLUTAMIC_ACID, "glx"),
+        (haem.AminoAcid.STOP, "ter"),
+        (haem.AminoAcid.SELENOCYSTEINE, "sec"),
+        (haem.AminoAcid.PYRROLYSINE, "pyl"),
+        (haem.AminoAcid.LEUCINE_ISOLEUCINE, "xle"),
+        (haem.AminoAcid.GAP, "gap"),
     ],
 )
 def test_short_name(amino_acid: haem.AminoAcid, short_name: str) -> None:
     assert amino_acid.short_name == short_name
 
 
+@pytest.mark.parametrize(
+    "amino_acid,amino_acids",
+    [
+        (haem.AminoAcid.ALANINE, {haem.AminoAcid.ALANINE}),
+        (
+            haem.AminoAcid.ASPARTIC_ACID_ASPARAGINE,
+            {haem.AminoAcid.ASPARTIC_ACID, haem.AminoAcid.ASPARAGINE},
+        ),
+        (
+            haem.AminoAcid.GLUTAMINE_GLUTAMIC_ACID,
+            {haem.AminoAcid.GLUTAMIC_ACID, haem.AminoAcid.GLUTAMINE},
+        ),
+        (
+            haem.AminoAcid.LEUCINE_ISOLEUCINE,
+            {haem.AminoAcid.ISOLEUCINE, haem.AminoAcid.LEUCINE},
+        ),
+        (haem.AminoAcid.STOP, {haem.AminoAcid.STOP}),
+        (haem.AminoAcid.GAP, set()),
+    ],
+)
+def test_amino_acids(
+    amino_acid: haem.AminoAcid, amino_acids: typing.Set[haem.AminoAcid]
+) -> None:
+    assert amino_acid.amino_acids == amino_acids
+
+
+def test_amino_acids_any() -> None:
+    amino_acids = haem.AminoAcid.ANY.amino_acids
+
+    assert len(amino_acids) == 22
+    assert haem.AminoAcid.SELENOCYSTEINE in amino_acids
+    assert haem.AminoAcid.STOP not in amino_acids
+
+
+@pytest.mark.parametrize(
+    "amino_acid,other,result",
+    [
+        (haem.AminoAcid.ALANINE, haem.AminoAcid.ALANINE, True),
+        (haem.AminoAcid.ALANINE, haem.AminoAcid.CYSTEINE, False),
+        (haem.AminoAcid.ASPARTIC_ACID_ASPARAGINE, "N", True),
+        (haem.AminoAcid.ASPARTIC_ACID, "B", True),
+        (haem.AminoAcid.ASPARTIC_ACID_ASPARAGINE, "Z", False),
+        (haem.AminoAcid.ANY, "W", True),
+        (haem.AminoAcid.ANY, "*", False),
+        (haem.AminoAcid.STOP, "*", True),
+    ],
+)
+def test_matches(
+    amino_acid: haem.AminoAcid, other: typing.Union[haem.AminoAcid, str], result: bool
+) -> None:
+    assert amino_acid.matches(other) is result
+
+
+def test__hash__() -> None:
+    assert len({haem.AminoAcid.ALANINE, haem.AminoAcid("A"), haem.AminoAcid.GAP}) == 2
+
+
 def test__eq__() -> None:
     assert haem.AminoAcid.ALANINE == haem.AminoAcid.ALANINE
 
@@ -568,6 +660,7 @@ def test_unsupported_comparison(
 
 def test__bool__() -> None:
     assert bool(haem.AminoAcid.ALANINE) is True
+    assert bool(haem.AminoAcid.GAP) is False
 
 
 @pytest.mark.parametrize(
@@ -19,9 +19,9 @@ def test__new__str() -> None:
 
 def test__new__str__invalid() -> None:
     with pytest.raises(ValueError) as excinfo:
-        haem.AminoAcidSequence("JJ")
+        haem.AminoAcidSequence("!!")
 
-    assert str(excinfo.value) == 'invalid IUPAC amino acid code "J"'
+    assert str(excinfo.value) == 'invalid IUPAC amino acid code "!"'
 
 
 def test__new__iterable_amino_acid() -> None:
@@ -43,11 +43,18 @@ def test__new__iterable_str() -> None:
     )
 
 
+def test__new__extended_codes() -> None:
+    sequence = haem.AminoAcidSequence("MUOJ*-")
+
+    assert str(sequence) == "MUOJ*-"
+    assert sequence[5] == haem.AminoAcid.GAP
+
+
 def test__new__iterable_invalid() -> None:
     with pytest.raises(ValueError) as excinfo:
-        haem.AminoAcidSequence(iter(["J"]))
+        haem.AminoAcidSequence(iter(["!"]))
 
-    assert str(excinfo.value) == 'invalid IUPAC amino acid code "J"'
+    assert str(excinfo.value) == 'invalid IUPAC amino acid code "!"'
 
 
 def test__new__sequence_amino_acids() -> None:
@@ -116,12 +123,41 @@ def test__ne__() -> None:
     ) != haem.AminoAcidSequence([haem.AminoAcid.METHIONINE, haem.AminoAcid.ARGININE])
 
 
-@pytest.mark.parametrize("op", [operator.gt, operator.ge, operator.lt, operator.le])
-def test_unsupported_comparison(
+def test__hash__() -> None:
+    sequences = {
+        haem.AminoAcidSequence("MKV"),
+        haem.AminoAcidSequence("MKV"),
+        haem.AminoAcidSequence("MVR"),
+    }
+
+    assert len(sequences) == 2
+
+
+@pytest.mark.parametrize(
+    "op,result",
+    [
+        (operator.lt, True),
+        (operator.le, True),
+        (operator.gt, False),
+        (operator.ge, False),
+    ],
+)
+def test_comparison(
     op: typing.Callable[[haem.AminoAcidSequence, haem.AminoAcidSequence], bool],
+    result: bool,
 ) -> None:
-    with pytest.raises(TypeError):
-        op(haem.AminoAcidSequence(), haem.AminoAcidSequence())
+    assert op(haem.AminoAcidSequence("MKV"), haem.AminoAcidSequence("MVR")) is result
+
+
+def test_sorted() -> None:
+    sequences = [haem.AminoAcidSequence(codes) for codes in ["MVR", "", "MKV", "MK"]]
+
+    assert sorted(sequences) == [
+        haem.AminoAcidSequence(),
+        haem.AminoAcidSequence("MK"),
+        haem.AminoAcidSequence("MKV"),
+        haem.AminoAcidSequence("MVR"),
+    ]
 
 
 @pytest.mark.parametrize(
@@ -376,3 +412,114 @@ def test_find(
     result: typing.Optional[int],
 ) -> None:
     assert sequence.find(target) == result
+
+
+@pytest.mark.parametrize(
+    "target,index,total",
+    [
+        ("D", 1, 2),
+        ("B", 1, 2),
+        ("L", 3, 2),
+        ("X", 0, 5),
+        ("ZX", 2, 1),
+        ("*", None, 0),
+    ],
+)
+def test_ambiguous(target: str, index: typing.Optional[int], total: int) -> None:
+    sequence = haem.AminoAcidSequence("MBZJX")
+
+    assert sequence.find(target, ambiguous=True) == index
+    assert sequence.count(target, ambiguous=True) == total
+    assert sequence.contains(target, ambiguous=True) is (index is not None)
+
+
+def test_ambiguous_disabled() -> None:
+    sequence = haem.AminoAcidSequence("MBZJX")
+
+    assert sequence.find("D") is None
+    assert sequence.count("X") == 1
+    assert not sequence.contains("L")
+    assert "L" not in sequence
+
+
+@pytest.mark.parametrize(
+    "start,end,result",
+    [
+        (None, None, 0),
+        (2, None, 2),
+        (-3, None, 5),
+        (None, 1, None),
+        (6, 2, None),
+        (100, None, None),
+    ],
+)
+def test_find_bounds(
+    start: typing.Optional[int], end: typing.Optional[int], result: typing.Optional[int]
+) -> None:
+    assert haem.AminoAcidSequence("MMMMVMMM").find("MM", start, end) == result
+
+
+@pytest.mark.parametrize(
+    "target,start,end,result",
+    [
+        ("M", None, None, 7),
+        ("MM", None, None, 6),
+        ("M", None, -3, 3),
+        ("V", 5, None, None),
+        ("W", None, None, None),
+    ],
+)
+def test_rfind(
+    target: str,
+    start: typing.Optional[int],
+    end: typing.Optional[int],
+    result: typing.Optional[int],
+) -> None:
+    assert haem.AminoAcidSequence("MMMMVMMM").rfind(target, start, end) == result
+
+
+def test_index() -> None:
+    sequence = haem.AminoAcidSequence("MMMMVMMM")
+
+    assert sequence.index("V") == 4
+    assert sequence.rindex("MM", -4) == 6
+
+
+@pytest.mark.parametrize("method", ["index", "rindex"])
+def test_index_not_found(method: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        getattr(haem.AminoAcidSequence("MMMMVMMM"), method)("V", 5)
+
+    assert str(excinfo.value) == "subsequence not found"
+
+
+@pytest.mark.parametrize(
+    "target,overlap,start,end,result",
+    [
+        ("MM", False, None, None, [0, 2, 5]),
+        ("MM", True, None, None, [0, 1, 2, 5, 6]),
+        ("MM", True, 1, -1, [1, 2, 5]),
+        ("M", False, -3, None, [5, 6, 7]),
+        ("W", False, None, None, []),
+        ("", False, None, None, []),
+    ],
+)
+def test_find_all(
+    target: str,
+    overlap: bool,
+    start: typing.Optional[int],
+    end: typing.Optional[int],
+    result: typing.List[int],
+) -> None:
+    sequence = haem.AminoAcidSequence("MMMMVMMM")
+
+    assert sequence.find_all(target, overlap, start, end) == result
+    assert list(sequence.finditer(target, overlap, start, end)) == result
+
+
+def test_finditer_lazy() -> None:
+    matches = haem.AminoAcidSequence("MMMMVMMM").finditer("MM")
+
+    assert next(matches) == 0
+    assert next(matches) == 2
+    assert list(matches) == [5]
@@ -0,0 +1,110 @@
+import typing
+
+import pytest
+
+import haem
+
+
+@pytest.mark.parametrize(
+    "sequences,threshold,expected",
+    [
+        (["ACGT", "ACGA", "GCGT", "GC-T"], 0.5, "RCGT"),
+        (["ACGT", "ACGA", "GCGT", "GC-T"], 0.75, "RCGT"),
+        (["ACGT", "ACGA", "GCGT", "GC-T"], 1, "RCGW"),
+        (["A", "C", "G", "T"], 0.5, "N"),
+        (["G", "A", "C", "G"], 0.75, "V"),
+        (["R", "A", "G", "A"], 0.5, "A"),
+        (["N", "A"], 0.5, "A"),
+        (["A-", "C-"], 0.5, "M-"),
+    ],
+)
+def test_consensus(
+    sequences: typing.List[str], threshold: float, expected: str
+) -> None:
+    assert haem.consensus(
+        [haem.DNASequence(sequence) for sequence in sequences], threshold=threshold
+    ) == haem.DNASequence(expected)
+
+
+def test_consensus_rna() -> None:
+    assert haem.consensus(
+        [haem.RNASequence("ACGU"), haem.RNASequence("AUGU")]
+    ) == haem.RNASequence("AYGU")
+
+
+def test_consensus_alignment() -> None:
+    alignment = haem.Alignment([haem.DNASequence("ACGT"), haem.DNASequence("ACCT")])
+
+    assert haem.consensus(alignment) == haem.DNASequence("ACST")
+
+
+@pytest.mark.parametrize(
+    "sequences,expected",
+    [
+        (["DEIA", "NQLC", "DEIA", "NQLW"], "BZJA"),
+        (["DEIA", "NQLC", "AEIA"], "XEIA"),
+        (["MB", "MD", "MN"], "MB"),
+    ],
+)
+def test_consensus_amino_acids(sequences: typing.List[str], expected: str) -> None:
+    assert haem.consensus(
+        [haem.AminoAcidSequence(sequence) for sequence in sequences]
+    ) == haem.AminoAcidSequence(expected)
+
+
+@pytest.mark.parametrize(
+    "sequences,gaps,expected",
+    [
+        (["A-", "--", "--"], "ignore", "A-"),
+        (["A-", "--", "--"], "include", "--"),
+        (["AC", "AC", "-C"], "include", "AC"),
+        (["AC", "GC", "-C", "-C"], "include", "-C"),
+    ],
+)
+def test_consensus_gaps(sequences: typing.List[str], gaps: str, expected: str) -> None:
+    assert haem.consensus(
+        [haem.DNASequence(sequence) for sequence in sequences], gaps=gaps
+    ) == haem.DNASequence(expected)
+
+
+@pytest.mark.parametrize(
+    "kind,sequences,expected",
+    [
+        (haem.DNASequence, ["AC", "A-", "AC"], "AN"),
+        (haem.AminoAcidSequence, ["MC", "M-", "MC"], "MX"),
+    ],
+)
+def test_consensus_min_depth(
+    kind: typing.Type[typing.Union[haem.DNASequence, haem.AminoAcidSequence]],
+    sequences: typing.List[str],
+    expected: str,
+) -> None:
+    assert haem.consensus(
+        [kind(sequence) for sequence in sequences], min_depth=3
+    ) == kind(expected)
+
+
+def test_consensus_empty() -> None:
+    assert haem.consensus([]) == haem.DNASequence()
+
+
+@pytest.mark.parametrize("threshold", [0, -0.5, 1.5])
+def test_consensus_invalid_threshold(threshold: float) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.consensus([haem.DNASequence("A")], threshold=threshold)
+
+    assert str(excinfo.value) == "threshold must be between 0 and 1"
+
+
+def test_consensus_unknown_gaps() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.consensus([haem.DNASequence("A")], gaps="remove")
+
+    assert str(excinfo.value) == 'unknown gap handling "remove"'
+
+
+def test_consensus_different_lengths() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.consensus([haem.DNASequence("A"), haem.DNASequence("AC")])
+
+    assert str(excinfo.value) == "sequences must all be the same length"
@@ -0,0 +1,142 @@
+import typing
+
+import pytest
+
+import haem
+
+
+@pytest.mark.parametrize(
+    "first,second,ambiguous,expected",
+    [
+        (haem.DNASequence("ACGT"), haem.DNASequence("ACGT"), False, 0),
+        (haem.DNASequence("ACGT"), haem.DNASequence("ANGA"), False, 2),
+        (haem.DNASequence("ACGT"), haem.DNASequence("ANGA"), True, 1),
+        (haem.DNASequence("ACGT"), haem.DNASequence("AC-T"), True, 1),
+        (haem.DNASequence("RRRR"), haem.DNASequence("AGCT"), True, 2),
+        (haem.RNASequence("ACGU"), haem.RNASequence("UGCA"), False, 4),
+        (haem.AminoAcidSequence("MBZ"), haem.AminoAcidSequence("MDE"), True, 0),
+        (haem.DNASequence(), haem.DNASequence(), False, 0),
+    ],
+)
+def test_hamming_distance(
+    first: typing.Union[haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence],
+    second: typing.Union[haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence],
+    ambiguous: bool,
+    expected: int,
+) -> None:
+    assert haem.hamming_distance(first, second, ambiguous=ambiguous) == expected
+
+
+def test_hamming_distance_different_lengths() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.hamming_distance(haem.DNASequence("AC"), haem.DNASequence("A"))
+
+    assert str(excinfo.value) == "sequences must be the same length"
+
+
+@pytest.mark.parametrize(
+    "first,second,max_distance,ambiguous,expected",
+    [
+        ("GATTACA", "GATTACA", None, False, 0),
+        ("GATTACA", "GATACA", None, False, 1),
+        ("KITTEN", "SITTING", None, False, 3),
+        ("ACGT", "", None, False, 4),
+        ("", "ACGT", None, False, 4),
+        ("ACGTACGT", "TGCATGCA", None, False, 6),
+        ("ACGTACGT", "TGCATGCA", 6, False, 6),
+        ("ACGTACGT", "TGCATGCA", 5, False, None),
+        ("ACGT", "ACGTAAAA", 3, False, None),
+        ("ACGT", "ACGA", 0, False, None),
+        ("ACGT", "ACGT", 0, False, 0),
+        ("ACGT", "XCGT", None, True, 0),
+        ("ACGT", "XCGT", None, False, 1),
+    ],
+)
+def test_levenshtein_distance(
+    first: str,
+    second: str,
+    max_distance: typing.Optional[int],
+    ambiguous: bool,
+    expected: typing.Optional[int],
+) -> None:
+    assert (
+        haem.levenshtein_distance(
+            haem.AminoAcidSequence(first),
+            haem.AminoAcidSequence(second),
+            max_distance=max_distance,
+            ambiguous=ambiguous,
+        )
+        == expected
+    )
+
+
+@pytest.mark.parametrize("function", [haem.hamming_distance, haem.levenshtein_distance])
+def test_distance_different_types(
+    function: typing.Callable[..., typing.Optional[int]],
+) -> None:
+    with pytest.raises(TypeError) as excinfo:
+        function(haem.DNASequence("A"), haem.RNASequence("A"))
+
+    assert str(excinfo.value) == "sequences must be of the same type"
+
+
+def test_distance_matrix() -> None:
+    sequences = [
+        haem.DNASequence("ACGT"),
+        haem.DNASequence("ACGA"),
+        haem.DNASequence("TTTT"),
+    ]
+
+    assert haem.distance_matrix(sequences) == [[0, 1, 3], [1, 0, 4], [3, 4, 0]]
+
+
+@pytest.mark.parametrize(
+    "metric,max_distance,ambiguous,expected",
+    [
+        ("levenshtein", None, False, [[0, 1, 3], [1, 0, 4], [3, 4, 0]]),
+        ("levenshtein", 2, False, [[0, 1, None], [1, 0, None], [None, None, 0]]),
+        ("levenshtein", 2, True, [[0, 1, 1], [1, 0, 2], [1, 2, 0]]),
+    ],
+)
+def test_distance_matrix_levenshtein(
+    metric: str,
+    max_distance: typing.Optional[int],
+    ambiguous: bool,
+    expected: typing.List[typing.List[typing.Optional[int]]],
+) -> None:
+    sequences = [
+        haem.DNASequence("ACGT"),
+        haem.DNASequence("ACG"),
+        haem.DNASequence("NNTT"),
+    ]
+
+    assert (
+        haem.distance_matrix(
+            sequences, metric, max_distance=max_distance, ambiguous=ambiguous
+        )
+        == expected
+    )
+
+
+def test_distance_matrix_hamming_max_distance() -> None:
+    sequences = [haem.RNASequence("ACGU"), haem.RNASequence("UGCA")]
+
+    assert haem.distance_matrix(sequences, max_distance=2) == [[0, None], [None, 0]]
+
+
+def test_distance_matrix_empty() -> None:
+    assert haem.distance_matrix([]) == []
+
+
+def test_distance_matrix_different_lengths() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.distance_matrix([haem.DNASequence("AC"), haem.DNASequence("A")])
+
+    assert str(excinfo.value) == "sequences must be the same length"
+
+
+def test_distance_matrix_unknown_metric() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.distance_matrix([], "euclidean")
+
+    assert str(excinfo.value) == 'unknown distance metric "euclidean"'
@@ -148,6 +148,50 @@ def test_transcribe(dna_base: haem.DNABase, rna_base: haem.RNABase) -> None:
     assert dna_base.transcribe() == rna_base
 
 
+@pytest.mark.parametrize(
+    "base,bases",
+    [
+        (haem.DNABase.ADENINE, {haem.DNABase.ADENINE}),
+        (haem.DNABase.ADENINE_GUANINE, {haem.DNABase.ADENINE, haem.DNABase.GUANINE}),
+        (
+            haem.DNABase.ANY,
+            {
+                haem.DNABase.ADENINE,
+                haem.DNABase.CYTOSINE,
+                haem.DNABase.GUANINE,
+                haem.DNABase.THYMINE,
+            },
+        ),
+        (haem.DNABase.GAP, set()),
+    ],
+)
+def test_bases(base: haem.DNABase, bases: typing.Set[haem.DNABase]) -> None:
+    assert base.bases == bases
+
+
+@pytest.mark.parametrize(
+    "base,other,result",
+    [
+        (haem.DNABase.ADENINE, haem.DNABase.ADENINE, True),
+        (haem.DNABase.ADENINE, haem.DNABase.GUANINE, False),
+        (haem.DNABase.ADENINE_GUANINE, haem.DNABase.GUANINE, True),
+        (haem.DNABase.GUANINE, haem.DNABase.ADENINE_GUANINE, True),
+        (haem.DNABase.ADENINE_GUANINE, haem.DNABase.CYTOSINE_THYMINE, False),
+        (haem.DNABase.ANY, "T", True),
+        (haem.DNABase.ANY, haem.DNABase.GAP, False),
+        (haem.DNABase.GAP, "-", True),
+    ],
+)
+def test_matches(
+    base: haem.DNABase, other: typing.Union[haem.DNABase, str], result: bool
+) -> None:
+    assert base.matches(other) is result
+
+
+def test__hash__() -> None:
+    assert len({haem.DNABase.ADENINE, haem.DNABase("A"), haem.DNABase.GAP}) == 2
+
+
 def test__eq__() -> None:
     assert haem.DNABase.ADENINE == haem.DNABase.ADENINE
 
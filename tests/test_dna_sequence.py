@@ -76,6 +76,34 @@ def test_complement(sequence: haem.DNASequence, complement: haem.DNASequence) ->
     assert ~sequence == complement
 
 
+@pytest.mark.parametrize(
+    "sequence,reverse_complement",
+    [
+        (haem.DNASequence(), haem.DNASequence()),
+        (haem.DNASequence("A"), haem.DNASequence("T")),
+        (haem.DNASequence("AACG"), haem.DNASequence("CGTT")),
+        (haem.DNASequence("MRWSYKVHDBN-"), haem.DNASequence("-NVHDBMRSWYK")),
+    ],
+)
+def test_reverse_complement(
+    sequence: haem.DNASequence, reverse_complement: haem.DNASequence
+) -> None:
+    assert sequence.reverse_complement() == reverse_complement
+
+
+@pytest.mark.parametrize(
+    "sequence,reverse",
+    [
+        (haem.DNASequence(), haem.DNASequence()),
+        (haem.DNASequence("A"), haem.DNASequence("A")),
+        (haem.DNASequence("AACG"), haem.DNASequence("GCAA")),
+    ],
+)
+def test_reverse(sequence: haem.DNASequence, reverse: haem.DNASequence) -> None:
+    assert sequence.reverse() == reverse
+    assert haem.DNASequence(reversed(sequence)) == reverse
+
+
 @pytest.mark.parametrize(
     "dna_sequence,rna_sequence",
     [
@@ -89,6 +117,107 @@ def test_transcribe(
     assert dna_sequence.transcribe() == rna_sequence
 
 
+@pytest.mark.parametrize(
+    "dna_sequence,amino_acid_sequence",
+    [
+        (haem.DNASequence("ATGTAA"), haem.AminoAcidSequence("M")),
+        (haem.DNASequence("CATGTGGTAA"), haem.AminoAcidSequence("MW")),
+        (haem.DNASequence("ATGMTTTAA"), haem.AminoAcidSequence("MJ")),
+    ],
+)
+def test_translate(
+    dna_sequence: haem.DNASequence, amino_acid_sequence: haem.AminoAcidSequence
+) -> None:
+    assert dna_sequence.translate() == amino_acid_sequence
+    assert dna_sequence.translate() == dna_sequence.transcribe().translate()
+
+
+def test_translate_options() -> None:
+    sequence = haem.DNASequence("GTGGCCTGAAGA")
+
+    assert str(sequence.translate(11, alternative_starts=True)) == "MA"
+    assert str(sequence.translate(2, frame=0, to_stop=False)) == "VAW*"
+    assert str(sequence[:9].translate(11, cds=True)) == "MA"
+
+
+def test_translate_no_start_codon() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.DNASequence("GCCTAA").translate()
+
+    assert str(excinfo.value) == "no start codon found"
+
+
+def test_translate_frames() -> None:
+    frames = haem.DNASequence("CCATGAAATGCCCTAAGGTTACATTTCATGG").translate_frames()
+
+    assert [str(frame) for frame in frames] == [
+        "P*NALRLHFM",
+        "HEMP*GYISW",
+        "MKCPKVTFH",
+        "P*NVTLGHFM",
+        "HEM*P*GISW",
+        "MKCNLRAFH",
+    ]
+
+
+@pytest.mark.parametrize(
+    "sequence,expected",
+    [
+        ("ATGAAANNNTAAGG", ["MKX*", "*XXK", "EXXR", "PXXF", "LXXS", "LXFH"]),
+        ("ATGAA-NNNTAAGG", ["M-X*", "*-XK", "E-XR", "PX-F", "LX-S", "LX-H"]),
+    ],
+)
+def test_translate_frames_ambiguous(sequence: str, expected: typing.List[str]) -> None:
+    frames = haem.DNASequence(sequence).translate_frames()
+
+    assert [str(frame) for frame in frames] == expected
+
+
+def test_find_orfs() -> None:
+    orfs = haem.DNASequence("CCATGAAATGCCCTAAGGTTACATTTCATGG").find_orfs()
+
+    assert [(orf.start, orf.end, orf.strand, orf.frame) for orf in orfs] == [
+        (7, 16, 1, 1),
+        (18, 24, -1, 1),
+    ]
+    assert [str(orf.sequence) for orf in orfs] == ["MP", "M"]
+
+
+@pytest.mark.parametrize(
+    "nested,min_length,sequences",
+    [
+        (False, 0, ["MMK"]),
+        (True, 0, ["MMK", "MK"]),
+        (True, 3, ["MMK"]),
+        (False, 4, []),
+    ],
+)
+def test_find_orfs_nested(
+    nested: bool, min_length: int, sequences: typing.List[str]
+) -> None:
+    orfs = haem.DNASequence("ATGATGAAATAA").find_orfs(
+        nested=nested, min_length=min_length
+    )
+
+    assert [str(orf.sequence) for orf in orfs] == sequences
+
+
+def test_find_orfs_alternative_starts() -> None:
+    sequence = haem.DNASequence("GTGAAATGA")
+
+    orfs = sequence.find_orfs(11, alternative_starts=True)
+
+    assert sequence.find_orfs(11) == []
+    assert [str(orf.sequence) for orf in orfs] == ["MK"]
+
+
+def test_open_reading_frame() -> None:
+    (orf,) = haem.DNASequence("CCATGTAA").find_orfs()
+
+    assert len(orf) == 6
+    assert repr(orf) == "<OpenReadingFrame: 2..8 +>"
+
+
 @pytest.mark.parametrize(
     "bases,text",
     [
@@ -130,12 +259,40 @@ def test__ne__() -> None:
     ) != haem.DNASequence([haem.DNABase.ADENINE, haem.DNABase.THYMINE])
 
 
-@pytest.mark.parametrize("op", [operator.gt, operator.ge, operator.lt, operator.le])
-def test_unsupported_comparison(
-    op: typing.Callable[[haem.DNASequence, haem.DNASequence], bool],
+def test__hash__() -> None:
+    sequences = {
+        haem.DNASequence("ACG"),
+        haem.DNASequence("ACG"),
+        haem.DNASequence("AGT"),
+    }
+
+    assert len(sequences) == 2
+
+
+@pytest.mark.parametrize(
+    "op,result",
+    [
+        (operator.lt, True),
+        (operator.le, True),
+        (operator.gt, False),
+        (operator.ge, False),
+    ],
+)
+def test_comparison(
+    op: typing.Callable[[haem.DNASequence, haem.DNASequence], bool], result: bool
 ) -> None:
-    with pytest.raises(TypeError):
-        op(haem.DNASequence(), haem.DNASequence())
+    assert op(haem.DNASequence("ACG"), haem.DNASequence("AGT")) is result
+
+
+def test_sorted() -> None:
+    sequences = [haem.DNASequence(codes) for codes in ["AGT", "", "ACG", "AC"]]
+
+    assert sorted(sequences) == [
+        haem.DNASequence(),
+        haem.DNASequence("AC"),
+        haem.DNASequence("ACG"),
+        haem.DNASequence("AGT"),
+    ]
 
 
 @pytest.mark.parametrize("bases,result", [([], False), ([haem.DNABase.ADENINE], True)])
@@ -353,3 +510,113 @@ def test_find(
     result: typing.Optional[int],
 ) -> None:
     assert sequence.find(target) == result
+
+
+@pytest.mark.parametrize(
+    "target,index,total",
+    [
+        ("R", 0, 5),
+        ("N", 0, 7),
+        ("GN", 2, 2),
+        ("TC", 3, 1),
+        ("-", None, 0),
+    ],
+)
+def test_ambiguous(target: str, index: typing.Optional[int], total: int) -> None:
+    sequence = haem.DNASequence("ACGTNRA")
+
+    assert sequence.find(target, ambiguous=True) == index
+    assert sequence.count(target, ambiguous=True) == total
+    assert sequence.contains(target, ambiguous=True) is (index is not None)
+
+
+def test_ambiguous_disabled() -> None:
+    sequence = haem.DNASequence("ACGTNRA")
+
+    assert sequence.find("R") == 5
+    assert sequence.count("N") == 1
+    assert not sequence.contains("GN")
+    assert "GN" not in sequence
+
+
+@pytest.mark.parametrize(
+    "start,end,result",
+    [
+        (None, None, 0),
+        (2, None, 2),
+        (-3, None, 5),
+        (None, 1, None),
+        (6, 2, None),
+        (100, None, None),
+    ],
+)
+def test_find_bounds(
+    start: typing.Optional[int], end: typing.Optional[int], result: typing.Optional[int]
+) -> None:
+    assert haem.DNASequence("AAAACAAA").find("AA", start, end) == result
+
+
+@pytest.mark.parametrize(
+    "target,start,end,result",
+    [
+        ("A", None, None, 7),
+        ("AA", None, None, 6),
+        ("A", None, -3, 3),
+        ("C", 5, None, None),
+        ("G", None, None, None),
+    ],
+)
+def test_rfind(
+    target: str,
+    start: typing.Optional[int],
+    end: typing.Optional[int],
+    result: typing.Optional[int],
+) -> None:
+    assert haem.DNASequence("AAAACAAA").rfind(target, start, end) == result
+
+
+def test_index() -> None:
+    sequence = haem.DNASequence("AAAACAAA")
+
+    assert sequence.index("C") == 4
+    assert sequence.rindex("AA", -4) == 6
+
+
+@pytest.mark.parametrize("method", ["index", "rindex"])
+def test_index_not_found(method: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        getattr(haem.DNASequence("AAAACAAA"), method)("C", 5)
+
+    assert str(excinfo.value) == "subsequence not found"
+
+
+@pytest.mark.parametrize(
+    "target,overlap,start,end,result",
+    [
+        ("AA", False, None, None, [0, 2, 5]),
+        ("AA", True, None, None, [0, 1, 2, 5, 6]),
+        ("AA", True, 1, -1, [1, 2, 5]),
+        ("A", False, -3, None, [5, 6, 7]),
+        ("G", False, None, None, []),
+        ("", False, None, None, []),
+    ],
+)
+def test_find_all(
+    target: str,
+    overlap: bool,
+    start: typing.Optional[int],
+    end: typing.Optional[int],
+    result: typing.List[int],
+) -> None:
+    sequence = haem.DNASequence("AAAACAAA")
+
+    assert sequence.find_all(target, overlap, start, end) == result
+    assert list(sequence.finditer(target, overlap, start, end)) == result
+
+
+def test_finditer_lazy() -> None:
+    matches = haem.DNASequence("AAAACAAA").finditer("AA")
+
+    assert next(matches) == 0
+    assert next(matches) == 2
+    assert list(matches) == [5]
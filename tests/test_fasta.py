@@ -0,0 +1,150 @@
+import pathlib
+import typing
+
+import pytest
+
+import haem
+
+
+def test_read_fasta(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "sequences.fa"
+    path.write_text(">seq1 first sequence\nACGT\nacgt\n\n>seq2\nGGCC\n")
+
+    records = list(haem.read_fasta(path))
+
+    assert len(records) == 2
+    assert records[0].id == "seq1"
+    assert records[0].description == "first sequence"
+    assert records[0].sequence == haem.DNASequence("ACGTACGT")
+    assert records[1].id == "seq2"
+    assert records[1].description == ""
+    assert records[1].sequence == haem.DNASequence("GGCC")
+
+
+@pytest.mark.parametrize(
+    "kind,text,sequence",
+    [
+        (haem.DNASequence, "ACGT", haem.DNASequence("ACGT")),
+        (haem.RNASequence, "ACGU", haem.RNASequence("ACGU")),
+        (haem.AminoAcidSequence, "MVVR", haem.AminoAcidSequence("MVVR")),
+    ],
+)
+def test_read_fasta_kind(
+    tmp_path: pathlib.Path,
+    kind: typing.Type[
+        typing.Union[haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence]
+    ],
+    text: str,
+    sequence: typing.Union[
+        haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence
+    ],
+) -> None:
+    path = tmp_path / "sequences.fa"
+    path.write_text(f">seq\n{text}\n")
+
+    assert next(haem.read_fasta(path, kind=kind)).sequence == sequence
+
+
+def test_read_fasta_empty_record(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "sequences.fa"
+    path.write_text(">empty\n>seq\nA\n")
+
+    records = list(haem.read_fasta(path))
+
+    assert records[0].sequence == haem.DNASequence()
+    assert records[1].sequence == haem.DNASequence("A")
+
+
+def test_read_fasta_empty_file(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "sequences.fa"
+    path.write_text("")
+
+    assert list(haem.read_fasta(path)) == []
+
+
+def test_read_fasta_missing_header(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "sequences.fa"
+    path.write_text("ACGT\n")
+
+    with pytest.raises(ValueError) as excinfo:
+        list(haem.read_fasta(path))
+
+    assert str(excinfo.value) == "invalid FASTA: expected header"
+
+
+def test_read_fasta_invalid_code(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "sequences.fa"
+    path.write_text(">seq\nACGU\n")
+
+    with pytest.raises(ValueError) as excinfo:
+        list(haem.read_fasta(path))
+
+    assert str(excinfo.value) == 'invalid IUPAC DNA code "U"'
+
+
+def test_read_fasta_invalid_kind(tmp_path: pathlib.Path) -> None:
+    with pytest.raises(TypeError):
+        haem.read_fasta(tmp_path / "sequences.fa", kind=str)  # type: ignore[arg-type]
+
+
+def test_read_fasta_missing_file(tmp_path: pathlib.Path) -> None:
+    with pytest.raises(FileNotFoundError):
+        haem.read_fasta(tmp_path / "missing.fa")
+
+
+@pytest.mark.parametrize(
+    "line_width,text",
+    [
+        (60, ">seq1 first\nACGTACGT\n>seq2\nMVVR\n"),
+        (3, ">seq1 first\nACG\nTAC\nGT\n>seq2\nMVV\nR\n"),
+        (None, ">seq1 first\nACGTACGT\n>seq2\nMVVR\n"),
+    ],
+)
+def test_write_fasta(
+    tmp_path: pathlib.Path, line_width: typing.Optional[int], text: str
+) -> None:
+    path = tmp_path / "sequences.fa"
+
+    haem.write_fasta(
+        path,
+        [
+            haem.FastaRecord("seq1", haem.DNASequence("ACGTACGT"), "first"),
+            haem.FastaRecord("seq2", haem.AminoAcidSequence("MVVR")),
+        ],
+        line_width=line_width,
+    )
+
+    assert path.read_text() == text
+
+
+def test_write_fasta_invalid_line_width(tmp_path: pathlib.Path) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.write_fasta(tmp_path / "sequences.fa", [], line_width=0)
+
+    assert str(excinfo.value) == "line_width must be positive"
+
+
+def test_write_fasta_round_trip(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "sequences.fa"
+    records = [
+        haem.FastaRecord("seq1", haem.RNASequence("ACGU" * 50), "some description"),
+        haem.FastaRecord("seq2", haem.RNASequence("GGG")),
+    ]
+
+    haem.write_fasta(path, records)
+
+    for expected, actual in zip(
+        records, haem.read_fasta(path, kind=haem.RNASequence)
+    ):
+        assert actual.id == expected.id
+        assert actual.description == expected.description
+        assert actual.sequence == expected.sequence
+
+
+def test_fasta_record_invalid_sequence() -> None:
+    with pytest.raises(TypeError):
+        haem.FastaRecord("seq", "ACGT")  # type: ignore[arg-type]
+
+
+def test_fasta_record__repr__() -> None:
+    assert repr(haem.FastaRecord("seq", haem.DNASequence())) == "<FastaRecord: seq>"
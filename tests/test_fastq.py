@@ -0,0 +1,160 @@
+import pathlib
+import typing
+
+import pytest
+
+import haem
+
+
+def test__new__() -> None:
+    record = haem.FastqRecord("read", "ACGT", [40, 30, 20, 10], "first read")
+
+    assert record.id == "read"
+    assert record.description == "first read"
+    assert record.sequence == haem.DNASequence("ACGT")
+    assert record.qualities == [40, 30, 20, 10]
+
+
+def test__new__length_mismatch() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.FastqRecord("read", "ACGT", [40])
+
+    assert str(excinfo.value) == "sequence and qualities differ in length"
+
+
+@pytest.mark.parametrize(
+    "qualities,mean,minimum",
+    [([], None, None), ([40], 40.0, 40), ([40, 30, 20, 10], 25.0, 10)],
+)
+def test_quality_statistics(
+    qualities: typing.List[int],
+    mean: typing.Optional[float],
+    minimum: typing.Optional[int],
+) -> None:
+    record = haem.FastqRecord("read", "A" * len(qualities), qualities)
+
+    assert record.mean_quality == mean
+    assert record.min_quality == minimum
+
+
+def test__getitem__index() -> None:
+    record = haem.FastqRecord("read", "ACGT", [40, 30, 20, 10])
+
+    assert record[1] == haem.DNABase.CYTOSINE
+    assert record[-1] == haem.DNABase.THYMINE
+
+
+def test__getitem__index_out_of_range() -> None:
+    with pytest.raises(IndexError) as excinfo:
+        haem.FastqRecord("read", "ACGT", [40, 30, 20, 10])[4]
+
+    assert str(excinfo.value) == "FastqRecord index out of range"
+
+
+@pytest.mark.parametrize(
+    "key,sequence,qualities",
+    [
+        (slice(1, 3), "CG", [30, 20]),
+        (slice(None, None, 2), "AG", [40, 20]),
+        (slice(None, None, -1), "TGCA", [10, 20, 30, 40]),
+        (slice(4, None), "", []),
+    ],
+)
+def test__getitem__slice(
+    key: slice, sequence: str, qualities: typing.List[int]
+) -> None:
+    record = haem.FastqRecord("read", "ACGT", [40, 30, 20, 10], "first read")
+
+    assert record[key] == haem.FastqRecord("read", sequence, qualities, "first read")
+
+
+def test__len__() -> None:
+    assert len(haem.FastqRecord("read", "ACGT", [40, 30, 20, 10])) == 4
+
+
+def test__repr__() -> None:
+    assert repr(haem.FastqRecord("read", "A", [40])) == "<FastqRecord: read>"
+
+
+def test__str__() -> None:
+    assert str(haem.FastqRecord("read", "ACGT", [40, 30, 20, 10])) == "ACGT"
+
+
+def test_read_fastq(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "reads.fq"
+    path.write_text("@read1 first\nACGT\n+\nI?5+\n@read2\nggn\n+read2\n!!!\n")
+
+    records = list(haem.read_fastq(path))
+
+    assert records == [
+        haem.FastqRecord("read1", "ACGT", [40, 30, 20, 10], "first"),
+        haem.FastqRecord("read2", "GGN", [0, 0, 0]),
+    ]
+
+
+def test_read_fastq_phred64(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "reads.fq"
+    path.write_text("@read\nACGT\n+\nh^TJ\n")
+
+    assert list(haem.read_fastq(path, offset=64)) == [
+        haem.FastqRecord("read", "ACGT", [40, 30, 20, 10])
+    ]
+
+
+@pytest.mark.parametrize(
+    "text,message",
+    [
+        ("ACGT\n", "invalid FASTQ: expected header"),
+        ("@read\nACGT\n", "invalid FASTQ: truncated record"),
+        ("@read\nACGT\nIIII\nIIII\n", "invalid FASTQ: expected separator"),
+        (
+            "@read\nACGT\n+\nIII\n",
+            "invalid FASTQ: sequence and qualities differ in length",
+        ),
+        ("@read\nACGT\n+\nII I\n", 'invalid Phred+33 quality " "'),
+    ],
+)
+def test_read_fastq_invalid(tmp_path: pathlib.Path, text: str, message: str) -> None:
+    path = tmp_path / "reads.fq"
+    path.write_text(text)
+
+    with pytest.raises(ValueError) as excinfo:
+        list(haem.read_fastq(path))
+
+    assert str(excinfo.value) == message
+
+
+def test_read_fastq_invalid_offset(tmp_path: pathlib.Path) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.read_fastq(tmp_path / "reads.fq", offset=50)
+
+    assert str(excinfo.value) == "offset must be 33 or 64"
+
+
+@pytest.mark.parametrize(
+    "offset,text",
+    [
+        (33, "@read1 first\nACGT\n+\nI?5+\n@read2\nA\n+\n!\n"),
+        (64, "@read1 first\nACGT\n+\nh^TJ\n@read2\nA\n+\n@\n"),
+    ],
+)
+def test_write_fastq(tmp_path: pathlib.Path, offset: int, text: str) -> None:
+    path = tmp_path / "reads.fq"
+
+    haem.write_fastq(
+        path,
+        [
+            haem.FastqRecord("read1", "ACGT", [40, 30, 20, 10], "first"),
+            haem.FastqRecord("read2", "A", [0]),
+        ],
+        offset=offset,
+    )
+
+    assert path.read_text() == text
+
+
+def test_write_fastq_unencodable_quality(tmp_path: pathlib.Path) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.write_fastq(tmp_path / "reads.fq", [haem.FastqRecord("read", "A", [94])])
+
+    assert str(excinfo.value) == "quality 94 cannot be encoded as Phred+33"
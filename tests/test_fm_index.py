@@ -0,0 +1,155 @@
+import pathlib
+import typing
+
+import pytest
+
+import haem
+
+
+@pytest.mark.parametrize(
+    "sequence,count",
+    [("A", 3), ("AC", 2), ("ACG", 1), ("AA", 1), ("T", 0), ("ACAACGA", 0)],
+)
+def test_count(sequence: str, count: int) -> None:
+    index = haem.FMIndex(haem.DNASequence("ACAACG"))
+
+    assert index.count(haem.DNASequence(sequence)) == count
+    assert index.count(sequence) == count
+
+
+def test_locate() -> None:
+    index = haem.FMIndex(
+        [haem.DNASequence("ACGTACG"), haem.DNASequence(), haem.DNASequence("TACG")]
+    )
+
+    assert index.locate(haem.DNASequence("ACG")) == [(0, 0), (0, 4), (2, 1)]
+
+
+def test_locate_across_sequences() -> None:
+    index = haem.FMIndex([haem.DNASequence("AAC"), haem.DNASequence("GTT")])
+
+    assert index.locate(haem.DNASequence("CG")) == []
+
+
+@pytest.mark.parametrize(
+    "sequence,expected",
+    [("ACG", True), ("GTA", True), ("TT", False), ("ACGTACGT", False)],
+)
+def test_contains(sequence: str, expected: bool) -> None:
+    index = haem.FMIndex(haem.DNASequence("ACGTACG"))
+
+    assert index.contains(haem.DNASequence(sequence)) is expected
+    assert (haem.DNASequence(sequence) in index) is expected
+
+
+@pytest.mark.parametrize("method", ["count", "locate", "contains"])
+def test_empty_sequence(method: str) -> None:
+    index = haem.FMIndex(haem.DNASequence("ACG"))
+
+    with pytest.raises(ValueError) as excinfo:
+        getattr(index, method)(haem.DNASequence())
+
+    assert str(excinfo.value) == "sequence must not be empty"
+
+
+def test_bwt() -> None:
+    assert haem.FMIndex(haem.DNASequence("ACAACG")).bwt == "GC$AAAC"
+
+
+def test_suffix_array() -> None:
+    index = haem.FMIndex(haem.DNASequence("ACAACG"))
+
+    assert index.suffix_array == [6, 2, 0, 3, 1, 4, 5]
+
+
+def test_empty_index() -> None:
+    index = haem.FMIndex([])
+
+    assert index.bwt == ""
+    assert index.suffix_array == []
+    assert index.count(haem.DNASequence("A")) == 0
+
+
+def test_save_load(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "index.fmi"
+    index = haem.FMIndex([haem.DNASequence("ACNGT"), haem.DNASequence("GT-A")])
+    index.save(path)
+
+    loaded = haem.FMIndex.load(path)
+
+    assert loaded.bwt == index.bwt
+    assert loaded.suffix_array == index.suffix_array
+    assert loaded.locate(haem.DNASequence("GT")) == [(0, 3), (1, 0)]
+    assert len(loaded) == 2
+
+
+def test_save_load_empty(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "index.fmi"
+    haem.FMIndex([]).save(path)
+
+    loaded = haem.FMIndex.load(path)
+
+    assert loaded.bwt == ""
+    assert loaded.suffix_array == []
+    assert len(loaded) == 0
+
+
+@pytest.mark.parametrize(
+    "content", [b"", b"HAEMFMI", b"HAEMFMI\x02\x01", b"NOTANFMI\x01\x00"]
+)
+def test_load_invalid(tmp_path: pathlib.Path, content: bytes) -> None:
+    path = tmp_path / "index.fmi"
+    path.write_bytes(content)
+
+    with pytest.raises(ValueError) as excinfo:
+        haem.FMIndex.load(path)
+
+    assert str(excinfo.value) == "invalid FM-index file"
+
+
+# Offsets into the saved index of ACGT: the version at 7, the suffix array from
+# 32, the BWT from 80 and the counts from 93.
+@pytest.mark.parametrize(
+    "edit",
+    [
+        lambda content: content[:7] + b"\x01" + content[8:],
+        lambda content: content[:32] + b"\x05" + content[33:],
+        lambda content: content[:32] + content[40:48] + content[40:],
+        lambda content: content[:80] + b"\x11" + content[81:],
+        lambda content: content[:101] + b"\x02" + content[102:],
+        lambda content: content[:-1],
+        lambda content: content + b"\x00",
+    ],
+)
+def test_load_corrupt(
+    tmp_path: pathlib.Path, edit: typing.Callable[[bytes], bytes]
+) -> None:
+    path = tmp_path / "index.fmi"
+    haem.FMIndex(haem.DNASequence("ACGT")).save(path)
+    path.write_bytes(edit(path.read_bytes()))
+
+    with pytest.raises(ValueError) as excinfo:
+        haem.FMIndex.load(path)
+
+    assert str(excinfo.value) == "invalid FM-index file"
+
+
+@pytest.mark.parametrize(
+    "sequences,expected",
+    [
+        (haem.DNASequence("ACG"), 1),
+        ([], 0),
+        ([haem.DNASequence("A"), haem.DNASequence("C")], 2),
+    ],
+)
+def test__len__(
+    sequences: typing.Union[haem.DNASequence, typing.List[haem.DNASequence]],
+    expected: int,
+) -> None:
+    assert len(haem.FMIndex(sequences)) == expected
+
+
+def test__repr__() -> None:
+    index = haem.FMIndex([haem.DNASequence("A"), haem.DNASequence("C")])
+
+    assert repr(index) == "<FMIndex: 2 sequences>"
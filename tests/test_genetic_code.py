@@ -0,0 +1,163 @@
+import typing
+
+import pytest
+
+import haem
+
+TABLE_IDS = [1, 2, 3, 4, 5, 6, 9, 10, 11, 12, 13, 14, 15, 16]
+TABLE_IDS += [21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33]
+
+
+@pytest.mark.parametrize("table_id", TABLE_IDS)
+def test__new__id(table_id: int) -> None:
+    assert haem.GeneticCode(table_id).id == table_id
+
+
+@pytest.mark.parametrize(
+    "name,table_id",
+    [
+        ("Standard", 1),
+        ("vertebrate mitochondrial", 2),
+        ("Mycoplasma", 4),
+        (
+            "Mold Mitochondrial; Protozoan Mitochondrial; Coelenterate Mitochondrial; "
+            "Mycoplasma; Spiroplasma",
+            4,
+        ),
+        ("Bacterial, Archaeal and Plant Plastid", 11),
+        ("Cephalodiscidae Mitochondrial", 33),
+    ],
+)
+def test__new__name(name: str, table_id: int) -> None:
+    assert haem.GeneticCode(name).id == table_id
+
+
+def test__new__genetic_code() -> None:
+    assert haem.GeneticCode(haem.GeneticCode(2)) == haem.GeneticCode(2)
+
+
+@pytest.mark.parametrize(
+    "table,message",
+    [
+        (7, "unknown genetic code 7"),
+        (34, "unknown genetic code 34"),
+        ("Martian", 'unknown genetic code "Martian"'),
+    ],
+)
+def test__new__unknown(table: typing.Union[int, str], message: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.GeneticCode(table)
+
+    assert str(excinfo.value) == message
+
+
+def test_name() -> None:
+    assert haem.GeneticCode(2).name == "Vertebrate Mitochondrial"
+    assert str(haem.GeneticCode(2)) == "Vertebrate Mitochondrial"
+
+
+def test__repr__() -> None:
+    assert repr(haem.GeneticCode(11)) == "<GeneticCode: 11>"
+
+
+def test__eq__() -> None:
+    assert haem.GeneticCode(1) == haem.GeneticCode("Standard")
+    assert haem.GeneticCode(1) != haem.GeneticCode(2)
+
+
+@pytest.mark.parametrize(
+    "table_id,start_codons,stop_codons",
+    [
+        (1, ["UUG", "CUG", "AUG"], ["UAA", "UAG", "UGA"]),
+        (2, ["AUU", "AUC", "AUA", "AUG", "GUG"], ["UAA", "UAG", "AGA", "AGG"]),
+        (6, ["AUG"], ["UGA"]),
+        (11, ["UUG", "CUG", "AUU", "AUC", "AUA", "AUG", "GUG"], ["UAA", "UAG", "UGA"]),
+        (14, ["AUG"], ["UAG"]),
+        (22, ["AUG"], ["UCA", "UAA", "UGA"]),
+        (23, ["AUU", "AUG", "GUG"], ["UUA", "UAA", "UAG", "UGA"]),
+    ],
+)
+def test_start_and_stop_codons(
+    table_id: int, start_codons: typing.List[str], stop_codons: typing.List[str]
+) -> None:
+    assert haem.GeneticCode(table_id).start_codons == start_codons
+    assert haem.GeneticCode(table_id).stop_codons == stop_codons
+
+
+@pytest.mark.parametrize(
+    "codon,table,amino_acid",
+    [
+        ("UGA", 2, haem.AminoAcid.TRYPTOPHAN),
+        ("AUA", 2, haem.AminoAcid.METHIONINE),
+        ("CUG", 3, haem.AminoAcid.THREONINE),
+        ("UAA", 6, haem.AminoAcid.GLUTAMINE),
+        ("UAR", 6, haem.AminoAcid.GLUTAMINE),
+        ("UGA", 10, haem.AminoAcid.CYSTEINE),
+        ("CUG", 12, haem.AminoAcid.SERINE),
+        ("AGR", 13, haem.AminoAcid.GLYCINE),
+        ("AAA", 9, haem.AminoAcid.ASPARAGINE),
+        ("UGA", 25, haem.AminoAcid.GLYCINE),
+        ("CUG", 26, haem.AminoAcid.ALANINE),
+        ("UAR", 30, haem.AminoAcid.GLUTAMIC_ACID),
+        ("SAR", 30, haem.AminoAcid.GLUTAMINE_GLUTAMIC_ACID),
+        (
+            "UGA",
+            haem.GeneticCode("Vertebrate Mitochondrial"),
+            haem.AminoAcid.TRYPTOPHAN,
+        ),
+        ("UGA", "Mycoplasma", haem.AminoAcid.TRYPTOPHAN),
+    ],
+)
+def test_amino_acid_table(
+    codon: str,
+    table: typing.Union[haem.GeneticCode, int, str],
+    amino_acid: haem.AminoAcid,
+) -> None:
+    assert haem.AminoAcid(codon, table=table) == amino_acid
+
+
+@pytest.mark.parametrize(
+    "codon,table",
+    [("AGA", 2), ("AGR", 2), ("UCA", 22), ("UUA", 23), ("UGA", 27), ("UAA", 28)],
+)
+def test_amino_acid_table_stop(codon: str, table: int) -> None:
+    with pytest.raises(haem.StopTranslation):
+        haem.AminoAcid(codon, table=table)
+
+
+def test_amino_acid_table_ambiguous() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.AminoAcid("AGN", table=2)
+
+    assert str(excinfo.value) == "ambiguous codon"
+
+
+def test_amino_acid_table_unknown() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.AminoAcid("AUG", table=8)
+
+    assert str(excinfo.value) == "unknown genetic code 8"
+
+
+@pytest.mark.parametrize(
+    "table,amino_acid_sequence",
+    [
+        (1, haem.AminoAcidSequence("MA")),
+        (2, haem.AminoAcidSequence("MAWL")),
+        (haem.GeneticCode(2), haem.AminoAcidSequence("MAWL")),
+    ],
+)
+def test_rna_sequence_translate_table(
+    table: typing.Union[haem.GeneticCode, int],
+    amino_acid_sequence: haem.AminoAcidSequence,
+) -> None:
+    sequence = haem.RNASequence("AUGGCCUGAUUAAGA")
+
+    assert sequence.translate(table=table) == amino_acid_sequence
+
+
+def test_rna_sequence_translate_table_start() -> None:
+    # AUA is only a methionine codon in mitochondrial tables such as table 2.
+    sequence = haem.RNASequence("CAUAGCCUAA")
+
+    assert sequence.translate(table=2) == haem.AminoAcidSequence("MA")
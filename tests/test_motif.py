@@ -0,0 +1,122 @@
+import typing
+
+import pytest
+
+import haem
+
+
+@pytest.mark.parametrize(
+    "pattern,sequence,expected",
+    [
+        ("NGT", "ANGTNGT", [(1, 4), (4, 7)]),
+        ("N[^P][ST][^P]", "MNGTANPSTNAS", [(1, 5)]),
+        ("C.C", "CACGCAC", [(0, 3), (4, 7)]),
+        ("[DE]K", "AEKDKGK", [(1, 3), (3, 5)]),
+        ("GA?K", "GKGAK", [(0, 2), (2, 5)]),
+        ("KA*", "KAAAK", [(0, 4), (4, 5)]),
+        ("KA+", "KAAAK", [(0, 4)]),
+        ("C.{2,4}C", "CAACAAAAC", [(0, 4)]),
+        ("CA{2}", "CAAACA", [(0, 3)]),
+        ("CA{2,}", "CAAACA", [(0, 4)]),
+        ("^MA", "MAMA", [(0, 2)]),
+        ("MA$", "MAMA", [(2, 4)]),
+        ("^MA$", "MAMA", []),
+        ("B", "DNAE", [(0, 1), (1, 2)]),
+        ("B", "ABZD", [(1, 2), (3, 4)]),
+        ("Z", "QBZE", [(0, 1), (2, 3), (3, 4)]),
+        ("J", "IJLK", [(0, 1), (1, 2), (2, 3)]),
+        ("[BZ]K", "BKZKDK", [(0, 2), (2, 4), (4, 6)]),
+        ("A.A", "A*AA-A", []),
+        ("A-A", "A-AA.A", [(0, 3), (3, 6)]),
+    ],
+)
+def test_find_all(
+    pattern: str, sequence: str, expected: typing.List[typing.Tuple[int, int]]
+) -> None:
+    motif = haem.Motif(pattern)
+
+    assert motif.find_all(haem.AminoAcidSequence(sequence)) == expected
+    assert motif.find_all(sequence) == expected
+
+
+@pytest.mark.parametrize(
+    "pattern,sequence,expected",
+    [
+        ("N-{P}-[ST]-{P}.", "MNGTANPSTNAS", [(1, 5)]),
+        ("N-{P}-[ST]-{P}", "NNSTNAS", [(0, 4)]),
+        ("C-x(2,4)-C", "CAACAAAAC", [(0, 4)]),
+        ("C-x(2)-H", "CAAHCAH", [(0, 4)]),
+        ("<M-x-K", "MAKMAK", [(0, 3)]),
+        ("A-x(1,3)-K>", "AKAKK", [(0, 5)]),
+        ("[DE](2)-K", "DEKEK", [(0, 3)]),
+    ],
+)
+def test_find_all_prosite(
+    pattern: str, sequence: str, expected: typing.List[typing.Tuple[int, int]]
+) -> None:
+    motif = haem.Motif.from_prosite(pattern)
+
+    assert motif.find_all(haem.AminoAcidSequence(sequence)) == expected
+
+
+@pytest.mark.parametrize(
+    "overlap,expected",
+    [(False, [(0, 4), (4, 8)]), (True, [(0, 4), (1, 5), (4, 8)])],
+)
+def test_find_all_overlap(
+    overlap: bool, expected: typing.List[typing.Tuple[int, int]]
+) -> None:
+    motif = haem.Motif.from_prosite("N-{P}-[ST]-{P}")
+
+    assert motif.find_all("NNSTNASA", overlap=overlap) == expected
+
+
+@pytest.mark.parametrize("pattern", ["[]", "[ST", "A{3,1}", "A{", "A{,2}"])
+def test__new__invalid(pattern: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Motif(pattern)
+
+    assert str(excinfo.value) == f'invalid motif "{pattern}"'
+
+
+@pytest.mark.parametrize(
+    "pattern", ["A{18446744073709551617}", "A{1,99999999999999999999}"]
+)
+def test__new__repeat_too_large(pattern: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Motif(pattern)
+
+    assert str(excinfo.value) == "repeat count too large"
+
+
+@pytest.mark.parametrize(
+    "pattern", ["N-", "N--P", "N>-P", "A(", "{}", "A-x(2", "A-C.-D"]
+)
+def test_from_prosite_invalid(pattern: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Motif.from_prosite(pattern)
+
+    assert str(excinfo.value) == f'invalid motif "{pattern}"'
+
+
+def test__new__invalid_code() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Motif("A[S!]")
+
+    assert str(excinfo.value) == 'invalid IUPAC amino acid code "!"'
+
+
+@pytest.mark.parametrize("pattern", ["", "^$", "A*", "A?C{0,2}"])
+def test__new__empty_match(pattern: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Motif(pattern)
+
+    assert str(excinfo.value) == "motif must not match an empty sequence"
+
+
+def test_pattern() -> None:
+    assert haem.Motif.from_prosite("N-{P}-[ST]-{P}").pattern == "N-{P}-[ST]-{P}"
+
+
+def test__repr__() -> None:
+    assert repr(haem.Motif("N[^P][ST]")) == "<Motif: N[^P][ST]>"
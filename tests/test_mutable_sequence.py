@@ -0,0 +1,239 @@
+import typing
+
+import pytest
+
+import haem
+
+Mutable = typing.Union[
+    haem.MutableDNASequence, haem.MutableRNASequence, haem.MutableAminoAcidSequence
+]
+
+
+@pytest.mark.parametrize(
+    "mutable,sequence",
+    [
+        (haem.MutableDNASequence("ACGT"), haem.DNASequence("ACGT")),
+        (haem.MutableDNASequence(haem.DNASequence("AC")), haem.DNASequence("AC")),
+        (haem.MutableDNASequence(["A", "C"]), haem.DNASequence("AC")),
+        (haem.MutableDNASequence(), haem.DNASequence()),
+        (haem.MutableRNASequence("ACGU"), haem.RNASequence("ACGU")),
+        (haem.MutableAminoAcidSequence("MKV"), haem.AminoAcidSequence("MKV")),
+    ],
+)
+def test_freeze(
+    mutable: Mutable,
+    sequence: typing.Union[haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence],
+) -> None:
+    assert mutable.freeze() == sequence
+
+
+def test__init__invalid() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.MutableDNASequence("ACGU")
+
+    assert str(excinfo.value) == 'invalid IUPAC DNA code "U"'
+
+
+def test__init__mutable() -> None:
+    mutable = haem.MutableDNASequence("AC")
+    copy = haem.MutableDNASequence(mutable)
+    copy.append("G")
+
+    assert mutable == haem.MutableDNASequence("AC")
+    assert copy == haem.MutableDNASequence("ACG")
+
+
+def test_append() -> None:
+    mutable = haem.MutableDNASequence()
+    mutable.append("A")
+    mutable.append(haem.DNABase.CYTOSINE)
+
+    assert mutable == haem.MutableDNASequence("AC")
+
+
+def test_extend() -> None:
+    mutable = haem.MutableRNASequence("A")
+    mutable.extend("CG")
+    mutable.extend(haem.RNASequence("U"))
+    mutable.extend(mutable)
+    mutable += "N"
+
+    assert mutable == haem.MutableRNASequence("ACGUACGUN")
+
+
+@pytest.mark.parametrize(
+    "index,expected",
+    [(0, "GAC"), (1, "AGC"), (2, "ACG"), (10, "ACG"), (-1, "AGC"), (-10, "GAC")],
+)
+def test_insert(index: int, expected: str) -> None:
+    mutable = haem.MutableDNASequence("AC")
+    mutable.insert(index, "G")
+
+    assert mutable == haem.MutableDNASequence(expected)
+
+
+@pytest.mark.parametrize(
+    "key,value,expected",
+    [
+        (0, "T", "TCGT"),
+        (-1, haem.DNABase.ADENINE, "ACGA"),
+        (slice(1, 3), "NNN", "ANNNT"),
+        (slice(1, 1), "G", "AGCGT"),
+        (slice(None), "", ""),
+        (slice(None, None, 2), "NN", "NCNT"),
+        (slice(None, None, -2), "GA", "AAGG"),
+    ],
+)
+def test__setitem__(
+    key: typing.Union[int, slice],
+    value: typing.Union[haem.DNABase, str],
+    expected: str,
+) -> None:
+    mutable = haem.MutableDNASequence("ACGT")
+    mutable[key] = value
+
+    assert mutable == haem.MutableDNASequence(expected)
+
+
+@pytest.mark.parametrize(
+    "key,value,message",
+    [
+        (0, "AC", "only a single member can be assigned to an index"),
+        (
+            slice(None, None, 2),
+            "A",
+            "attempt to assign sequence of size 1 to extended slice of size 2",
+        ),
+    ],
+)
+def test__setitem__invalid(
+    key: typing.Union[int, slice], value: str, message: str
+) -> None:
+    mutable = haem.MutableDNASequence("ACGT")
+
+    with pytest.raises(ValueError) as excinfo:
+        mutable[key] = value
+
+    assert str(excinfo.value) == message
+
+
+@pytest.mark.parametrize(
+    "key,expected",
+    [
+        (0, "CGT"),
+        (-1, "ACG"),
+        (slice(1, 3), "AT"),
+        (slice(None, None, 2), "CT"),
+        (slice(None, None, -3), "CG"),
+    ],
+)
+def test__delitem__(key: typing.Union[int, slice], expected: str) -> None:
+    mutable = haem.MutableDNASequence("ACGT")
+    del mutable[key]
+
+    assert mutable == haem.MutableDNASequence(expected)
+
+
+@pytest.mark.parametrize("key", [4, -5])
+def test__delitem__out_of_range(key: int) -> None:
+    mutable = haem.MutableAminoAcidSequence("MKVL")
+
+    with pytest.raises(IndexError) as excinfo:
+        del mutable[key]
+
+    assert str(excinfo.value) == "MutableAminoAcidSequence index out of range"
+
+
+@pytest.mark.parametrize(
+    "mutable,expected",
+    [
+        (haem.MutableDNASequence("AACGN"), haem.MutableDNASequence("NCGTT")),
+        (haem.MutableRNASequence("AUG-"), haem.MutableRNASequence("-CAU")),
+        (haem.MutableDNASequence(), haem.MutableDNASequence()),
+    ],
+)
+def test_reverse_complement_inplace(
+    mutable: typing.Union[haem.MutableDNASequence, haem.MutableRNASequence],
+    expected: typing.Union[haem.MutableDNASequence, haem.MutableRNASequence],
+) -> None:
+    mutable.reverse_complement_inplace()
+
+    assert mutable == expected
+
+
+@pytest.mark.parametrize(
+    "key,expected",
+    [
+        (1, haem.AminoAcid.LYSINE),
+        (slice(1, 3), haem.MutableAminoAcidSequence("KV")),
+    ],
+)
+def test__getitem__(
+    key: typing.Union[int, slice],
+    expected: typing.Union[haem.AminoAcid, haem.MutableAminoAcidSequence],
+) -> None:
+    assert haem.MutableAminoAcidSequence("MKVL")[key] == expected
+
+
+@pytest.mark.parametrize(
+    "mutable,expected",
+    [
+        (haem.MutableDNASequence("ACGT"), "<MutableDNASequence: ACGT>"),
+        (haem.MutableRNASequence(), "<MutableRNASequence>"),
+    ],
+)
+def test__repr__(mutable: Mutable, expected: str) -> None:
+    assert repr(mutable) == expected
+
+
+def test__len__() -> None:
+    assert len(haem.MutableDNASequence("ACGT")) == 4
+    assert not haem.MutableDNASequence()
+
+
+@pytest.mark.parametrize(
+    "mutable,item,expected",
+    [
+        (haem.MutableDNASequence("ACCA"), "A", True),
+        (haem.MutableDNASequence("ACCA"), "CC", True),
+        (haem.MutableDNASequence("ACCA"), haem.DNABase.ADENINE, True),
+        (haem.MutableDNASequence("ACCA"), haem.DNABase.GUANINE, False),
+        (haem.MutableDNASequence("ACCA"), haem.DNASequence("CA"), True),
+        (haem.MutableDNASequence("ACCA"), haem.MutableDNASequence("AA"), False),
+        (haem.MutableRNASequence("ACGU"), "U", True),
+        (haem.MutableAminoAcidSequence("MKV"), haem.AminoAcid.LYSINE, True),
+    ],
+)
+def test__contains__(
+    mutable: Mutable,
+    item: typing.Union[haem.DNABase, haem.AminoAcid, str, Mutable],
+    expected: bool,
+) -> None:
+    assert (item in mutable) == expected
+
+
+def test_contains_ambiguous() -> None:
+    mutable = haem.MutableDNASequence("ACGT")
+
+    assert mutable.contains("RT", ambiguous=True)
+    assert "RT" not in mutable
+
+
+@pytest.mark.parametrize(
+    "target,overlap,expected",
+    [("A", False, 3), ("AA", False, 1), ("AA", True, 2), ("", False, 0)],
+)
+def test_count(target: str, overlap: bool, expected: int) -> None:
+    assert haem.MutableDNASequence("AAAGT").count(target, overlap) == expected
+
+
+@pytest.mark.parametrize(
+    "target,start,expected",
+    [("G", None, 3), ("A", 1, 1), ("A", 3, None), (haem.AminoAcid.VALINE, None, None)],
+)
+def test_find(
+    target: typing.Union[haem.AminoAcid, str],
+    start: typing.Optional[int],
+    expected: typing.Optional[int],
+) -> None:
+    assert haem.MutableAminoAcidSequence("AAAGT").find(target, start) == expected
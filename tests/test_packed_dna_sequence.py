@@ -0,0 +1,208 @@
+import typing
+
+import pytest
+
+import haem
+
+
+@pytest.mark.parametrize(
+    "bases,bits_per_base,nbytes",
+    [
+        ("", 2, 0),
+        ("ACGT", 2, 1),
+        ("ACGTA", 2, 2),
+        ("ACGTN", 4, 3),
+        ("AC-GT", 4, 3),
+        ("MRWSYKVHDB", 4, 5),
+    ],
+)
+def test__init__(bases: str, bits_per_base: int, nbytes: int) -> None:
+    sequence = haem.PackedDNASequence(bases)
+
+    assert sequence.bits_per_base == bits_per_base
+    assert sequence.nbytes == nbytes
+    assert len(sequence) == len(bases)
+    assert sequence.unpack() == haem.DNASequence(bases)
+
+
+def test__init__invalid() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.PackedDNASequence("ACGU")
+
+    assert str(excinfo.value) == 'invalid IUPAC DNA code "U"'
+
+
+def test__init__dna_sequence() -> None:
+    sequence = haem.DNASequence("ACGT")
+
+    assert haem.PackedDNASequence(sequence).unpack() == sequence
+
+
+@pytest.mark.parametrize(
+    "bases", ["", "ACGTA", "ACGTACGTN", "NACGT", "ACGTACG-T", "ACGTACGTACGTACGTA"]
+)
+def test__init__iterator(bases: str) -> None:
+    sequence = haem.PackedDNASequence(iter(bases))
+
+    assert sequence == haem.PackedDNASequence(bases)
+    assert sequence.unpack() == haem.DNASequence(bases)
+
+
+@pytest.mark.parametrize(
+    "sequence,expected",
+    [
+        (haem.PackedDNASequence(), "<PackedDNASequence>"),
+        (haem.PackedDNASequence("ACN-"), "<PackedDNASequence: ACN->"),
+    ],
+)
+def test__repr__(sequence: haem.PackedDNASequence, expected: str) -> None:
+    assert repr(sequence) == expected
+
+
+def test__str__() -> None:
+    assert str(haem.PackedDNASequence("ACGT" * 10)) == "ACGTACGTAC...GTACGTACGT"
+
+
+def test__eq__() -> None:
+    assert haem.PackedDNASequence("ACGN") == haem.PackedDNASequence("ACGN")
+    assert haem.PackedDNASequence("ACGN") != haem.PackedDNASequence("ACGT")
+
+
+def test__hash__() -> None:
+    sequences = {
+        haem.PackedDNASequence("ACG"),
+        haem.PackedDNASequence("ACG"),
+        haem.PackedDNASequence("ACN"),
+    }
+
+    assert len(sequences) == 2
+
+
+@pytest.mark.parametrize(
+    "key,expected",
+    [
+        (0, haem.DNABase.ADENINE),
+        (-1, haem.DNABase.ANY),
+        (slice(1, 3), haem.PackedDNASequence("CG")),
+        (slice(None, None, -2), haem.PackedDNASequence("NGA")),
+        (slice(4, 0), haem.PackedDNASequence()),
+    ],
+)
+def test__getitem__(
+    key: typing.Union[int, slice],
+    expected: typing.Union[haem.DNABase, haem.PackedDNASequence],
+) -> None:
+    assert haem.PackedDNASequence("ACGTN")[key] == expected
+
+
+@pytest.mark.parametrize("key", [5, -6])
+def test__getitem__out_of_range(key: int) -> None:
+    with pytest.raises(IndexError) as excinfo:
+        haem.PackedDNASequence("ACGTN")[key]
+
+    assert str(excinfo.value) == "PackedDNASequence index out of range"
+
+
+@pytest.mark.parametrize(
+    "first,second,expected",
+    [
+        (haem.PackedDNASequence("AC"), "GT", haem.PackedDNASequence("ACGT")),
+        (haem.PackedDNASequence("AC"), "N", haem.PackedDNASequence("ACN")),
+        (
+            haem.PackedDNASequence("AC"),
+            haem.PackedDNASequence("G-"),
+            haem.PackedDNASequence("ACG-"),
+        ),
+        ("GT", haem.PackedDNASequence("AC"), haem.PackedDNASequence("GTAC")),
+        (
+            haem.PackedDNASequence("ACG"),
+            haem.PackedDNASequence("TN"),
+            haem.PackedDNASequence("ACGTN"),
+        ),
+        (
+            haem.PackedDNASequence("A-G"),
+            haem.PackedDNASequence("TAC"),
+            haem.PackedDNASequence("A-GTAC"),
+        ),
+        (
+            "NA",
+            haem.PackedDNASequence("CGT"),
+            haem.PackedDNASequence("NACGT"),
+        ),
+    ],
+)
+def test__add__(
+    first: typing.Union[haem.PackedDNASequence, str],
+    second: typing.Union[haem.PackedDNASequence, str],
+    expected: haem.PackedDNASequence,
+) -> None:
+    assert first + second == expected
+
+
+@pytest.mark.parametrize(
+    "target,overlap,ambiguous,expected",
+    [
+        ("A", False, False, 3),
+        ("AA", False, False, 1),
+        ("AA", True, False, 2),
+        ("N", False, False, 1),
+        ("R", False, True, 6),
+        (haem.PackedDNASequence("AG"), False, False, 1),
+        ("", False, False, 0),
+    ],
+)
+def test_count(
+    target: typing.Union[haem.PackedDNASequence, str],
+    overlap: bool,
+    ambiguous: bool,
+    expected: int,
+) -> None:
+    sequence = haem.PackedDNASequence("AAAGTGNC")
+
+    assert sequence.count(target, overlap, ambiguous=ambiguous) == expected
+
+
+@pytest.mark.parametrize(
+    "target,start,end,ambiguous,expected",
+    [
+        ("G", None, None, False, 3),
+        ("G", 4, None, False, 5),
+        ("G", -2, None, False, None),
+        ("GTG", None, None, False, 3),
+        ("NC", None, None, False, 6),
+        ("RC", None, None, True, 5),
+        ("T", 0, 3, False, None),
+        ("", None, None, False, None),
+    ],
+)
+def test_find(
+    target: str,
+    start: typing.Optional[int],
+    end: typing.Optional[int],
+    ambiguous: bool,
+    expected: typing.Optional[int],
+) -> None:
+    sequence = haem.PackedDNASequence("AAAGTGNC")
+
+    assert sequence.find(target, start, end, ambiguous=ambiguous) == expected
+
+
+@pytest.mark.parametrize(
+    "target,ambiguous,expected",
+    [
+        ("GTG", False, True),
+        ("GTA", False, False),
+        ("TGA", True, True),
+        ("", False, True),
+    ],
+)
+def test_contains(target: str, ambiguous: bool, expected: bool) -> None:
+    sequence = haem.PackedDNASequence("AAAGTGNC")
+
+    assert sequence.contains(target, ambiguous=ambiguous) == expected
+    assert (target in sequence) == (expected and not ambiguous)
+
+
+def test__bool__() -> None:
+    assert haem.PackedDNASequence("A")
+    assert not haem.PackedDNASequence()
@@ -0,0 +1,141 @@
+import typing
+
+import pytest
+
+import haem
+
+
+def test_find_all() -> None:
+    matcher = haem.PatternMatcher(
+        [haem.DNASequence("GAATTC"), haem.DNASequence("ACG"), haem.DNASequence("CA")]
+    )
+
+    assert matcher.find_all(haem.DNASequence("TTGAATTCACGTT")) == [
+        (0, 2, 1),
+        (2, 7, 1),
+        (1, 8, 1),
+    ]
+
+
+def test_find_all_overlapping_patterns() -> None:
+    matcher = haem.PatternMatcher(
+        [haem.RNASequence("AUA"), haem.RNASequence("UA"), haem.RNASequence("A")]
+    )
+
+    assert matcher.find_all(haem.RNASequence("AUAUA")) == [
+        (0, 0, 1),
+        (2, 0, 1),
+        (1, 1, 1),
+        (0, 2, 1),
+        (2, 2, 1),
+        (1, 3, 1),
+        (2, 4, 1),
+    ]
+
+
+def test_find_all_both_strands() -> None:
+    matcher = haem.PatternMatcher(
+        [haem.DNASequence("GAATTC"), haem.DNASequence("ACG")], both_strands=True
+    )
+
+    assert matcher.find_all(haem.DNASequence("TTGAATTCACGTT")) == [
+        (0, 2, -1),
+        (0, 2, 1),
+        (1, 8, 1),
+        (1, 9, -1),
+    ]
+
+
+@pytest.mark.parametrize(
+    "patterns,sequence,hits",
+    [
+        (["RAC"], "GACTAC", [(0, 0, 1)]),
+        (["RAC", "N"], "GAC", [(0, 0, 1), (1, 0, 1), (1, 1, 1), (1, 2, 1)]),
+        (["GAC"], "GNCRAC", [(0, 0, 1), (0, 3, 1)]),
+        (["G-C"], "GNCG-C", [(0, 3, 1)]),
+    ],
+)
+def test_find_all_ambiguous(
+    patterns: typing.List[str],
+    sequence: str,
+    hits: typing.List[typing.Tuple[int, int, int]],
+) -> None:
+    matcher = haem.PatternMatcher(
+        [haem.DNASequence(pattern) for pattern in patterns], ambiguous=True
+    )
+
+    assert matcher.find_all(haem.DNASequence(sequence)) == hits
+
+
+def test_find_all_not_ambiguous() -> None:
+    matcher = haem.PatternMatcher([haem.DNASequence("RAC")])
+
+    assert matcher.find_all(haem.DNASequence("GACRAC")) == [(0, 3, 1)]
+
+
+def test_find_all_amino_acids() -> None:
+    matcher = haem.PatternMatcher([haem.AminoAcidSequence("MB")], ambiguous=True)
+
+    assert matcher.find_all(haem.AminoAcidSequence("MDMNMZ")) == [
+        (0, 0, 1),
+        (0, 2, 1),
+    ]
+
+
+def test_find_all_long_sequence() -> None:
+    sequence = haem.DNASequence("ACGT" * 50000)
+    matcher = haem.PatternMatcher([haem.DNASequence("TACG")])
+
+    assert len(matcher.find_all(sequence)) == 49999
+
+
+def test_find_all_no_patterns() -> None:
+    assert haem.PatternMatcher([]).find_all(haem.DNASequence("ACGT")) == []
+
+
+def test_find_all_wrong_type() -> None:
+    matcher = haem.PatternMatcher([haem.DNASequence("A")])
+
+    with pytest.raises(TypeError) as excinfo:
+        matcher.find_all(haem.RNASequence("A"))
+
+    assert str(excinfo.value) == "sequence must be of the same type as the patterns"
+
+
+def test__new__mixed_types() -> None:
+    with pytest.raises(TypeError) as excinfo:
+        haem.PatternMatcher([haem.DNASequence("A"), haem.RNASequence("A")])
+
+    assert str(excinfo.value) == "patterns must all be of the same sequence type"
+
+
+@pytest.mark.parametrize(
+    "patterns,both_strands,message",
+    [
+        ([haem.DNASequence("A"), haem.DNASequence()], False, "pattern 1 is empty"),
+        (
+            [haem.AminoAcidSequence("M")],
+            True,
+            "both_strands requires nucleotide patterns",
+        ),
+        (
+            [haem.DNASequence("N" * 9)],
+            False,
+            "pattern 0 has too many ambiguous expansions",
+        ),
+    ],
+)
+def test__new__invalid(
+    patterns: typing.List[haem.DNASequence], both_strands: bool, message: str
+) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.PatternMatcher(patterns, both_strands=both_strands, ambiguous=True)
+
+    assert str(excinfo.value) == message
+
+
+def test__repr__() -> None:
+    matcher = haem.PatternMatcher([haem.DNASequence("A"), haem.DNASequence("C")])
+
+    assert repr(matcher) == "<PatternMatcher: 2 DNASequence patterns>"
+    assert len(matcher) == 2
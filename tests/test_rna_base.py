@@ -148,6 +148,50 @@ def test_retro_transcribe(rna_base: haem.RNABase, dna_base: haem.DNABase) -> Non
     assert rna_base.retro_transcribe() == dna_base
 
 
+@pytest.mark.parametrize(
+    "base,bases",
+    [
+        (haem.RNABase.ADENINE, {haem.RNABase.ADENINE}),
+        (haem.RNABase.ADENINE_GUANINE, {haem.RNABase.ADENINE, haem.RNABase.GUANINE}),
+        (
+            haem.RNABase.ANY,
+            {
+                haem.RNABase.ADENINE,
+                haem.RNABase.CYTOSINE,
+                haem.RNABase.GUANINE,
+                haem.RNABase.URACIL,
+            },
+        ),
+        (haem.RNABase.GAP, set()),
+    ],
+)
+def test_bases(base: haem.RNABase, bases: typing.Set[haem.RNABase]) -> None:
+    assert base.bases == bases
+
+
+@pytest.mark.parametrize(
+    "base,other,result",
+    [
+        (haem.RNABase.ADENINE, haem.RNABase.ADENINE, True),
+        (haem.RNABase.ADENINE, haem.RNABase.GUANINE, False),
+        (haem.RNABase.ADENINE_GUANINE, haem.RNABase.GUANINE, True),
+        (haem.RNABase.GUANINE, haem.RNABase.ADENINE_GUANINE, True),
+        (haem.RNABase.ADENINE_GUANINE, haem.RNABase.CYTOSINE_URACIL, False),
+        (haem.RNABase.ANY, "U", True),
+        (haem.RNABase.ANY, haem.RNABase.GAP, False),
+        (haem.RNABase.GAP, "-", True),
+    ],
+)
+def test_matches(
+    base: haem.RNABase, other: typing.Union[haem.RNABase, str], result: bool
+) -> None:
+    assert base.matches(other) is result
+
+
+def test__hash__() -> None:
+    assert len({haem.RNABase.ADENINE, haem.RNABase("A"), haem.RNABase.GAP}) == 2
+
+
 def test__eq__() -> None:
     assert haem.RNABase.ADENINE == haem.RNABase.ADENINE
 
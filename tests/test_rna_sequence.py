@@ -76,6 +76,34 @@ def test_complement(sequence: haem.RNASequence, complement: haem.RNASequence) ->
     assert ~sequence == complement
 
 
+@pytest.mark.parametrize(
+    "sequence,reverse_complement",
+    [
+        (haem.RNASequence(), haem.RNASequence()),
+        (haem.RNASequence("A"), haem.RNASequence("U")),
+        (haem.RNASequence("AACG"), haem.RNASequence("CGUU")),
+        (haem.RNASequence("MRWSYKVHDBN-"), haem.RNASequence("-NVHDBMRSWYK")),
+    ],
+)
+def test_reverse_complement(
+    sequence: haem.RNASequence, reverse_complement: haem.RNASequence
+) -> None:
+    assert sequence.reverse_complement() == reverse_complement
+
+
+@pytest.mark.parametrize(
+    "sequence,reverse",
+    [
+        (haem.RNASequence(), haem.RNASequence()),
+        (haem.RNASequence("A"), haem.RNASequence("A")),
+        (haem.RNASequence("AACG"), haem.RNASequence("GCAA")),
+    ],
+)
+def test_reverse(sequence: haem.RNASequence, reverse: haem.RNASequence) -> None:
+    assert sequence.reverse() == reverse
+    assert haem.RNASequence(reversed(sequence)) == reverse
+
+
 @pytest.mark.parametrize(
     "rna_sequence,dna_sequence",
     [
@@ -130,12 +158,40 @@ def test__ne__() -> None:
     ) != haem.RNASequence([haem.RNABase.ADENINE, haem.RNABase.URACIL])
 
 
-@pytest.mark.parametrize("op", [operator.gt, operator.ge, operator.lt, operator.le])
-def test_unsupported_comparison(
-    op: typing.Callable[[haem.RNASequence, haem.RNASequence], bool],
+def test__hash__() -> None:
+    sequences = {
+        haem.RNASequence("ACG"),
+        haem.RNASequence("ACG"),
+        haem.RNASequence("AGU"),
+    }
+
+    assert len(sequences) == 2
+
+
+@pytest.mark.parametrize(
+    "op,result",
+    [
+        (operator.lt, True),
+        (operator.le, True),
+        (operator.gt, False),
+        (operator.ge, False),
+    ],
+)
+def test_comparison(
+    op: typing.Callable[[haem.RNASequence, haem.RNASequence], bool], result: bool
 ) -> None:
-    with pytest.raises(TypeError):
-        op(haem.RNASequence(), haem.RNASequence())
+    assert op(haem.RNASequence("ACG"), haem.RNASequence("AGU")) is result
+
+
+def test_sorted() -> None:
+    sequences = [haem.RNASequence(codes) for codes in ["AGU", "", "ACG", "AC"]]
+
+    assert sorted(sequences) == [
+        haem.RNASequence(),
+        haem.RNASequence("AC"),
+        haem.RNASequence("ACG"),
+        haem.RNASequence("AGU"),
+    ]
 
 
 @pytest.mark.parametrize("bases,result", [([], False), ([haem.RNABase.ADENINE], True)])
@@ -383,3 +439,274 @@ def test_translate_no_stop_codon() -> None:
         haem.RNASequence("AUG").translate()
 
     assert str(excinfo.value) == "no stop codon found"
+
+
+@pytest.mark.parametrize(
+    "rna_sequence,frame,amino_acid_sequence",
+    [
+        (haem.RNASequence("GCCAUGUAA"), 0, haem.AminoAcidSequence("AM")),
+        (haem.RNASequence("AGCCAUGUAA"), 1, haem.AminoAcidSequence("AM")),
+        (haem.RNASequence("AAGCCAUGUAA"), 2, haem.AminoAcidSequence("AM")),
+        (haem.RNASequence("GCCAUGA"), 0, haem.AminoAcidSequence("AM")),
+        (haem.RNASequence("GCCUAAAUG"), 0, haem.AminoAcidSequence("A")),
+        (haem.RNASequence("A"), 2, haem.AminoAcidSequence()),
+        (haem.RNASequence(), 0, haem.AminoAcidSequence()),
+    ],
+)
+def test_translate_frame(
+    rna_sequence: haem.RNASequence,
+    frame: int,
+    amino_acid_sequence: haem.AminoAcidSequence,
+) -> None:
+    assert rna_sequence.translate(frame=frame) == amino_acid_sequence
+
+
+def test_translate_invalid_frame() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.RNASequence("AUGUAA").translate(frame=3)
+
+    assert str(excinfo.value) == "frame must be 0, 1 or 2"
+
+
+@pytest.mark.parametrize(
+    "rna_sequence,frame,amino_acid_sequence",
+    [
+        (haem.RNASequence("AUGUAAGCCUGA"), None, haem.AminoAcidSequence("M*A*")),
+        (haem.RNASequence("CAUGUAAGCCU"), None, haem.AminoAcidSequence("M*A")),
+        (haem.RNASequence("GCCUAAAUG"), 0, haem.AminoAcidSequence("A*M")),
+    ],
+)
+def test_translate_through_stop(
+    rna_sequence: haem.RNASequence,
+    frame: typing.Optional[int],
+    amino_acid_sequence: haem.AminoAcidSequence,
+) -> None:
+    assert rna_sequence.translate(frame=frame, to_stop=False) == amino_acid_sequence
+
+
+@pytest.mark.parametrize(
+    "rna_sequence,table,amino_acid_sequence",
+    [
+        (haem.RNASequence("CCUGGCCUAA"), 1, haem.AminoAcidSequence("MA")),
+        (haem.RNASequence("GUGGCCUAAAUG"), 11, haem.AminoAcidSequence("MA")),
+        (haem.RNASequence("AUUGCCUAA"), 11, haem.AminoAcidSequence("MA")),
+        (haem.RNASequence("UUGGCCUAA"), 1, haem.AminoAcidSequence("MA")),
+    ],
+)
+def test_translate_alternative_starts(
+    rna_sequence: haem.RNASequence,
+    table: int,
+    amino_acid_sequence: haem.AminoAcidSequence,
+) -> None:
+    assert (
+        rna_sequence.translate(table, alternative_starts=True) == amino_acid_sequence
+    )
+
+
+def test_translate_alternative_starts_disabled() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.RNASequence("GUGGCCUAA").translate()
+
+    assert str(excinfo.value) == "no start codon found"
+
+
+@pytest.mark.parametrize(
+    "rna_sequence,table,amino_acid_sequence",
+    [
+        (haem.RNASequence("AUGGCCUAA"), 1, haem.AminoAcidSequence("MA")),
+        (haem.RNASequence("GUGGCCUGA"), 11, haem.AminoAcidSequence("MA")),
+        (haem.RNASequence("AUGUGAAGA"), 2, haem.AminoAcidSequence("MW")),
+    ],
+)
+def test_translate_cds(
+    rna_sequence: haem.RNASequence,
+    table: int,
+    amino_acid_sequence: haem.AminoAcidSequence,
+) -> None:
+    assert rna_sequence.translate(table, cds=True) == amino_acid_sequence
+
+
+@pytest.mark.parametrize(
+    "rna_sequence,message",
+    [
+        (haem.RNASequence("AUGGCCUA"), "CDS length is not a multiple of three"),
+        (haem.RNASequence("GCCAUGUAA"), "CDS does not begin with a start codon"),
+        (haem.RNASequence("AUGGCCGCC"), "CDS does not end with a stop codon"),
+        (haem.RNASequence("AUG"), "CDS does not end with a stop codon"),
+        (haem.RNASequence(), "CDS does not begin with a start codon"),
+        (haem.RNASequence("AUGUAAGCCUAA"), "CDS contains an internal stop codon"),
+    ],
+)
+def test_translate_cds_invalid(rna_sequence: haem.RNASequence, message: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        rna_sequence.translate(cds=True)
+
+    assert str(excinfo.value) == message
+
+
+def test_translate_cds_with_frame() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.RNASequence("AUGUAA").translate(frame=0, cds=True)
+
+    assert str(excinfo.value) == "frame cannot be used with cds translation"
+
+
+def test_translate_frames() -> None:
+    frames = haem.RNASequence("CCAUGAAAUGCCCUAAGGUUACAUUUCAUGG").translate_frames()
+
+    assert [str(frame) for frame in frames] == [
+        "P*NALRLHFM",
+        "HEMP*GYISW",
+        "MKCPKVTFH",
+        "P*NVTLGHFM",
+        "HEM*P*GISW",
+        "MKCNLRAFH",
+    ]
+
+
+def test_find_orfs() -> None:
+    orfs = haem.RNASequence("CCAUGAAAUGCCCUAAGGUUACAUUUCAUGG").find_orfs()
+
+    assert [(orf.start, orf.end, orf.strand, orf.frame) for orf in orfs] == [
+        (7, 16, 1, 1),
+        (18, 24, -1, 1),
+    ]
+    assert [str(orf.sequence) for orf in orfs] == ["MP", "M"]
+
+
+@pytest.mark.parametrize(
+    "nested,min_length,sequences",
+    [
+        (False, 0, ["MMK"]),
+        (True, 0, ["MMK", "MK"]),
+        (True, 3, ["MMK"]),
+        (False, 4, []),
+    ],
+)
+def test_find_orfs_nested(
+    nested: bool, min_length: int, sequences: typing.List[str]
+) -> None:
+    orfs = haem.RNASequence("AUGAUGAAAUAA").find_orfs(
+        nested=nested, min_length=min_length
+    )
+
+    assert [str(orf.sequence) for orf in orfs] == sequences
+
+
+def test_find_orfs_alternative_starts() -> None:
+    sequence = haem.RNASequence("GUGAAAUGA")
+
+    orfs = sequence.find_orfs(11, alternative_starts=True)
+
+    assert sequence.find_orfs(11) == []
+    assert [str(orf.sequence) for orf in orfs] == ["MK"]
+
+
+@pytest.mark.parametrize(
+    "target,index,total",
+    [
+        ("R", 0, 5),
+        ("N", 0, 7),
+        ("GN", 2, 2),
+        ("UC", 3, 1),
+        ("-", None, 0),
+    ],
+)
+def test_ambiguous(target: str, index: typing.Optional[int], total: int) -> None:
+    sequence = haem.RNASequence("ACGUNRA")
+
+    assert sequence.find(target, ambiguous=True) == index
+    assert sequence.count(target, ambiguous=True) == total
+    assert sequence.contains(target, ambiguous=True) is (index is not None)
+
+
+def test_ambiguous_disabled() -> None:
+    sequence = haem.RNASequence("ACGUNRA")
+
+    assert sequence.find("R") == 5
+    assert sequence.count("N") == 1
+    assert not sequence.contains("GN")
+    assert "GN" not in sequence
+
+
+@pytest.mark.parametrize(
+    "start,end,result",
+    [
+        (None, None, 0),
+        (2, None, 2),
+        (-3, None, 5),
+        (None, 1, None),
+        (6, 2, None),
+        (100, None, None),
+    ],
+)
+def test_find_bounds(
+    start: typing.Optional[int], end: typing.Optional[int], result: typing.Optional[int]
+) -> None:
+    assert haem.RNASequence("AAAACAAA").find("AA", start, end) == result
+
+
+@pytest.mark.parametrize(
+    "target,start,end,result",
+    [
+        ("A", None, None, 7),
+        ("AA", None, None, 6),
+        ("A", None, -3, 3),
+        ("C", 5, None, None),
+        ("G", None, None, None),
+    ],
+)
+def test_rfind(
+    target: str,
+    start: typing.Optional[int],
+    end: typing.Optional[int],
+    result: typing.Optional[int],
+) -> None:
+    assert haem.RNASequence("AAAACAAA").rfind(target, start, end) == result
+
+
+def test_index() -> None:
+    sequence = haem.RNASequence("AAAACAAA")
+
+    assert sequence.index("C") == 4
+    assert sequence.rindex("AA", -4) == 6
+
+
+@pytest.mark.parametrize("method", ["index", "rindex"])
+def test_index_not_found(method: str) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        getattr(haem.RNASequence("AAAACAAA"), method)("C", 5)
+
+    assert str(excinfo.value) == "subsequence not found"
+
+
+@pytest.mark.parametrize(
+    "target,overlap,start,end,result",
+    [
+        ("AA", False, None, None, [0, 2, 5]),
+        ("AA", True, None, None, [0, 1, 2, 5, 6]),
+        ("AA", True, 1, -1, [1, 2, 5]),
+        ("A", False, -3, None, [5, 6, 7]),
+        ("G", False, None, None, []),
+        ("", False, None, None, []),
+    ],
+)
+def test_find_all(
+    target: str,
+    overlap: bool,
+    start: typing.Optional[int],
+    end: typing.Optional[int],
+    result: typing.List[int],
+) -> None:
+    sequence = haem.RNASequence("AAAACAAA")
+
+    assert sequence.find_all(target, overlap, start, end) == result
+    assert list(sequence.finditer(target, overlap, start, end)) == result
+
+
+def test_finditer_lazy() -> None:
+    matches = haem.RNASequence("AAAACAAA").finditer("AA")
+
+    assert next(matches) == 0
+    assert next(matches) == 2
+    assert list(matches) == [5]
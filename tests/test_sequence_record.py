@@ -0,0 +1,241 @@
+import typing
+
+import pytest
+
+import haem
+
+
+def test__new__() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("ACGT"),
+        "seq",
+        "a sequence",
+        annotations={"organism": "E. coli"},
+        features=[haem.Feature("gene", 0, 2)],
+    )
+
+    assert record.sequence == haem.DNASequence("ACGT")
+    assert record.id == "seq"
+    assert record.description == "a sequence"
+    assert record.annotations == {"organism": "E. coli"}
+    assert record.features == [haem.Feature("gene", 0, 2)]
+
+
+def test__new__defaults() -> None:
+    record = haem.SequenceRecord(haem.AminoAcidSequence("MVVR"))
+
+    assert record.id == ""
+    assert record.description == ""
+    assert record.annotations == {}
+    assert record.features == []
+
+
+def test__new__invalid_sequence() -> None:
+    with pytest.raises(TypeError):
+        haem.SequenceRecord("ACGT")  # type: ignore[arg-type]
+
+
+def test__new__invalid_feature() -> None:
+    with pytest.raises(TypeError):
+        haem.SequenceRecord(
+            haem.DNASequence(),
+            features=["gene"],  # type: ignore[list-item]
+        )
+
+
+def test_set_id_and_description() -> None:
+    record = haem.SequenceRecord(haem.DNASequence())
+    record.id = "seq"
+    record.description = "a sequence"
+
+    assert record.id == "seq"
+    assert record.description == "a sequence"
+
+
+def test_annotations_mutable() -> None:
+    record = haem.SequenceRecord(haem.DNASequence())
+    record.annotations["organism"] = "E. coli"
+    record.features.append(haem.Feature("gene", 0, 0))
+
+    assert record.annotations == {"organism": "E. coli"}
+    assert record.features == [haem.Feature("gene", 0, 0)]
+
+
+def test_complement() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("ACGT"),
+        "seq",
+        "a sequence",
+        annotations={"organism": "E. coli"},
+        features=[haem.Feature("gene", 0, 2)],
+    )
+
+    for complement in (record.complement, ~record):
+        assert complement.sequence == haem.DNASequence("TGCA")
+        assert complement.id == "seq"
+        assert complement.description == "a sequence"
+        assert complement.annotations == {"organism": "E. coli"}
+        assert complement.features == [haem.Feature("gene", 0, 2)]
+
+
+def test_derived_annotations_are_copied() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("ACGT"), annotations={"organism": "E. coli"}
+    )
+
+    complement = record.complement
+    complement.annotations["organism"] = "H. sapiens"
+    complement.features.append(haem.Feature("gene", 0, 1))
+
+    assert record.annotations == {"organism": "E. coli"}
+    assert record.features == []
+
+
+def test_reverse_complement() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("AACGTT"),
+        "seq",
+        features=[
+            haem.Feature("gene", 0, 2, strand=1),
+            haem.Feature("exon", 3, 6, strand=-1),
+            haem.Feature("repeat", 1, 3),
+        ],
+    )
+
+    reverse_complement = record.reverse_complement()
+
+    assert reverse_complement.sequence == haem.DNASequence("AACGTT")
+    assert reverse_complement.id == "seq"
+    assert reverse_complement.features == [
+        haem.Feature("repeat", 3, 5),
+        haem.Feature("exon", 0, 3, strand=1),
+        haem.Feature("gene", 4, 6, strand=-1),
+    ]
+
+
+def test_reverse_complement_feature_out_of_range() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("AC"), features=[haem.Feature("gene", 0, 3)]
+    )
+
+    with pytest.raises(ValueError) as excinfo:
+        record.reverse_complement()
+
+    assert str(excinfo.value) == "feature extends beyond the sequence"
+
+
+def test_transcribe_and_retro_transcribe() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("ACGT"), "seq", features=[haem.Feature("gene", 1, 3)]
+    )
+
+    transcribed = record.transcribe()
+    assert transcribed.sequence == haem.RNASequence("ACGU")
+    assert transcribed.id == "seq"
+    assert transcribed.features == [haem.Feature("gene", 1, 3)]
+
+    retro_transcribed = transcribed.retro_transcribe()
+    assert retro_transcribed.sequence == haem.DNASequence("ACGT")
+    assert retro_transcribed.features == [haem.Feature("gene", 1, 3)]
+
+
+def test_translate() -> None:
+    record = haem.SequenceRecord(
+        haem.RNASequence("AUGGCCUAA"),
+        "seq",
+        annotations={"organism": "E. coli"},
+        features=[haem.Feature("CDS", 0, 9)],
+    )
+
+    translated = record.translate()
+
+    assert translated.sequence == haem.AminoAcidSequence("MA")
+    assert translated.id == "seq"
+    assert translated.annotations == {"organism": "E. coli"}
+    assert translated.features == []
+
+
+def test_unsupported_operation() -> None:
+    with pytest.raises(AttributeError):
+        haem.SequenceRecord(haem.AminoAcidSequence("M")).transcribe()
+
+
+def test__getitem__index() -> None:
+    record = haem.SequenceRecord(haem.DNASequence("ACGT"))
+
+    assert record[1] == haem.DNABase.CYTOSINE
+
+
+def test__getitem__slice() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("ACGTACGT"),
+        "seq",
+        annotations={"organism": "E. coli"},
+        features=[
+            haem.Feature("gene", 0, 4),
+            haem.Feature("exon", 2, 5, strand=-1, qualifiers={"number": 1}),
+            haem.Feature("exon", 5, 8),
+        ],
+    )
+
+    sliced = record[2:6]
+
+    assert sliced.sequence == haem.DNASequence("GTAC")
+    assert sliced.id == "seq"
+    assert sliced.annotations == {"organism": "E. coli"}
+    assert sliced.features == [
+        haem.Feature("exon", 0, 3, strand=-1, qualifiers={"number": 1})
+    ]
+
+
+def test__getitem__strided_slice_drops_features() -> None:
+    record = haem.SequenceRecord(
+        haem.DNASequence("ACGT"), features=[haem.Feature("gene", 0, 4)]
+    )
+
+    sliced = record[::2]
+
+    assert sliced.sequence == haem.DNASequence("AG")
+    assert sliced.features == []
+
+
+def test__len__() -> None:
+    assert len(haem.SequenceRecord(haem.DNASequence("ACGT"))) == 4
+
+
+def test__str__() -> None:
+    assert str(haem.SequenceRecord(haem.DNASequence("ACGT"))) == "ACGT"
+
+
+def test__repr__() -> None:
+    assert (
+        repr(haem.SequenceRecord(haem.DNASequence(), "seq")) == "<SequenceRecord: seq>"
+    )
+
+
+def test_feature() -> None:
+    feature = haem.Feature("gene", 2, 5, strand=-1, qualifiers={"gene": "lacZ"})
+
+    assert feature.kind == "gene"
+    assert feature.start == 2
+    assert feature.end == 5
+    assert feature.strand == -1
+    assert feature.qualifiers == {"gene": "lacZ"}
+    assert len(feature) == 3
+    assert repr(feature) == "<Feature: gene 2..5>"
+
+
+@pytest.mark.parametrize(
+    "start,end,strand,message",
+    [
+        (5, 2, None, "feature start is after its end"),
+        (0, 1, 2, "feature strand must be 1, -1 or None"),
+    ],
+)
+def test_feature_invalid(
+    start: int, end: int, strand: typing.Optional[int], message: str
+) -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.Feature("gene", start, end, strand=strand)
+
+    assert str(excinfo.value) == message
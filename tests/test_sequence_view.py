@@ -0,0 +1,193 @@
+import pickle
+import typing
+
+import pytest
+
+import haem
+
+Sequence = typing.Union[haem.DNASequence, haem.RNASequence, haem.AminoAcidSequence]
+View = typing.Union[
+    haem.DNASequenceView, haem.RNASequenceView, haem.AminoAcidSequenceView
+]
+
+
+@pytest.mark.parametrize(
+    "sequence,key",
+    [
+        (haem.DNASequence("ACGTN"), (1, 4, None)),
+        (haem.DNASequence("ACGTN"), (None, None, 2)),
+        (haem.DNASequence("ACGTN"), (None, None, -1)),
+        (haem.DNASequence("ACGTN"), (-2, None, None)),
+        (haem.DNASequence("ACGTN"), (3, 1, None)),
+        (haem.RNASequence("ACGUN"), (4, 0, -2)),
+        (haem.AminoAcidSequence("MKVLA"), (1, None, None)),
+        (haem.AminoAcidSequence(), (None, None, None)),
+    ],
+)
+def test_view(
+    sequence: Sequence,
+    key: typing.Tuple[typing.Optional[int], ...],
+) -> None:
+    view = sequence.view(*key)
+
+    assert view.copy() == sequence[slice(*key)]
+    assert len(view) == len(sequence[slice(*key)])
+    assert str(view) == str(sequence[slice(*key)])
+
+
+def test_view_step_zero() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.DNASequence("ACGT").view(step=0)
+
+    assert str(excinfo.value) == "slice step cannot be zero"
+
+
+@pytest.mark.parametrize(
+    "view,expected",
+    [
+        (haem.DNASequence("ACGT").view(1), "<DNASequenceView: CGT>"),
+        (haem.RNASequence("ACGU").view(4), "<RNASequenceView>"),
+        (haem.AminoAcidSequence("MKV").view(step=2), "<AminoAcidSequenceView: MV>"),
+    ],
+)
+def test__repr__(view: View, expected: str) -> None:
+    assert repr(view) == expected
+
+
+@pytest.mark.parametrize(
+    "key,expected",
+    [
+        (0, haem.DNABase.CYTOSINE),
+        (-1, haem.DNABase.ANY),
+        (slice(1, 3), haem.DNASequence("GT")),
+        (slice(None, None, -2), haem.DNASequence("NG")),
+    ],
+)
+def test__getitem__(
+    key: typing.Union[int, slice],
+    expected: typing.Union[haem.DNABase, haem.DNASequence],
+) -> None:
+    item = haem.DNASequence("ACGTNA").view(1, 5)[key]
+
+    if isinstance(item, haem.DNASequenceView):
+        item = item.copy()
+
+    assert item == expected
+
+
+@pytest.mark.parametrize("key", [4, -5])
+def test__getitem__out_of_range(key: int) -> None:
+    with pytest.raises(IndexError) as excinfo:
+        haem.DNASequence("ACGTNA").view(1, 5)[key]
+
+    assert str(excinfo.value) == "DNASequenceView index out of range"
+
+
+@pytest.mark.parametrize(
+    "view,target,overlap,expected",
+    [
+        (haem.DNASequence("GAAAAC").view(1, -1), "AA", False, 2),
+        (haem.DNASequence("GAAAAC").view(1, -1), "AA", True, 3),
+        (haem.DNASequence("GAAAAC").view(step=-1), "CA", False, 1),
+        (haem.RNASequence("AUAUAU").view(step=2), "A", False, 3),
+        (haem.AminoAcidSequence("MKVKV").view(1), "KV", False, 2),
+    ],
+)
+def test_count(
+    view: View,
+    target: str,
+    overlap: bool,
+    expected: int,
+) -> None:
+    assert view.count(target, overlap) == expected
+
+
+@pytest.mark.parametrize(
+    "view,target,start,ambiguous,expected",
+    [
+        (haem.DNASequence("GACGAC").view(1), "AC", None, False, 0),
+        (haem.DNASequence("GACGAC").view(1), "AC", 1, False, 3),
+        (haem.DNASequence("GACGAC").view(1), "RC", 1, True, 3),
+        (haem.DNASequence("GACGAC").view(step=-1), "CA", None, False, 0),
+        (haem.AminoAcidSequence("MKVKV").view(1), "VL", None, False, None),
+    ],
+)
+def test_find(
+    view: View,
+    target: str,
+    start: typing.Optional[int],
+    ambiguous: bool,
+    expected: typing.Optional[int],
+) -> None:
+    assert view.find(target, start, ambiguous=ambiguous) == expected
+
+
+@pytest.mark.parametrize(
+    "view,expected",
+    [
+        (haem.DNASequence("GACGT").view(1, 4), haem.DNASequence("TGC")),
+        (haem.RNASequence("ACGU").view(step=-1), haem.RNASequence("ACGU")),
+    ],
+)
+def test_complement(
+    view: typing.Union[haem.DNASequenceView, haem.RNASequenceView],
+    expected: typing.Union[haem.DNASequence, haem.RNASequence],
+) -> None:
+    assert view.complement == expected
+
+
+@pytest.mark.parametrize(
+    "view,expected",
+    [
+        (haem.DNASequence("CCATGGCCTAA").view(2), haem.AminoAcidSequence("MA")),
+        (
+            haem.RNASequence("ANUNGNGNCNCNUNANA").view(step=2),
+            haem.AminoAcidSequence("MA"),
+        ),
+    ],
+)
+def test_translate(
+    view: typing.Union[haem.DNASequenceView, haem.RNASequenceView],
+    expected: haem.AminoAcidSequence,
+) -> None:
+    assert view.translate() == expected
+
+
+def test_view_outlives_reference() -> None:
+    view = haem.DNASequence("ACGT").view(1)
+
+    assert view.copy() == haem.DNASequence("CGT")
+
+
+@pytest.mark.parametrize(
+    "first,second,expected",
+    [
+        (haem.DNASequence("ACGT").view(), haem.DNASequence("ACGT").view(), True),
+        (haem.DNASequence("ACGT").view(1, 3), haem.DNASequence("GCGA").view(1, 3), True),
+        (
+            haem.DNASequence("ACGT").view(step=-1),
+            haem.DNASequence("TGCA").view(),
+            True,
+        ),
+        (haem.DNASequence("ACGT").view(1), haem.DNASequence("ACGT").view(), False),
+        (haem.RNASequence("ACGU").view(2), haem.RNASequence("ACGA").view(2), False),
+    ],
+)
+def test__eq__(first: View, second: View, expected: bool) -> None:
+    assert (first == second) == expected
+    assert (first != second) != expected
+
+
+def test__hash__() -> None:
+    views = {
+        haem.AminoAcidSequence("MKV").view(),
+        haem.AminoAcidSequence("AMKV").view(1),
+        haem.AminoAcidSequence("MKL").view(),
+    }
+
+    assert len(views) == 2
+
+
+def test_view_not_picklable() -> None:
+    with pytest.raises(TypeError):
+        pickle.dumps(haem.DNASequence("ACGT").view())
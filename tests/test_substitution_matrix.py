@@ -0,0 +1,133 @@
+import pathlib
+import typing
+
+import pytest
+
+import haem
+
+
+@pytest.mark.parametrize(
+    "name,first,second,score",
+    [
+        ("BLOSUM45", "W", "W", 15),
+        ("BLOSUM50", "H", "H", 10),
+        ("BLOSUM62", "A", "G", 0),
+        ("BLOSUM62", "W", "W", 11),
+        ("BLOSUM62", "*", "A", -4),
+        ("BLOSUM80", "C", "C", 9),
+        ("BLOSUM90", "D", "E", 1),
+        ("PAM30", "W", "W", 13),
+        ("PAM70", "*", "A", -11),
+        ("PAM250", "C", "C", 12),
+        ("NUC.4.4", "A", "A", 5),
+        ("NUC.4.4", "A", "R", 1),
+        ("NUC.4.4", "N", "N", -1),
+    ],
+)
+def test__getitem__(name: str, first: str, second: str, score: int) -> None:
+    matrix = haem.SubstitutionMatrix(name)
+
+    assert matrix[first, second] == score
+    assert matrix[second, first] == score
+
+
+@pytest.mark.parametrize(
+    "name,first,second,score",
+    [
+        ("BLOSUM62", haem.AminoAcid.ALANINE, haem.AminoAcid.GLYCINE, 0),
+        ("BLOSUM62", haem.AminoAcid.TRYPTOPHAN, "W", 11),
+        ("NUC.4.4", haem.DNABase.ADENINE, haem.DNABase.ANY, -2),
+        ("NUC.4.4", haem.RNABase.URACIL, haem.DNABase.THYMINE, 5),
+    ],
+)
+def test__getitem__members(
+    name: str,
+    first: typing.Union[haem.AminoAcid, haem.DNABase, haem.RNABase, str],
+    second: typing.Union[haem.AminoAcid, haem.DNABase, haem.RNABase, str],
+    score: int,
+) -> None:
+    assert haem.SubstitutionMatrix(name)[first, second] == score
+
+
+@pytest.mark.parametrize(
+    "name,first,code",
+    [
+        ("BLOSUM62", "U", "U"),
+        ("BLOSUM62", haem.DNABase.ADENINE, "A"),
+        ("NUC.4.4", haem.AminoAcid.ALANINE, "A"),
+    ],
+)
+def test__getitem__missing(
+    name: str,
+    first: typing.Union[haem.AminoAcid, haem.DNABase, str],
+    code: str,
+) -> None:
+    with pytest.raises(KeyError) as excinfo:
+        haem.SubstitutionMatrix(name)[first, "A"]
+
+    assert excinfo.value.args == (code,)
+
+
+def test__new__case_insensitive() -> None:
+    assert haem.SubstitutionMatrix("blosum62").name == "BLOSUM62"
+
+
+def test__new__unknown() -> None:
+    with pytest.raises(ValueError) as excinfo:
+        haem.SubstitutionMatrix("BLOSUM100")
+
+    assert str(excinfo.value) == 'unknown substitution matrix "BLOSUM100"'
+
+
+def test_alphabet() -> None:
+    matrix = haem.SubstitutionMatrix("BLOSUM62")
+
+    assert matrix.alphabet == "ARNDCQEGHILKMFPSTWYVBZX*"
+
+
+@pytest.mark.parametrize(
+    "first,second,score", [("A", "A", 5), ("A", "C", -4), ("A", "R", 1), ("A", "N", -2)]
+)
+def test_nucleotide(first: str, second: str, score: int) -> None:
+    matrix = haem.SubstitutionMatrix.nucleotide(5, -4)
+
+    assert matrix[first, second] == score
+    assert matrix.alphabet == "ACGTMRWSYKVHDBN"
+
+
+def test_load(tmp_path: pathlib.Path) -> None:
+    path = tmp_path / "MATRIX"
+    path.write_text("# comment\n   A  C\nA  2 -1\nC -1  3\n")
+
+    matrix = haem.SubstitutionMatrix.load(path)
+
+    assert matrix.name == "MATRIX"
+    assert matrix["A", "C"] == -1
+    assert matrix["C", "C"] == 3
+    assert matrix[haem.DNABase.ADENINE, haem.DNABase.ADENINE] == 2
+
+
+@pytest.mark.parametrize(
+    "content",
+    [
+        "",
+        "   A  C\nA  2 -1\n",
+        "   A  C\nA  2\nC -1  3\n",
+        "   A  C\nA  2 x\nC -1  3\n",
+        "   A  A\nA  2 -1\nA -1  3\n",
+    ],
+)
+def test_load_invalid(tmp_path: pathlib.Path, content: str) -> None:
+    path = tmp_path / "MATRIX"
+    path.write_text(content)
+
+    with pytest.raises(ValueError) as excinfo:
+        haem.SubstitutionMatrix.load(path)
+
+    assert str(excinfo.value) == "invalid substitution matrix"
+
+
+def test__repr__() -> None:
+    matrix = haem.SubstitutionMatrix("PAM250")
+
+    assert repr(matrix) == "<SubstitutionMatrix: PAM250>"